@@ -2,10 +2,15 @@ use std::fmt::Display;
 
 use crate::{
     Runs,
-    baseball::{inning::Outs, lineup::BattingPosition},
+    baseball::{
+        inning::Outs,
+        lineup::BattingPosition,
+        movement::{Advancement, Destination, RunnerMovement},
+    },
 };
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Base {
     First,
     Second,
@@ -35,7 +40,81 @@ impl Base {
     }
 }
 
+impl Display for Base {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Base::First => write!(f, "first"),
+            Base::Second => write!(f, "second"),
+            Base::Third => write!(f, "third"),
+            Base::Home => write!(f, "home"),
+        }
+    }
+}
+
+pub(crate) fn base_number(base: Base) -> u8 {
+    match base {
+        Base::First => 1,
+        Base::Second => 2,
+        Base::Third => 3,
+        Base::Home => 4,
+    }
+}
+
+/// Where a runner starts, counting home plate as 0 for the batter.
+pub(crate) fn start_number(base: Base) -> u8 {
+    match base {
+        Base::Home => 0,
+        base => base_number(base),
+    }
+}
+
+pub(crate) fn base_at(number: u8) -> Base {
+    match number {
+        1 => Base::First,
+        2 => Base::Second,
+        3 => Base::Third,
+        _ => Base::Home,
+    }
+}
+
+/// `movements`, lead runner first, cut short once `runs_needed` runs have scored: any other runner
+/// who is safe, or whose out in `ended_outs` came after the game ended, moves up no more than
+/// `credited_bases` bases.
+pub(crate) fn walk_off_movements(
+    movements: &[RunnerMovement],
+    runs_needed: Runs,
+    credited_bases: u8,
+    ended_outs: &[BattingPosition],
+) -> Vec<RunnerMovement> {
+    let mut runs = 0;
+    movements
+        .iter()
+        .filter_map(|movement| {
+            let base = match movement.to() {
+                Destination::Safe(base) => base,
+                Destination::Out(base) if ended_outs.contains(&movement.runner()) => base,
+                _ => return Some(*movement),
+            };
+            if movement.to() == Destination::Safe(Base::Home) && runs < runs_needed {
+                runs += 1;
+                return Some(*movement);
+            }
+            let start = start_number(movement.from());
+            let destination = (start + credited_bases).min(base_number(base));
+            (destination > start).then(|| {
+                RunnerMovement::new(
+                    movement.runner(),
+                    movement.from(),
+                    Destination::Safe(base_at(destination)),
+                )
+                .with_advancement(movement.advancement())
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BaseOutcome {
     ForceOut,
     TagOut,
@@ -75,6 +154,7 @@ impl BaseOutcome {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HomeOutcome {
     pub runs: Runs,
     pub outs: Outs,
@@ -119,12 +199,118 @@ impl HomeOutcome {
     }
 }
 
+/// How the batter's plate appearance is scored, independent of where the runners end up.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayKind {
+    Single,
+    Double,
+    Triple,
+    HomeRun,
+    Groundout,
+    Flyout,
+    /// The defense retires a runner ahead of the batter instead of the batter (Rule 9.05(b)(2)).
+    FieldersChoice,
+    SacrificeFly,
+    SacrificeBunt,
+    /// Strike three with runners moving on the pitch, or with the batter running on a third strike
+    /// the catcher did not catch.
+    Strikeout,
+    /// The batter reaches base because a fielder misplays the ball (Rule 9.12(a)(1)). Charged as a
+    /// time at bat but not a hit.
+    ReachedOnError,
+    #[default]
+    Other,
+}
+
+impl PlayKind {
+    pub fn is_hit(self) -> bool {
+        matches!(
+            self,
+            PlayKind::Single | PlayKind::Double | PlayKind::Triple | PlayKind::HomeRun
+        )
+    }
+
+    /// Sacrifices do not count as a time at bat (Rule 9.02(a)(1)).
+    pub fn is_sacrifice(self) -> bool {
+        matches!(self, PlayKind::SacrificeFly | PlayKind::SacrificeBunt)
+    }
+}
+
+/// Why a play cannot be made with the runners currently on base.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayError {
+    NoRunnerOn(Base),
+    BasesEmpty,
+    /// A runner ends up on base who was neither on base nor at bat.
+    UnknownRunner(BattingPosition),
+    /// The same runner ends up on two bases.
+    DuplicateRunner(BattingPosition),
+    /// A runner ends up on a base behind the one they started from.
+    RunnerMovedBack(BattingPosition),
+    /// Two runners end up on the same base.
+    BaseOccupied(Base),
+    /// A runner ends up ahead of a runner who started ahead of them.
+    RunnerPassed(BattingPosition),
+    /// The runners and batter do not all end up on base, across the plate or out.
+    RunnersNotConserved {
+        runners: u8,
+        accounted_for: u8,
+    },
+    TooManyOuts(u8),
+    /// The batter runs on strike three with first base occupied and fewer than two out (Rule 5.09(a)(3)).
+    FirstBaseOccupied,
+    /// A strikeout play before the batter has two strikes.
+    NotStrikeThree,
+    /// The batter reaches base on a foul fly that was caught (Rule 5.09(a)(1)).
+    BatterSafeOnCaughtFoul,
+    /// A runner given an extra base on an error is out, scores or is not on the play.
+    NotLeftOnBase(BattingPosition),
+}
+
+impl Display for PlayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlayError::NoRunnerOn(base) => write!(f, "No runner on {} base", base),
+            PlayError::BasesEmpty => write!(f, "The bases are empty"),
+            PlayError::UnknownRunner(runner) => {
+                write!(f, "The {} hitter was neither on base nor at bat", runner)
+            }
+            PlayError::DuplicateRunner(runner) => write!(f, "The {} hitter is on two bases", runner),
+            PlayError::RunnerMovedBack(runner) => {
+                write!(f, "The {} hitter ends up behind the base they started from", runner)
+            }
+            PlayError::BaseOccupied(base) => write!(f, "Two runners end up on {} base", base),
+            PlayError::RunnerPassed(runner) => write!(f, "The {} hitter passes the runner ahead", runner),
+            PlayError::RunnersNotConserved { runners, accounted_for } => write!(
+                f,
+                "{} runners including the batter, but {} are on base, scored or out",
+                runners, accounted_for
+            ),
+            PlayError::TooManyOuts(outs) => write!(f, "{} outs on a single play", outs),
+            PlayError::FirstBaseOccupied => write!(
+                f,
+                "The batter is out on strike three with first base occupied and fewer than two out"
+            ),
+            PlayError::NotStrikeThree => write!(f, "The batter does not have two strikes"),
+            PlayError::BatterSafeOnCaughtFoul => write!(f, "The batter is out on a caught foul ball"),
+            PlayError::NotLeftOnBase(runner) => write!(f, "The {} hitter is not left on base", runner),
+        }
+    }
+}
+
+impl std::error::Error for PlayError {}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayOutcome {
     first: BaseOutcome,
     second: BaseOutcome,
     third: BaseOutcome,
     home: HomeOutcome,
+    kind: PlayKind,
+    movements: [Option<RunnerMovement>; 4],
 }
 
 impl Display for PlayOutcome {
@@ -134,75 +320,245 @@ impl Display for PlayOutcome {
 }
 
 impl PlayOutcome {
+    /// A play described only by where it leaves each base. It does not record which runner went
+    /// where; `runner_movements` works that out.
     pub fn new(first: BaseOutcome, second: BaseOutcome, third: BaseOutcome, home: HomeOutcome) -> Self {
         PlayOutcome {
             first,
             second,
             third,
             home,
+            kind: PlayKind::Other,
+            movements: [None; 4],
         }
     }
 
+    /// A play described runner by runner, the batter starting from home plate, in the order it
+    /// happened: outs are taken to be made in the order their movements are listed. Runners without
+    /// a movement hold their base.
+    pub fn from_movements(baserunners: BaserunnerState, movements: &[RunnerMovement]) -> Result<Self, PlayError> {
+        baserunners.apply(movements)?;
+        Ok(Self::moved(baserunners, movements, PlayKind::Other))
+    }
+
     pub fn groundout() -> Self {
         PlayOutcome {
             first: BaseOutcome::ForceOut,
             second: BaseOutcome::None,
             third: BaseOutcome::None,
             home: HomeOutcome::none(),
+            kind: PlayKind::Groundout,
+            movements: [None; 4],
         }
     }
 
-    pub fn single(baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
-        PlayOutcome {
-            first: BaseOutcome::Runner(batter),
-            second: baserunners
-                .first()
-                .map(BaseOutcome::Runner)
-                .unwrap_or(BaseOutcome::None),
-            third: baserunners
-                .second()
-                .map(BaseOutcome::Runner)
-                .unwrap_or(BaseOutcome::None),
-            home: Self::scored(None, None, baserunners.third(), None),
+    /// The batter is thrown out at first; runners only move up when the batter-runner forces them.
+    pub fn groundout_with(baserunners: BaserunnerState, batter: BattingPosition) -> Self {
+        let mut movements = Self::forced(baserunners);
+        movements.push(RunnerMovement::new(
+            batter,
+            Base::Home,
+            Destination::ForceOut(Base::First),
+        ));
+        Self::moved(baserunners, &movements, PlayKind::Groundout)
+    }
+
+    /// A 6-4-3 double play: the runner from first is forced at second and the batter at first.
+    /// The other runners move up only if forced.
+    pub fn double_play(baserunners: BaserunnerState, batter: BattingPosition) -> Result<Self, PlayError> {
+        let on_first = Self::require(baserunners, Base::First)?;
+        let mut movements = Self::forced(baserunners);
+        movements.retain(|movement| movement.runner() != on_first);
+        movements.push(RunnerMovement::new(
+            on_first,
+            Base::First,
+            Destination::ForceOut(Base::Second),
+        ));
+        movements.push(RunnerMovement::new(
+            batter,
+            Base::Home,
+            Destination::ForceOut(Base::First),
+        ));
+        Ok(Self::moved(baserunners, &movements, PlayKind::Groundout))
+    }
+
+    /// A 5-4-3 triple play, forcing the runners from second and first and then the batter. A runner
+    /// forced home from third crosses the plate, but the run does not count on a third-out force
+    /// play (Rule 5.08(a)).
+    pub fn triple_play(baserunners: BaserunnerState, batter: BattingPosition) -> Result<Self, PlayError> {
+        let on_first = Self::require(baserunners, Base::First)?;
+        let on_second = Self::require(baserunners, Base::Second)?;
+        let mut movements = Self::forced(baserunners);
+        movements.retain(|movement| movement.from() == Base::Third);
+        movements.push(RunnerMovement::new(
+            on_second,
+            Base::Second,
+            Destination::ForceOut(Base::Third),
+        ));
+        movements.push(RunnerMovement::new(
+            on_first,
+            Base::First,
+            Destination::ForceOut(Base::Second),
+        ));
+        movements.push(RunnerMovement::new(
+            batter,
+            Base::Home,
+            Destination::ForceOut(Base::First),
+        ));
+        Ok(Self::moved(baserunners, &movements, PlayKind::Groundout))
+    }
+
+    /// The runner from first is forced at second while the batter reaches first. The other runners
+    /// move up only if forced.
+    pub fn fielders_choice(baserunners: BaserunnerState, batter: BattingPosition) -> Result<Self, PlayError> {
+        let on_first = Self::require(baserunners, Base::First)?;
+        let mut movements = Self::forced(baserunners);
+        movements.retain(|movement| movement.runner() != on_first);
+        movements.push(RunnerMovement::new(
+            on_first,
+            Base::First,
+            Destination::ForceOut(Base::Second),
+        ));
+        movements.push(RunnerMovement::new(batter, Base::Home, Destination::Safe(Base::First)));
+        Ok(Self::moved(baserunners, &movements, PlayKind::FieldersChoice))
+    }
+
+    /// A fly ball caught for an out while the runner from third tags up and scores (Rule 9.08(d)).
+    /// A runner on second tags up and takes third; a runner on first holds.
+    pub fn sacrifice_fly(baserunners: BaserunnerState, batter: BattingPosition) -> Result<Self, PlayError> {
+        let on_third = Self::require(baserunners, Base::Third)?;
+        let mut movements = vec![RunnerMovement::new(
+            on_third,
+            Base::Third,
+            Destination::Safe(Base::Home),
+        )];
+        if let Some(on_second) = baserunners.second() {
+            movements.push(RunnerMovement::new(
+                on_second,
+                Base::Second,
+                Destination::Safe(Base::Third),
+            ));
         }
+        movements.push(RunnerMovement::new(batter, Base::Home, Destination::Out(Base::Home)));
+        Ok(Self::moved(baserunners, &movements, PlayKind::SacrificeFly))
     }
 
-    pub fn double(baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
-        PlayOutcome {
-            first: BaseOutcome::None,
-            second: BaseOutcome::Runner(batter),
-            third: baserunners
-                .first()
-                .map(BaseOutcome::Runner)
-                .unwrap_or(BaseOutcome::None),
-            home: Self::scored(None, baserunners.second(), baserunners.third(), None),
+    /// A bunt on which the batter is thrown out at first and every runner moves up a base
+    /// (Rule 9.08(a)). With a runner on third this is a squeeze play and the run scores.
+    pub fn sacrifice_bunt(baserunners: BaserunnerState, batter: BattingPosition) -> Result<Self, PlayError> {
+        if baserunners.is_empty() {
+            return Err(PlayError::BasesEmpty);
         }
+        let mut movements = Self::advanced(baserunners, 1);
+        movements.push(RunnerMovement::new(
+            batter,
+            Base::Home,
+            Destination::ForceOut(Base::First),
+        ));
+        Ok(Self::moved(baserunners, &movements, PlayKind::SacrificeBunt))
     }
 
-    pub fn triple(baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
-        let home = Self::scored(baserunners.first(), baserunners.second(), baserunners.third(), None);
-        PlayOutcome {
-            first: BaseOutcome::None,
-            second: BaseOutcome::None,
-            third: BaseOutcome::Runner(batter),
-            home,
+    /// Strike three is not caught and the batter reaches first on `advancement`, forcing runners
+    /// along (Rule 5.05(a)(2)). The batter can only run with first base open or two out.
+    pub fn dropped_third_strike(
+        baserunners: BaserunnerState,
+        batter: BattingPosition,
+        outs: Outs,
+        advancement: Advancement,
+    ) -> Result<Self, PlayError> {
+        if !baserunners.batter_can_run_on_third_strike(outs) {
+            return Err(PlayError::FirstBaseOccupied);
         }
+        let mut movements: Vec<RunnerMovement> = Self::forced(baserunners)
+            .into_iter()
+            .map(|movement| movement.with_advancement(advancement))
+            .collect();
+        movements.push(
+            RunnerMovement::new(batter, Base::Home, Destination::Safe(Base::First)).with_advancement(advancement),
+        );
+        Ok(Self::moved(baserunners, &movements, PlayKind::Strikeout))
     }
 
-    pub fn homerun(baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
+    /// The batter strikes out and the runner on `from` is thrown out stealing on the same pitch.
+    pub fn strikeout_double_play(
+        baserunners: BaserunnerState,
+        batter: BattingPosition,
+        from: Base,
+    ) -> Result<Self, PlayError> {
+        let runner = Self::require(baserunners, from)?;
+        let movements = [
+            RunnerMovement::new(runner, from, Destination::Out(from.next())).with_advancement(Advancement::StolenBase),
+            RunnerMovement::new(batter, Base::Home, Destination::Out(Base::Home)),
+        ];
+        Ok(Self::moved(baserunners, &movements, PlayKind::Strikeout))
+    }
+
+    /// A fielder misplays a ball the batter should have been out on, and the batter reaches first.
+    /// Runners move up only if forced, and advance on the error too.
+    pub fn reached_on_error(baserunners: BaserunnerState, batter: BattingPosition) -> Self {
+        let mut movements: Vec<RunnerMovement> = Self::forced(baserunners)
+            .into_iter()
+            .map(|movement| movement.with_advancement(Advancement::Error))
+            .collect();
+        movements.push(
+            RunnerMovement::new(batter, Base::Home, Destination::Safe(Base::First))
+                .with_advancement(Advancement::Error),
+        );
+        Self::moved(baserunners, &movements, PlayKind::ReachedOnError)
+    }
+
+    /// The play with `runner` taking one base more than it gave them on a throwing error, such as
+    /// the batter reaching second on a wild throw to first.
+    pub fn with_throwing_error(
+        self,
+        baserunners: BaserunnerState,
+        batter: BattingPosition,
+        runner: BattingPosition,
+    ) -> Result<Self, PlayError> {
+        let mut movements = self.runner_movements(&baserunners, batter);
+        let movement = movements
+            .iter_mut()
+            .find(|movement| movement.runner() == runner)
+            .filter(|movement| !movement.is_out() && !movement.scored())
+            .ok_or(PlayError::NotLeftOnBase(runner))?;
+        *movement = RunnerMovement::new(runner, movement.from(), Destination::Safe(movement.to().base().next()))
+            .with_advancement(Advancement::Error);
+        Ok(Self::from_movements(baserunners, &movements)?.with_kind(self.kind))
+    }
+
+    pub fn flyout() -> Self {
         PlayOutcome {
             first: BaseOutcome::None,
             second: BaseOutcome::None,
             third: BaseOutcome::None,
-            home: Self::scored(
-                baserunners.first(),
-                baserunners.second(),
-                baserunners.third(),
-                Some(batter),
-            ),
+            home: HomeOutcome::new_with_outs(Outs::One),
+            kind: PlayKind::Flyout,
+            movements: [None; 4],
         }
     }
 
+    /// A caught fly ball: the batter is out and every runner holds their base.
+    pub fn flyout_with(baserunners: BaserunnerState, batter: BattingPosition) -> Self {
+        let movements = [RunnerMovement::new(batter, Base::Home, Destination::Out(Base::Home))];
+        Self::moved(baserunners, &movements, PlayKind::Flyout)
+    }
+
+    pub fn single(baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
+        Self::hit(baserunners, batter, PlayKind::Single, Base::First)
+    }
+
+    pub fn double(baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
+        Self::hit(baserunners, batter, PlayKind::Double, Base::Second)
+    }
+
+    pub fn triple(baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
+        Self::hit(baserunners, batter, PlayKind::Triple, Base::Third)
+    }
+
+    pub fn homerun(baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
+        Self::hit(baserunners, batter, PlayKind::HomeRun, Base::Home)
+    }
+
     pub fn outs(self) -> u8 {
         self.first().outs() + self.second().outs() + self.third().outs() + self.home.outs()
     }
@@ -223,12 +579,41 @@ impl PlayOutcome {
         self.home
     }
 
+    pub fn kind(self) -> PlayKind {
+        self.kind
+    }
+
+    /// The movements the play was built from, empty for a play described base by base.
+    pub fn movements(self) -> impl Iterator<Item = RunnerMovement> {
+        self.movements.into_iter().flatten()
+    }
+
+    /// Whether the batter is credited with a base hit on this play.
+    pub fn is_hit(self) -> bool {
+        self.kind.is_hit()
+    }
+
+    pub fn with_kind(self, kind: PlayKind) -> Self {
+        Self {
+            first: self.first,
+            second: self.second,
+            third: self.third,
+            home: self.home,
+            kind,
+            movements: self.movements,
+        }
+    }
+
+    /// Replaces what happens at first base. Like the other base builders, this drops the recorded
+    /// movements, which no longer match the play.
     pub fn with_first(self, first: BaseOutcome) -> Self {
         Self {
             first,
             second: self.second,
             third: self.third,
             home: self.home,
+            kind: self.kind,
+            movements: [None; 4],
         }
     }
 
@@ -238,6 +623,8 @@ impl PlayOutcome {
             second,
             third: self.third,
             home: self.home,
+            kind: self.kind,
+            movements: [None; 4],
         }
     }
 
@@ -247,6 +634,8 @@ impl PlayOutcome {
             second: self.second,
             third,
             home: self.home,
+            kind: self.kind,
+            movements: [None; 4],
         }
     }
 
@@ -256,32 +645,286 @@ impl PlayOutcome {
             second: self.second,
             third: self.third,
             home,
+            kind: self.kind,
+            movements: [None; 4],
+        }
+    }
+
+    fn require(baserunners: BaserunnerState, base: Base) -> Result<BattingPosition, PlayError> {
+        baserunners.runner_on(base).ok_or(PlayError::NoRunnerOn(base))
+    }
+
+    /// Every runner moving up `bases` bases, lead runner first.
+    fn advanced(baserunners: BaserunnerState, bases: u8) -> Vec<RunnerMovement> {
+        [Base::Third, Base::Second, Base::First]
+            .into_iter()
+            .filter_map(|base| {
+                let runner = baserunners.runner_on(base)?;
+                Some(RunnerMovement::new(
+                    runner,
+                    base,
+                    Destination::Safe(base.advance_by(bases)),
+                ))
+            })
+            .collect()
+    }
+
+    /// The runners the batter-runner forces to move up a base, lead runner first.
+    fn forced(baserunners: BaserunnerState) -> Vec<RunnerMovement> {
+        let forced = match (baserunners.first(), baserunners.second(), baserunners.third()) {
+            (None, _, _) => 0,
+            (Some(_), None, _) => 1,
+            (Some(_), Some(_), None) => 2,
+            (Some(_), Some(_), Some(_)) => 3,
+        };
+        let mut movements = Self::advanced(baserunners, 1);
+        movements.retain(|movement| base_number(movement.from()) <= forced);
+        movements
+    }
+
+    /// The batter reaching `base` on a hit, with every runner moving up as many bases.
+    fn hit(baserunners: BaserunnerState, batter: BattingPosition, kind: PlayKind, base: Base) -> Self {
+        let mut movements = Self::advanced(baserunners, base_number(base));
+        movements.push(RunnerMovement::new(batter, Base::Home, Destination::Safe(base)));
+        Self::moved(baserunners, &movements, kind)
+    }
+
+    /// Builds the play from movements that are already known to fit `baserunners`. An out at a base
+    /// a runner ends up on anyway is counted with the outs at home.
+    fn moved(baserunners: BaserunnerState, movements: &[RunnerMovement], kind: PlayKind) -> Self {
+        let (after, runs) = baserunners.moved(movements);
+        let mut bases = [after.first, after.second, after.third].map(|runner| match runner {
+            Some(runner) => BaseOutcome::Runner(runner),
+            None => BaseOutcome::None,
+        });
+        let mut outs = Outs::Zero;
+        for movement in movements {
+            let out = match movement.to() {
+                Destination::Safe(_) => continue,
+                Destination::ForceOut(base) => (base, BaseOutcome::ForceOut),
+                Destination::Out(base) => (base, BaseOutcome::TagOut),
+            };
+            match out {
+                (Base::Home, _) => outs = outs.add_out(),
+                (base, out) => match &mut bases[base_number(base) as usize - 1] {
+                    slot @ BaseOutcome::None => *slot = out,
+                    _ => outs = outs.add_out(),
+                },
+            }
+        }
+
+        let mut recorded = [None; 4];
+        for (slot, movement) in recorded.iter_mut().zip(movements) {
+            *slot = Some(*movement);
+        }
+        let [first, second, third] = bases;
+        PlayOutcome {
+            first,
+            second,
+            third,
+            home: HomeOutcome::new(runs, outs),
+            kind,
+            movements: recorded,
         }
     }
 
-    fn scored(
-        first: Option<BattingPosition>,
-        second: Option<BattingPosition>,
-        third: Option<BattingPosition>,
-        batter: Option<BattingPosition>,
-    ) -> HomeOutcome {
-        match (first, second, third, batter) {
-            (None, None, None, None) => HomeOutcome::none(),
-            (None, None, None, Some(_)) => HomeOutcome::new_with_runs(0),
-            (None, None, Some(_), None) => HomeOutcome::new_with_runs(1),
-            (None, None, Some(_), Some(_)) => HomeOutcome::new_with_runs(2),
-            (None, Some(_), None, None) => HomeOutcome::new_with_runs(1),
-            (None, Some(_), None, Some(_)) => HomeOutcome::new_with_runs(2),
-            (None, Some(_), Some(_), None) => HomeOutcome::new_with_runs(2),
-            (None, Some(_), Some(_), Some(_)) => HomeOutcome::new_with_runs(3),
-            (Some(_), None, None, None) => HomeOutcome::new_with_runs(1),
-            (Some(_), None, None, Some(_)) => HomeOutcome::new_with_runs(2),
-            (Some(_), None, Some(_), None) => HomeOutcome::new_with_runs(2),
-            (Some(_), None, Some(_), Some(_)) => HomeOutcome::new_with_runs(3),
-            (Some(_), Some(_), None, None) => HomeOutcome::new_with_runs(2),
-            (Some(_), Some(_), None, Some(_)) => HomeOutcome::new_with_runs(3),
-            (Some(_), Some(_), Some(_), None) => HomeOutcome::new_with_runs(3),
-            (Some(_), Some(_), Some(_), Some(_)) => HomeOutcome::new_with_runs(4),
+    /// Each runner's part in the play, lead runner first and the batter last. For a play described
+    /// base by base, runs are credited lead runner first and outs go to the nearest base marked
+    /// with an out ahead of the runner, otherwise home plate.
+    pub fn runner_movements(self, baserunners: &BaserunnerState, batter: BattingPosition) -> Vec<RunnerMovement> {
+        if self.movements[0].is_some() {
+            let mut movements: Vec<RunnerMovement> = self.movements().collect();
+            movements.sort_by_key(|movement| std::cmp::Reverse(start_number(movement.from())));
+            return movements;
+        }
+
+        let after = self.baserunners();
+        let mut runs = self.runs_scored();
+        let mut marked_outs: Vec<(Base, BaseOutcome)> = [
+            (Base::First, self.first),
+            (Base::Second, self.second),
+            (Base::Third, self.third),
+        ]
+        .into_iter()
+        .filter(|(_, outcome)| outcome.is_out())
+        .collect();
+
+        let mut movements = Vec::new();
+        for (runner, from) in [
+            (baserunners.third(), Base::Third),
+            (baserunners.second(), Base::Second),
+            (baserunners.first(), Base::First),
+            (Some(batter), Base::Home),
+        ] {
+            let Some(runner) = runner else {
+                continue;
+            };
+            let to = match after.base_of(runner) {
+                Some(base) if base == from => continue,
+                Some(base) => Destination::Safe(base),
+                None if runs > 0 => {
+                    runs -= 1;
+                    Destination::Safe(Base::Home)
+                }
+                None => {
+                    let ahead = marked_outs
+                        .iter()
+                        .position(|(base, _)| base_number(*base) > start_number(from));
+                    match ahead.map(|index| marked_outs.remove(index)) {
+                        Some((base, BaseOutcome::ForceOut)) => Destination::ForceOut(base),
+                        Some((base, _)) => Destination::Out(base),
+                        None => Destination::Out(Base::Home),
+                    }
+                }
+            };
+            movements.push(RunnerMovement::new(runner, from, to));
+        }
+        movements
+    }
+
+    /// The play as scored when it ends the game after `runs_needed` runs, with `outs` already
+    /// recorded before it (Rule 7.01(g)). Only the runs needed to win count, and the batter and
+    /// trailing runners are credited with no more bases than the winning run advanced (Rule
+    /// 9.06(f)). Runs score lead runner first. The third out and any after it were made once the
+    /// game was over, so they do not stand.
+    pub fn walk_off(
+        self,
+        baserunners: BaserunnerState,
+        batter: BattingPosition,
+        outs: Outs,
+        runs_needed: Runs,
+    ) -> PlayOutcome {
+        let movements = self.runner_movements(&baserunners, batter);
+        let recorded: Vec<RunnerMovement> = match self.movements[0] {
+            Some(_) => self.movements().collect(),
+            None => movements.clone(),
+        };
+        let outs_to_go = 3usize.saturating_sub(outs.as_number() as usize).max(1);
+        let ended_outs: Vec<BattingPosition> = recorded
+            .iter()
+            .filter(|movement| movement.to().is_out())
+            .skip(outs_to_go - 1)
+            .map(|movement| movement.runner())
+            .collect();
+        let runs = self.runs_scored();
+        if runs_needed == 0 || runs < runs_needed || (runs == runs_needed && ended_outs.is_empty()) {
+            return self;
+        }
+
+        let scorers: Vec<RunnerMovement> = movements.iter().copied().filter(|movement| movement.scored()).collect();
+
+        // A play that scores more runs than it has runners only loses the extra runs
+        let Some(winning_run) = scorers.get(runs_needed as usize - 1) else {
+            return self.with_home(HomeOutcome::new(runs_needed, self.home.outs));
+        };
+        let winning_run_advance = 4 - start_number(winning_run.from());
+        let credited_bases = match self.kind {
+            PlayKind::Single => winning_run_advance.min(1),
+            PlayKind::Double => winning_run_advance.min(2),
+            PlayKind::Triple => winning_run_advance.min(3),
+            _ => winning_run_advance,
+        };
+        let kind = match (self.kind.is_hit(), credited_bases) {
+            (false, _) => self.kind,
+            (true, 1) => PlayKind::Single,
+            (true, 2) => PlayKind::Double,
+            (true, 3) => PlayKind::Triple,
+            (true, _) => PlayKind::HomeRun,
+        };
+
+        let credited = walk_off_movements(&movements, runs_needed, credited_bases, &ended_outs);
+        Self::moved(baserunners, &credited, kind)
+    }
+
+    /// Checks that the play can happen with `baserunners` on base and `batter` at the plate: every
+    /// runner and the batter ends up on base, across the plate or out, nobody appears from nowhere,
+    /// and runners only move forward without passing one another.
+    pub fn validate(self, baserunners: &BaserunnerState, batter: BattingPosition) -> Result<(), PlayError> {
+        // The base each runner starts from, counting home plate as 0 for the batter
+        let starts = [
+            (Some(batter), 0),
+            (baserunners.first(), 1),
+            (baserunners.second(), 2),
+            (baserunners.third(), 3),
+        ];
+        let start_of = |runner: BattingPosition| {
+            starts
+                .iter()
+                .find_map(|(on_base, start)| (*on_base == Some(runner)).then_some(*start))
+        };
+
+        for movement in self.movements() {
+            if start_of(movement.runner()) != Some(start_number(movement.from())) {
+                return Err(PlayError::UnknownRunner(movement.runner()));
+            }
+        }
+
+        let mut placed: Vec<u8> = Vec::new();
+        for (outcome, end) in [(self.first, 1), (self.second, 2), (self.third, 3)] {
+            let Some(runner) = outcome.as_basrunner() else {
+                continue;
+            };
+            let start = start_of(runner).ok_or(PlayError::UnknownRunner(runner))?;
+            if placed.contains(&start) {
+                return Err(PlayError::DuplicateRunner(runner));
+            }
+            if end < start {
+                return Err(PlayError::RunnerMovedBack(runner));
+            }
+            if placed.last().is_some_and(|previous| *previous > start) {
+                return Err(PlayError::RunnerPassed(runner));
+            }
+            placed.push(start);
+        }
+
+        let outs = self.outs();
+        if outs > 3 {
+            return Err(PlayError::TooManyOuts(outs));
+        }
+        let runners = baserunners.runner_count() + 1;
+        let accounted_for = placed.len() as u8 + self.runs_scored() + outs;
+        if accounted_for != runners {
+            return Err(PlayError::RunnersNotConserved { runners, accounted_for });
+        }
+        Ok(())
+    }
+
+    /// Whether the batter grounded into a force or reverse force double play, which earns no run
+    /// batted in (Rule 9.04(b)(1)): a ground ball on which two or more are put out, at least one of
+    /// them by force.
+    pub fn grounded_into_double_play(self) -> bool {
+        let ground_ball = matches!(self.kind, PlayKind::Groundout | PlayKind::FieldersChoice);
+        let force_out = [self.first, self.second, self.third].contains(&BaseOutcome::ForceOut)
+            || self
+                .movements()
+                .any(|movement| matches!(movement.to(), Destination::ForceOut(_)));
+        ground_ball && self.outs() >= 2 && force_out
+    }
+
+    /// Whether the runs on this play still count when it makes the third out, with `outs` already
+    /// recorded before it. They do not when the third out is a force out or the batter-runner put
+    /// out before reaching first (Rule 5.08(a)); a third out on any other runner leaves the runs
+    /// that crossed ahead of it. The outs are made in the order the movements were recorded. A play
+    /// described base by base has no such order, so any force out on it voids the runs.
+    pub fn scores_on_third_out(self, batter: BattingPosition, outs: Outs) -> bool {
+        if self.movements[0].is_none() {
+            let force_out = [self.first, self.second, self.third].contains(&BaseOutcome::ForceOut);
+            let batter_reached_first = self.is_hit() || self.baserunners().base_of(batter).is_some();
+            return !force_out && batter_reached_first;
+        }
+
+        let outs_to_go = 3usize.saturating_sub(outs.as_number() as usize).max(1);
+        let Some(third_out) = self
+            .movements()
+            .filter(|movement| movement.to().is_out())
+            .nth(outs_to_go - 1)
+        else {
+            return true;
+        };
+        match third_out.to() {
+            Destination::ForceOut(_) => false,
+            Destination::Out(Base::First | Base::Home) => third_out.runner() != batter,
+            _ => true,
         }
     }
 
@@ -299,6 +942,7 @@ impl PlayOutcome {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BaserunnerState {
     first: Option<BattingPosition>,
     second: Option<BattingPosition>,
@@ -363,6 +1007,27 @@ impl BaserunnerState {
         count
     }
 
+    pub fn runner_on(&self, base: Base) -> Option<BattingPosition> {
+        match base {
+            Base::First => self.first,
+            Base::Second => self.second,
+            Base::Third => self.third,
+            Base::Home => None,
+        }
+    }
+
+    /// The base `runner` is standing on, if they are on base at all.
+    pub fn base_of(&self, runner: BattingPosition) -> Option<Base> {
+        [Base::First, Base::Second, Base::Third]
+            .into_iter()
+            .find(|base| self.runner_on(*base) == Some(runner))
+    }
+
+    /// Whether a batter whose third strike is not caught may run to first (Rule 5.05(a)(2)).
+    pub fn batter_can_run_on_third_strike(&self, outs: Outs) -> bool {
+        !self.has_runner_on(Base::First) || outs == Outs::Two
+    }
+
     pub fn has_runner_on(&self, base: Base) -> bool {
         match base {
             Base::First => self.first.is_some(),
@@ -372,20 +1037,111 @@ impl BaserunnerState {
         }
     }
 
-    pub fn walk(&self, batter: BattingPosition) -> (BaserunnerState, Runs) {
-        let mut new_state = BaserunnerState::new().set_first(Some(batter));
-        let mut runs_scored = Runs::default();
+    /// Moves the runners as `movements` describe, the batter starting from home plate. Runners
+    /// without a movement hold their base. Returns the runners left on base and the runs scored.
+    pub fn apply(&self, movements: &[RunnerMovement]) -> Result<(BaserunnerState, Runs), PlayError> {
+        for (index, movement) in movements.iter().enumerate() {
+            let runner = movement.runner();
+            if movements[..index].iter().any(|earlier| earlier.runner() == runner) {
+                return Err(PlayError::DuplicateRunner(runner));
+            }
+            if movement.from() != Base::Home && self.runner_on(movement.from()) != Some(runner) {
+                return Err(PlayError::UnknownRunner(runner));
+            }
+            if base_number(movement.to().base()) < start_number(movement.from()) {
+                return Err(PlayError::RunnerMovedBack(runner));
+            }
+        }
 
-        if let Some(runner) = self.first {
-            new_state = new_state.set_second(Some(runner));
+        let (after, runs) = self.moved(movements);
+        let safe = movements
+            .iter()
+            .filter(|movement| !movement.is_out() && !movement.scored())
+            .map(|movement| movement.to().base());
+        let holding = [Base::First, Base::Second, Base::Third]
+            .into_iter()
+            .filter(|base| self.has_runner_on(*base))
+            .filter(|base| !movements.iter().any(|movement| movement.from() == *base));
+        let mut occupied: Vec<Base> = Vec::new();
+        for base in safe.chain(holding) {
+            if occupied.contains(&base) {
+                return Err(PlayError::BaseOccupied(base));
+            }
+            occupied.push(base);
         }
-        if let Some(runner) = self.second {
-            new_state = new_state.set_third(Some(runner));
+        Ok((after, runs))
+    }
+
+    /// Moves the runners without checking the movements fit.
+    fn moved(&self, movements: &[RunnerMovement]) -> (BaserunnerState, Runs) {
+        let mut bases = [self.first, self.second, self.third];
+        for movement in movements {
+            if movement.from() != Base::Home {
+                bases[base_number(movement.from()) as usize - 1] = None;
+            }
         }
-        if self.third.is_some() {
-            runs_scored += 1;
+
+        let mut runs = 0;
+        for movement in movements {
+            match movement.to() {
+                Destination::Safe(Base::Home) => runs += 1,
+                Destination::Safe(base) => bases[base_number(base) as usize - 1] = Some(movement.runner()),
+                Destination::ForceOut(_) | Destination::Out(_) => {}
+            }
         }
 
+        let [first, second, third] = bases;
+        (BaserunnerState { first, second, third }, runs)
+    }
+
+    /// Awards the batter first base on a base on balls (Rule 5.05(b)). Only runners
+    /// forced by the batter-runner move up; everyone else holds.
+    pub fn walk(&self, batter: BattingPosition) -> (BaserunnerState, Runs) {
+        let first = Some(batter);
+        match (self.first, self.second, self.third) {
+            (None, second, third) => (BaserunnerState { first, second, third }, 0),
+            (Some(runner), None, third) => (
+                BaserunnerState {
+                    first,
+                    second: Some(runner),
+                    third,
+                },
+                0,
+            ),
+            (Some(on_first), Some(on_second), None) => (
+                BaserunnerState {
+                    first,
+                    second: Some(on_first),
+                    third: Some(on_second),
+                },
+                0,
+            ),
+            (Some(on_first), Some(on_second), Some(_)) => (
+                BaserunnerState {
+                    first,
+                    second: Some(on_first),
+                    third: Some(on_second),
+                },
+                1,
+            ),
+        }
+    }
+
+    /// A batter hit by a pitch is awarded first base exactly like a walk (Rule 5.05(c)).
+    pub fn hit_by_pitch(&self, batter: BattingPosition) -> (BaserunnerState, Runs) {
+        self.walk(batter)
+    }
+
+    /// On catcher's interference the batter is awarded first base and every runner
+    /// advances one base, forced or not (Rule 5.05(d)).
+    pub fn catcher_interference(&self, batter: BattingPosition) -> (BaserunnerState, Runs) {
+        let new_state = BaserunnerState {
+            first: Some(batter),
+            second: self.first,
+            third: self.second,
+        };
+        let runs_scored = if self.third.is_some() { 1 } else { 0 };
+
         (new_state, runs_scored)
     }
 
@@ -449,6 +1205,116 @@ mod tests {
         assert!(state.has_runner_on(Base::First));
         assert!(!state.has_runner_on(Base::Second));
         assert!(state.has_runner_on(Base::Third));
+        assert_eq!(state.runner_on(Base::First), Some(BattingPosition::First));
+        assert_eq!(state.runner_on(Base::Second), None);
+        assert_eq!(state.runner_on(Base::Home), None);
+    }
+
+    /// Every base state, keyed by which of first, second and third are occupied.
+    fn all_base_states() -> [(bool, bool, bool); 8] {
+        [
+            (false, false, false),
+            (true, false, false),
+            (false, true, false),
+            (false, false, true),
+            (true, true, false),
+            (true, false, true),
+            (false, true, true),
+            (true, true, true),
+        ]
+    }
+
+    fn base_state((first, second, third): (bool, bool, bool)) -> BaserunnerState {
+        BaserunnerState::new()
+            .set_first(first.then_some(BattingPosition::First))
+            .set_second(second.then_some(BattingPosition::Second))
+            .set_third(third.then_some(BattingPosition::Third))
+    }
+
+    #[test]
+    fn test_walk_advances_only_forced_runners() {
+        let batter = BattingPosition::Fourth;
+
+        for occupied in all_base_states() {
+            let (after, runs) = base_state(occupied).walk(batter);
+
+            let expected = match occupied {
+                (false, second, third) => (base_state((false, second, third)).set_first(Some(batter)), 0),
+                (true, false, third) => (
+                    base_state((false, false, third))
+                        .set_first(Some(batter))
+                        .set_second(Some(BattingPosition::First)),
+                    0,
+                ),
+                (true, true, false) => (
+                    BaserunnerState::new()
+                        .set_first(Some(batter))
+                        .set_second(Some(BattingPosition::First))
+                        .set_third(Some(BattingPosition::Second)),
+                    0,
+                ),
+                (true, true, true) => (
+                    BaserunnerState::new()
+                        .set_first(Some(batter))
+                        .set_second(Some(BattingPosition::First))
+                        .set_third(Some(BattingPosition::Second)),
+                    1,
+                ),
+            };
+
+            assert_eq!((after, runs), expected, "walk with bases {:?}", occupied);
+        }
+    }
+
+    #[test]
+    fn test_walk_does_not_move_unforced_runners() {
+        let runners = BaserunnerState::new()
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::Third));
+
+        let (after, runs) = runners.walk(BattingPosition::Fourth);
+
+        assert_eq!(after.first(), Some(BattingPosition::Fourth));
+        assert_eq!(after.second(), Some(BattingPosition::Second));
+        assert_eq!(after.third(), Some(BattingPosition::Third));
+        assert_eq!(runs, 0);
+    }
+
+    #[test]
+    fn test_hit_by_pitch_matches_walk() {
+        for occupied in all_base_states() {
+            let runners = base_state(occupied);
+            assert_eq!(
+                runners.hit_by_pitch(BattingPosition::Fourth),
+                runners.walk(BattingPosition::Fourth),
+                "hit by pitch with bases {:?}",
+                occupied
+            );
+        }
+    }
+
+    #[test]
+    fn test_catcher_interference_advances_every_runner() {
+        let batter = BattingPosition::Fourth;
+
+        for occupied @ (first, second, third) in all_base_states() {
+            let (after, runs) = base_state(occupied).catcher_interference(batter);
+
+            assert_eq!(after.first(), Some(batter), "bases {:?}", occupied);
+            assert_eq!(
+                after.second(),
+                first.then_some(BattingPosition::First),
+                "bases {:?}",
+                occupied
+            );
+            assert_eq!(
+                after.third(),
+                second.then_some(BattingPosition::Second),
+                "bases {:?}",
+                occupied
+            );
+            assert_eq!(runs, if third { 1 } else { 0 }, "bases {:?}", occupied);
+        }
     }
 
     #[test]
@@ -524,6 +1390,154 @@ mod tests {
         assert_eq!(groundout.outs(), 1);
     }
 
+    #[test]
+    fn test_play_outcome_groundout_with_runners() {
+        let groundout = PlayOutcome::groundout();
+        assert_eq!(groundout.first(), BaseOutcome::ForceOut);
+        assert_eq!(groundout.outs(), 1);
+
+        // Only forced runners move up
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Second));
+        let groundout = PlayOutcome::groundout_with(baserunners, BattingPosition::Third);
+        assert_eq!(groundout.second(), BaseOutcome::Runner(BattingPosition::First));
+        assert_eq!(groundout.third(), BaseOutcome::Runner(BattingPosition::Second));
+        assert_eq!(groundout.runs_scored(), 0);
+
+        let bases_loaded = baserunners.set_second(Some(BattingPosition::Third));
+        assert_eq!(
+            PlayOutcome::groundout_with(bases_loaded, BattingPosition::Fourth).runs_scored(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_play_outcome_double_play() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Second));
+
+        let double_play = PlayOutcome::double_play(baserunners, BattingPosition::Third).expect("Runner on first");
+        assert_eq!(double_play.first(), BaseOutcome::ForceOut);
+        assert_eq!(double_play.second(), BaseOutcome::ForceOut);
+        assert_eq!(double_play.third(), BaseOutcome::Runner(BattingPosition::Second));
+        assert_eq!(double_play.runs_scored(), 0);
+        assert_eq!(double_play.outs(), 2);
+        assert_eq!(double_play.kind(), PlayKind::Groundout);
+        assert!(double_play.grounded_into_double_play());
+
+        let second = BaserunnerState::new().set_second(Some(BattingPosition::First));
+        assert_eq!(
+            PlayOutcome::double_play(second, BattingPosition::Second),
+            Err(PlayError::NoRunnerOn(Base::First))
+        );
+    }
+
+    #[test]
+    fn test_play_outcome_triple_play() {
+        let bases_loaded = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::Third));
+
+        let triple_play =
+            PlayOutcome::triple_play(bases_loaded, BattingPosition::Fourth).expect("Runners on first and second");
+        assert_eq!(triple_play.outs(), 3);
+        assert_eq!(triple_play.runs_scored(), 1);
+        assert!(!triple_play.scores_on_third_out(BattingPosition::Fourth, Outs::Zero));
+
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::First));
+        assert_eq!(
+            PlayOutcome::triple_play(first, BattingPosition::Second),
+            Err(PlayError::NoRunnerOn(Base::Second))
+        );
+    }
+
+    #[test]
+    fn test_play_outcome_fielders_choice() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second));
+        let batter = BattingPosition::Third;
+
+        let fielders_choice = PlayOutcome::fielders_choice(baserunners, batter).expect("Runner on first");
+        assert_eq!(fielders_choice.first(), BaseOutcome::Runner(batter));
+        assert_eq!(fielders_choice.second(), BaseOutcome::ForceOut);
+        assert_eq!(fielders_choice.third(), BaseOutcome::Runner(BattingPosition::Second));
+        assert_eq!(fielders_choice.outs(), 1);
+        assert_eq!(fielders_choice.kind(), PlayKind::FieldersChoice);
+        assert!(!fielders_choice.is_hit());
+
+        assert_eq!(
+            PlayOutcome::fielders_choice(BaserunnerState::empty(), batter),
+            Err(PlayError::NoRunnerOn(Base::First))
+        );
+    }
+
+    #[test]
+    fn test_play_outcome_sacrifice_fly() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::Third));
+
+        let sacrifice_fly = PlayOutcome::sacrifice_fly(baserunners, BattingPosition::Fourth).expect("Runner on third");
+        assert_eq!(sacrifice_fly.first(), BaseOutcome::Runner(BattingPosition::First));
+        assert_eq!(sacrifice_fly.second(), BaseOutcome::None);
+        assert_eq!(sacrifice_fly.third(), BaseOutcome::Runner(BattingPosition::Second));
+        assert_eq!(sacrifice_fly.runs_scored(), 1);
+        assert_eq!(sacrifice_fly.outs(), 1);
+        assert!(sacrifice_fly.kind().is_sacrifice());
+        assert!(!sacrifice_fly.scores_on_third_out(BattingPosition::Fourth, Outs::Two));
+
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::First));
+        assert_eq!(
+            PlayOutcome::sacrifice_fly(first, BattingPosition::Second),
+            Err(PlayError::NoRunnerOn(Base::Third))
+        );
+    }
+
+    #[test]
+    fn test_play_outcome_sacrifice_bunt() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Second));
+
+        let squeeze = PlayOutcome::sacrifice_bunt(baserunners, BattingPosition::Third).expect("Runners on base");
+        assert_eq!(squeeze.first(), BaseOutcome::ForceOut);
+        assert_eq!(squeeze.second(), BaseOutcome::Runner(BattingPosition::First));
+        assert_eq!(squeeze.third(), BaseOutcome::None);
+        assert_eq!(squeeze.runs_scored(), 1);
+        assert_eq!(squeeze.kind(), PlayKind::SacrificeBunt);
+
+        assert_eq!(
+            PlayOutcome::sacrifice_bunt(BaserunnerState::empty(), BattingPosition::First),
+            Err(PlayError::BasesEmpty)
+        );
+    }
+
+    #[test]
+    fn test_play_outcome_flyout() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::Fourth));
+
+        let flyout = PlayOutcome::flyout_with(baserunners, BattingPosition::Fifth);
+
+        assert_eq!(flyout.first(), BaseOutcome::Runner(BattingPosition::Second));
+        assert_eq!(flyout.second(), BaseOutcome::None);
+        assert_eq!(flyout.third(), BaseOutcome::Runner(BattingPosition::Fourth));
+        assert_eq!(flyout.runs_scored(), 0);
+        assert_eq!(flyout.outs(), 1);
+        assert_eq!(flyout.baserunners(), baserunners);
+
+        let flyout = PlayOutcome::flyout();
+        assert_eq!(flyout.kind(), PlayKind::Flyout);
+        assert_eq!(flyout.outs(), 1);
+        assert_eq!(flyout.baserunners(), BaserunnerState::empty());
+    }
+
     #[test]
     fn test_play_outcome_single() {
         let baserunners = BaserunnerState::new()
@@ -611,4 +1625,232 @@ mod tests {
         assert_eq!(baserunners.second(), None);
         assert_eq!(baserunners.third(), Some(BattingPosition::Third));
     }
+
+    #[test]
+    fn test_validate_play() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second));
+        let batter = BattingPosition::Third;
+
+        assert_eq!(
+            PlayOutcome::single(baserunners, batter).validate(&baserunners, batter),
+            Ok(())
+        );
+        assert_eq!(
+            PlayOutcome::homerun(baserunners, batter).validate(&baserunners, batter),
+            Ok(())
+        );
+        let double_play = PlayOutcome::double_play(baserunners, BattingPosition::Third).expect("Runner on first");
+        assert_eq!(double_play.validate(&baserunners, batter), Ok(()));
+
+        let duplicate = PlayOutcome::single(baserunners, batter).with_third(BaseOutcome::Runner(batter));
+        assert_eq!(
+            duplicate.validate(&baserunners, batter),
+            Err(PlayError::DuplicateRunner(batter))
+        );
+
+        let moved_back = PlayOutcome::flyout_with(baserunners, batter)
+            .with_first(BaseOutcome::Runner(BattingPosition::Second))
+            .with_second(BaseOutcome::Runner(BattingPosition::First));
+        assert_eq!(
+            moved_back.validate(&baserunners, batter),
+            Err(PlayError::RunnerMovedBack(BattingPosition::Second))
+        );
+
+        let passed = PlayOutcome::flyout_with(baserunners, batter)
+            .with_first(BaseOutcome::None)
+            .with_third(BaseOutcome::Runner(BattingPosition::First));
+        assert_eq!(
+            passed.validate(&baserunners, batter),
+            Err(PlayError::RunnerPassed(BattingPosition::First))
+        );
+
+        let vanished = PlayOutcome::flyout_with(baserunners, batter).with_first(BaseOutcome::None);
+        assert_eq!(
+            vanished.validate(&baserunners, batter),
+            Err(PlayError::RunnersNotConserved {
+                runners: 3,
+                accounted_for: 2
+            })
+        );
+
+        let too_many_outs = PlayOutcome::double_play(baserunners, batter)
+            .expect("Runner on first")
+            .with_third(BaseOutcome::TagOut)
+            .with_home(HomeOutcome::new_with_outs(Outs::One));
+        assert_eq!(
+            too_many_outs.validate(&baserunners, batter),
+            Err(PlayError::TooManyOuts(4))
+        );
+    }
+
+    #[test]
+    fn test_apply_runner_movements() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second));
+        let batter = BattingPosition::Third;
+        let scores = RunnerMovement::new(BattingPosition::Second, Base::Second, Destination::Safe(Base::Home));
+        let to_third = RunnerMovement::new(BattingPosition::First, Base::First, Destination::Safe(Base::Third));
+        let batter_to_first = RunnerMovement::new(batter, Base::Home, Destination::Safe(Base::First));
+
+        let (after, runs) = baserunners
+            .apply(&[scores, to_third, batter_to_first])
+            .expect("Movements fit the runners");
+        assert_eq!(runs, 1);
+        assert_eq!(after.first(), Some(batter));
+        assert_eq!(after.second(), None);
+        assert_eq!(after.third(), Some(BattingPosition::First));
+
+        assert_eq!(
+            baserunners.apply(&[to_third, to_third]),
+            Err(PlayError::DuplicateRunner(BattingPosition::First))
+        );
+        let not_on_third = RunnerMovement::new(BattingPosition::Second, Base::Third, Destination::Safe(Base::Home));
+        assert_eq!(
+            baserunners.apply(&[not_on_third]),
+            Err(PlayError::UnknownRunner(BattingPosition::Second))
+        );
+        assert_eq!(
+            baserunners.apply(&[batter_to_first]),
+            Err(PlayError::BaseOccupied(Base::First))
+        );
+        let back_to_first = RunnerMovement::new(BattingPosition::Second, Base::Second, Destination::Safe(Base::First));
+        assert_eq!(
+            baserunners.apply(&[back_to_first, to_third, batter_to_first]),
+            Err(PlayError::RunnerMovedBack(BattingPosition::Second))
+        );
+    }
+
+    #[test]
+    fn test_play_from_movements() {
+        // Single with the lead runner thrown out at home while the trailing runner scores from second
+        let baserunners = BaserunnerState::new()
+            .set_second(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Second));
+        let batter = BattingPosition::Third;
+        let movements = [
+            RunnerMovement::new(BattingPosition::Second, Base::Third, Destination::Out(Base::Home)),
+            RunnerMovement::new(BattingPosition::First, Base::Second, Destination::Safe(Base::Home))
+                .with_advancement(Advancement::Throw),
+            RunnerMovement::new(batter, Base::Home, Destination::Safe(Base::First)),
+        ];
+
+        let play = PlayOutcome::from_movements(baserunners, &movements)
+            .expect("Movements fit the runners")
+            .with_kind(PlayKind::Single);
+        assert_eq!(play.outs(), 1);
+        assert_eq!(play.runs_scored(), 1);
+        assert_eq!(play.baserunners(), BaserunnerState::new().set_first(Some(batter)));
+        assert_eq!(play.validate(&baserunners, batter), Ok(()));
+        assert_eq!(play.runner_movements(&baserunners, batter), movements);
+    }
+
+    #[test]
+    fn test_reached_on_error() {
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::First));
+        let batter = BattingPosition::Second;
+        let error = PlayOutcome::reached_on_error(first, batter);
+        assert_eq!(error.kind(), PlayKind::ReachedOnError);
+        assert!(!error.is_hit());
+        assert_eq!(error.outs(), 0);
+        assert_eq!(
+            error.baserunners(),
+            first.set_first(Some(batter)).set_second(Some(BattingPosition::First))
+        );
+        assert!(
+            error
+                .movements()
+                .all(|movement| movement.advancement() == Advancement::Error)
+        );
+    }
+
+    #[test]
+    fn test_extra_base_on_a_throwing_error() {
+        let second = BaserunnerState::new().set_second(Some(BattingPosition::First));
+        let batter = BattingPosition::Second;
+
+        // The runner from second goes to third on the single and scores on the wild throw
+        let play = PlayOutcome::single(second, batter)
+            .with_throwing_error(second, batter, BattingPosition::First)
+            .expect("Runner left on third");
+        assert_eq!(play.kind(), PlayKind::Single);
+        assert_eq!(play.runs_scored(), 1);
+        let scored = play.movements().find(|movement| movement.scored());
+        assert_eq!(scored.map(|movement| movement.advancement()), Some(Advancement::Error));
+
+        // The batter can take second too once the runner ahead has scored
+        let play = play
+            .with_throwing_error(second, batter, batter)
+            .expect("Batter left on first");
+        assert_eq!(play.baserunners(), BaserunnerState::new().set_second(Some(batter)));
+        assert_eq!(
+            play.with_throwing_error(second, batter, BattingPosition::First),
+            Err(PlayError::NotLeftOnBase(BattingPosition::First))
+        );
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::First));
+        assert_eq!(
+            PlayOutcome::single(first, batter).with_throwing_error(first, batter, batter),
+            Err(PlayError::BaseOccupied(Base::Second))
+        );
+    }
+
+    #[test]
+    fn test_inferred_runner_movements() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Second));
+        let batter = BattingPosition::Third;
+        let double_play = PlayOutcome::new(
+            BaseOutcome::ForceOut,
+            BaseOutcome::ForceOut,
+            BaseOutcome::None,
+            HomeOutcome::new_with_runs(1),
+        );
+
+        assert_eq!(
+            double_play.runner_movements(&baserunners, batter),
+            vec![
+                RunnerMovement::new(BattingPosition::Second, Base::Third, Destination::Safe(Base::Home)),
+                RunnerMovement::new(BattingPosition::First, Base::First, Destination::ForceOut(Base::Second)),
+                RunnerMovement::new(batter, Base::Home, Destination::ForceOut(Base::First)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_off_double_scored_as_single() {
+        let loaded = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Third))
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::First));
+        let batter = BattingPosition::Fourth;
+
+        let walk_off = PlayOutcome::double(loaded, batter).walk_off(loaded, batter, Outs::Zero, 1);
+        assert_eq!(walk_off.kind(), PlayKind::Single);
+        assert_eq!(walk_off.runs_scored(), 1);
+        assert_eq!(walk_off.outs(), 0);
+        assert_eq!(walk_off.first(), BaseOutcome::Runner(batter));
+        assert_eq!(walk_off.second(), BaseOutcome::Runner(BattingPosition::Third));
+        assert_eq!(walk_off.third(), BaseOutcome::Runner(BattingPosition::Second));
+
+        // Both runs are needed, so the double stands
+        let double = PlayOutcome::double(loaded, batter);
+        assert_eq!(double.walk_off(loaded, batter, Outs::Zero, 2), double);
+    }
+
+    #[test]
+    fn test_walk_off_inside_the_park_home_run() {
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::Eighth));
+        let batter = BattingPosition::Ninth;
+
+        // The winning run scores from first, so the batter is credited with a triple
+        let walk_off = PlayOutcome::homerun(first, batter)
+            .with_home(HomeOutcome::new_with_runs(2))
+            .walk_off(first, batter, Outs::Zero, 1);
+        assert_eq!(walk_off.kind(), PlayKind::Triple);
+        assert_eq!(walk_off.runs_scored(), 1);
+        assert_eq!(walk_off.baserunners().third(), Some(batter));
+    }
 }
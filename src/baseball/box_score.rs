@@ -0,0 +1,805 @@
+use std::fmt::Display;
+
+use crate::{
+    Runs,
+    baseball::{
+        earned_runs::{ChargedRun, EarnedRuns},
+        events::{GameEvent, PitchEvent},
+        game::{GameSummary, Team},
+        inning::InningHalf,
+        lineup::{BattingPosition, Player},
+        movement::Advancement,
+        pitching::Decision,
+        plate_appearance::{PitchOutcome, PlateAppearanceResult},
+    },
+};
+
+/// Outs recorded by a pitcher, shown the traditional way: `6.1` is six innings and one out.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InningsPitched {
+    outs: u16,
+}
+
+impl Display for InningsPitched {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.outs / 3, self.outs % 3)
+    }
+}
+
+impl InningsPitched {
+    pub fn from_outs(outs: u16) -> Self {
+        InningsPitched { outs }
+    }
+
+    pub fn outs(&self) -> u16 {
+        self.outs
+    }
+}
+
+/// One batter's line.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BattingLine {
+    player: Player,
+    batting_position: BattingPosition,
+    at_bats: u8,
+    runs: Runs,
+    hits: u8,
+    runs_batted_in: Runs,
+    walks: u8,
+    strikeouts: u8,
+    left_on_base: u8,
+}
+
+impl BattingLine {
+    fn new(player: Player, batting_position: BattingPosition) -> Self {
+        BattingLine {
+            player,
+            batting_position,
+            at_bats: 0,
+            runs: 0,
+            hits: 0,
+            runs_batted_in: 0,
+            walks: 0,
+            strikeouts: 0,
+            left_on_base: 0,
+        }
+    }
+
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn batting_position(&self) -> BattingPosition {
+        self.batting_position
+    }
+
+    pub fn at_bats(&self) -> u8 {
+        self.at_bats
+    }
+
+    pub fn runs(&self) -> Runs {
+        self.runs
+    }
+
+    pub fn hits(&self) -> u8 {
+        self.hits
+    }
+
+    pub fn runs_batted_in(&self) -> Runs {
+        self.runs_batted_in
+    }
+
+    pub fn walks(&self) -> u8 {
+        self.walks
+    }
+
+    pub fn strikeouts(&self) -> u8 {
+        self.strikeouts
+    }
+
+    /// Runners left on base when this batter made an out.
+    pub fn left_on_base(&self) -> u8 {
+        self.left_on_base
+    }
+}
+
+/// One pitcher's line.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PitchingLine {
+    player: Player,
+    innings_pitched: InningsPitched,
+    hits: u8,
+    runs: Runs,
+    earned_runs: Runs,
+    walks: u8,
+    strikeouts: u8,
+    pitches: u16,
+    decision: Option<Decision>,
+}
+
+impl PitchingLine {
+    fn new(player: Player) -> Self {
+        PitchingLine {
+            player,
+            innings_pitched: InningsPitched::default(),
+            hits: 0,
+            runs: 0,
+            earned_runs: 0,
+            walks: 0,
+            strikeouts: 0,
+            pitches: 0,
+            decision: None,
+        }
+    }
+
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn innings_pitched(&self) -> InningsPitched {
+        self.innings_pitched
+    }
+
+    pub fn hits(&self) -> u8 {
+        self.hits
+    }
+
+    pub fn runs(&self) -> Runs {
+        self.runs
+    }
+
+    pub fn earned_runs(&self) -> Runs {
+        self.earned_runs
+    }
+
+    pub fn walks(&self) -> u8 {
+        self.walks
+    }
+
+    pub fn strikeouts(&self) -> u8 {
+        self.strikeouts
+    }
+
+    pub fn pitches(&self) -> u16 {
+        self.pitches
+    }
+
+    /// The win, loss, save or hold credited to the pitcher.
+    pub fn decision(&self) -> Option<Decision> {
+        self.decision
+    }
+}
+
+/// A team's batting lines in batting order, followed by the pitchers who faced the other side.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TeamBoxScore {
+    batting: Vec<BattingLine>,
+    pitching: Vec<PitchingLine>,
+    left_on_base: u8,
+}
+
+impl TeamBoxScore {
+    /// Batting lines ordered by batting position, substitutes after the player they replaced.
+    pub fn batting(&self) -> &[BattingLine] {
+        &self.batting
+    }
+
+    /// Pitching lines in the order the pitchers appeared.
+    pub fn pitching(&self) -> &[PitchingLine] {
+        &self.pitching
+    }
+
+    /// Runners stranded when each of the team's half innings ended, each counted once.
+    pub fn left_on_base(&self) -> u8 {
+        self.left_on_base
+    }
+
+    pub fn batter(&self, number: u8) -> Option<&BattingLine> {
+        self.batting.iter().find(|line| line.player.number() == number)
+    }
+
+    pub fn pitcher(&self, number: u8) -> Option<&PitchingLine> {
+        self.pitching.iter().find(|line| line.player.number() == number)
+    }
+
+    fn batting_line(&mut self, player: &Player, batting_position: BattingPosition) -> &mut BattingLine {
+        let index = match self.batting.iter().position(|line| line.player == *player) {
+            Some(index) => index,
+            None => {
+                self.batting.push(BattingLine::new(player.clone(), batting_position));
+                self.batting.len() - 1
+            }
+        };
+        &mut self.batting[index]
+    }
+
+    fn pitching_line(&mut self, player: &Player) -> &mut PitchingLine {
+        let index = match self.pitching.iter().position(|line| line.player == *player) {
+            Some(index) => index,
+            None => {
+                self.pitching.push(PitchingLine::new(player.clone()));
+                self.pitching.len() - 1
+            }
+        };
+        &mut self.pitching[index]
+    }
+
+    fn fmt_batting(&self, f: &mut std::fmt::Formatter<'_>, name: &str) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<20}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}",
+            name, "AB", "R", "H", "RBI", "BB", "SO", "LOB"
+        )?;
+        for line in &self.batting {
+            writeln!(
+                f,
+                "{:<20.20}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}",
+                line.player.to_string(),
+                line.at_bats,
+                line.runs,
+                line.hits,
+                line.runs_batted_in,
+                line.walks,
+                line.strikeouts,
+                line.left_on_base
+            )?;
+        }
+
+        let total = |stat: fn(&BattingLine) -> u8| self.batting.iter().map(stat).sum::<u8>();
+        writeln!(
+            f,
+            "{:<20}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}",
+            "Totals",
+            total(BattingLine::at_bats),
+            total(BattingLine::runs),
+            total(BattingLine::hits),
+            total(BattingLine::runs_batted_in),
+            total(BattingLine::walks),
+            total(BattingLine::strikeouts),
+            self.left_on_base
+        )
+    }
+
+    fn fmt_pitching(&self, f: &mut std::fmt::Formatter<'_>, name: &str) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<20}{:>5}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}",
+            name, "IP", "H", "R", "ER", "BB", "SO", "PC"
+        )?;
+        for line in &self.pitching {
+            let name = match line.decision {
+                Some(decision) => format!("{} ({})", line.player, decision),
+                None => line.player.to_string(),
+            };
+            writeln!(
+                f,
+                "{:<20.20}{:>5}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}",
+                name,
+                line.innings_pitched.to_string(),
+                line.hits,
+                line.runs,
+                line.earned_runs,
+                line.walks,
+                line.strikeouts,
+                line.pitches
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Per-player batting and pitching lines for both teams, built by replaying a game's event log.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxScore {
+    away: TeamBoxScore,
+    home: TeamBoxScore,
+}
+
+impl Display for BoxScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.away.fmt_batting(f, "Away")?;
+        writeln!(f)?;
+        self.home.fmt_batting(f, "Home")?;
+        writeln!(f)?;
+        self.away.fmt_pitching(f, "Away Pitching")?;
+        writeln!(f)?;
+        self.home.fmt_pitching(f, "Home Pitching")
+    }
+}
+
+impl BoxScore {
+    pub fn new(summary: &GameSummary) -> Self {
+        let mut box_score = BoxScore::default();
+        let mut away_slots: [Option<Player>; 9] = Default::default();
+        let mut home_slots: [Option<Player>; 9] = Default::default();
+        let mut earned_runs = EarnedRuns::new();
+
+        for event in summary.events() {
+            match event {
+                GameEvent::Substitution(substitution) => {
+                    // A pitcher who does not bat has no batting line
+                    let Some(batting_position) = substitution.batting_position() else {
+                        continue;
+                    };
+                    let slots = match substitution.team() {
+                        Team::Away => &mut away_slots,
+                        Team::Home => &mut home_slots,
+                    };
+                    slots[batting_position.as_number() as usize - 1] = Some(substitution.incoming().clone());
+
+                    box_score
+                        .team_mut(substitution.team())
+                        .batting_line(substitution.incoming(), batting_position);
+                }
+                GameEvent::Pitch(pitch) => {
+                    let team = match pitch.half() {
+                        InningHalf::Top => Team::Away,
+                        InningHalf::Bottom => Team::Home,
+                    };
+                    let slots = match team {
+                        Team::Away => &mut away_slots,
+                        Team::Home => &mut home_slots,
+                    };
+                    slots[pitch.batting_position().as_number() as usize - 1] = Some(pitch.batter().clone());
+
+                    let charged = earned_runs.record(pitch);
+                    box_score.record_pitch(team, slots, pitch, &charged);
+                }
+                GameEvent::PlacedRunner(placed) => {
+                    let team = match placed.half() {
+                        InningHalf::Top => Team::Away,
+                        InningHalf::Bottom => Team::Home,
+                    };
+                    let slots = match team {
+                        Team::Away => &mut away_slots,
+                        Team::Home => &mut home_slots,
+                    };
+                    slots[placed.batting_position().as_number() as usize - 1] = Some(placed.runner().clone());
+                    earned_runs.place_runner(placed);
+                }
+            }
+        }
+
+        for team in [&mut box_score.away, &mut box_score.home] {
+            team.batting.sort_by_key(|line| line.batting_position.as_number());
+        }
+        for team in [Team::Away, Team::Home] {
+            box_score.team_mut(team).left_on_base = summary.line_score().team(team).left_on_base();
+        }
+
+        let decisions = summary.decisions();
+        for team in [Team::Away, Team::Home] {
+            for line in &mut box_score.team_mut(team).pitching {
+                line.decision = decisions.decision(team, &line.player);
+            }
+        }
+
+        box_score
+    }
+
+    pub fn away(&self) -> &TeamBoxScore {
+        &self.away
+    }
+
+    pub fn home(&self) -> &TeamBoxScore {
+        &self.home
+    }
+
+    pub fn team(&self, team: Team) -> &TeamBoxScore {
+        match team {
+            Team::Away => &self.away,
+            Team::Home => &self.home,
+        }
+    }
+
+    fn team_mut(&mut self, team: Team) -> &mut TeamBoxScore {
+        match team {
+            Team::Away => &mut self.away,
+            Team::Home => &mut self.home,
+        }
+    }
+
+    fn record_pitch(&mut self, team: Team, slots: &[Option<Player>; 9], pitch: &PitchEvent, charged: &[ChargedRun]) {
+        let result = pitch.result();
+        let scorers = pitch.scorers();
+        let outs = pitch.outs_after().as_number() - pitch.outs_before().as_number();
+
+        // Events without a pitcher come from lineups using a DH, which do not name one.
+        if let Some(pitcher) = pitch.pitcher() {
+            let line = self.team_mut(team.opponent()).pitching_line(pitcher);
+            if !matches!(pitch.pitch(), PitchOutcome::Running(_)) {
+                line.pitches += 1;
+            }
+            line.innings_pitched.outs += u16::from(outs);
+            if result.is_hit() {
+                line.hits += 1;
+            }
+            match result {
+                PlateAppearanceResult::Walk => line.walks += 1,
+                PlateAppearanceResult::Strikeout => line.strikeouts += 1,
+                _ => {}
+            }
+        }
+
+        // Runs go to the pitcher who let the runner on, who may have left the game (Rule 9.16(g))
+        for run in charged {
+            if let Some(pitcher) = run.pitcher() {
+                let line = self.team_mut(team.opponent()).pitching_line(pitcher);
+                line.runs += 1;
+                line.earned_runs += Runs::from(run.is_earned());
+            }
+        }
+
+        let batting = self.team_mut(team);
+        for runner in &scorers {
+            if let Some(player) = &slots[runner.as_number() as usize - 1] {
+                batting.batting_line(player, *runner).runs += 1;
+            }
+        }
+
+        if !pitch.ends_plate_appearance() {
+            return;
+        }
+
+        let batter = pitch.batting_position();
+        let line = batting.batting_line(pitch.batter(), batter);
+        match result {
+            PlateAppearanceResult::Walk => line.walks += 1,
+            PlateAppearanceResult::HitByPitch | PlateAppearanceResult::CatcherInterference => {}
+            PlateAppearanceResult::Strikeout => {
+                line.at_bats += 1;
+                line.strikeouts += 1;
+            }
+            PlateAppearanceResult::InPlay(play) if play.kind().is_sacrifice() => {}
+            PlateAppearanceResult::HomeRun | PlateAppearanceResult::InPlay(_) => line.at_bats += 1,
+            PlateAppearanceResult::InProgress(_) => {}
+        }
+        if result.is_hit() {
+            line.hits += 1;
+        }
+
+        // No RBI when the batter grounds into a force double play (Rule 9.04(b)(1))
+        let double_play = matches!(result, PlateAppearanceResult::InPlay(play) if play.grounded_into_double_play());
+        if !double_play {
+            // Runs that score on an error or a pitch the catcher misplays are not batted in (Rule 9.04)
+            let runs_batted_in = pitch
+                .movements()
+                .into_iter()
+                .filter(|movement| {
+                    movement.scored() && matches!(movement.advancement(), Advancement::BattedBall | Advancement::Throw)
+                })
+                .take(pitch.runs_scored() as usize)
+                .count();
+            line.runs_batted_in += runs_batted_in as Runs;
+        }
+
+        let batter_is_out = pitch.baserunners_after().base_of(batter).is_none() && !scorers.contains(&batter);
+        if batter_is_out {
+            line.left_on_base += pitch.baserunners_after().runner_count();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseball::{
+        baserunners::{Base, BaserunnerState, PlayKind, PlayOutcome},
+        game::{Game, GameResult, InningNumber},
+        lineup::{Bats, Throws},
+        movement::{Destination, RunnerMovement},
+        plate_appearance::PitchOutcome,
+        rules::GameRules,
+    };
+
+    const STRIKEOUT: [PitchOutcome; 3] = [PitchOutcome::Strike, PitchOutcome::Strike, PitchOutcome::Strike];
+    const WALK: [PitchOutcome; 4] = [
+        PitchOutcome::Ball,
+        PitchOutcome::Ball,
+        PitchOutcome::Ball,
+        PitchOutcome::Ball,
+    ];
+
+    fn play_out(pitches: &[PitchOutcome]) -> GameSummary {
+        play_out_game(Game::new(), pitches)
+    }
+
+    /// Throws `pitches` and then strikes out every remaining batter.
+    fn play_out_game(game: Game, pitches: &[PitchOutcome]) -> GameSummary {
+        let mut result = pitches
+            .iter()
+            .fold(GameResult::InProgress(game), |result, pitch| result.advance(*pitch));
+        while !result.is_complete() {
+            result = STRIKEOUT.iter().fold(result, |result, pitch| result.advance(*pitch));
+        }
+        result.summary().expect("Game should be complete")
+    }
+
+    #[test]
+    fn test_innings_pitched_display() {
+        assert_eq!(InningsPitched::from_outs(0).to_string(), "0.0");
+        assert_eq!(InningsPitched::from_outs(19).to_string(), "6.1");
+        assert_eq!(InningsPitched::from_outs(27).to_string(), "9.0");
+    }
+
+    #[test]
+    fn test_batting_lines() {
+        // Top 1st: home run, single, walk, then every batter strikes out
+        let mut pitches = vec![
+            PitchOutcome::HomeRun,
+            PitchOutcome::InPlay(PlayOutcome::single(BaserunnerState::empty(), BattingPosition::Second)),
+        ];
+        pitches.extend(WALK);
+        let box_score = play_out(&pitches).box_score();
+
+        let away = box_score.away();
+        assert_eq!(away.batting().len(), 9);
+        assert_eq!(away.batting()[0].player().number(), 1);
+
+        let leadoff = away.batter(1).expect("Leadoff hitter should have a line");
+        assert_eq!(leadoff.at_bats(), 4);
+        assert_eq!(leadoff.runs(), 1);
+        assert_eq!(leadoff.hits(), 1);
+        assert_eq!(leadoff.runs_batted_in(), 1);
+        assert_eq!(leadoff.strikeouts(), 3);
+
+        let walked = away.batter(3).expect("Third hitter should have a line");
+        assert_eq!(walked.walks(), 1);
+        assert_eq!(walked.at_bats(), 3);
+
+        // The next three batters strike out with two runners aboard
+        for number in 4..=6 {
+            let line = away.batter(number).expect("Batter should have a line");
+            assert_eq!(line.left_on_base(), 2);
+        }
+        // The team stranded those two runners once, not once per out
+        assert_eq!(away.left_on_base(), 2);
+
+        let home = box_score.home();
+        assert!(
+            home.batting()
+                .iter()
+                .all(|line| line.at_bats() == 3 && line.strikeouts() == 3)
+        );
+        assert_eq!(home.batting().iter().map(BattingLine::hits).sum::<u8>(), 0);
+    }
+
+    #[test]
+    fn test_sacrifice_is_not_an_at_bat() {
+        let mut pitches = vec![PitchOutcome::InPlay(PlayOutcome::triple(
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        ))];
+        let third = BaserunnerState::new().set_third(Some(BattingPosition::First));
+        pitches.push(PitchOutcome::InPlay(
+            PlayOutcome::sacrifice_fly(third, BattingPosition::Second).expect("Runner on third"),
+        ));
+        let box_score = play_out(&pitches).box_score();
+
+        // Three plate appearances, one of them a sacrifice
+        let line = box_score.away().batter(2).expect("Second hitter should have a line");
+        assert_eq!(line.at_bats(), 2);
+        assert_eq!(line.runs_batted_in(), 1);
+        assert_eq!(box_score.away().batter(1).map(BattingLine::runs), Some(1));
+    }
+
+    #[test]
+    fn test_double_plays_and_runs_batted_in() {
+        // Bases loaded with nobody out: the run scores as the batter grounds into a double play
+        let bases_loaded = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Third))
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::First));
+        let mut pitches = [WALK, WALK, WALK].concat();
+        pitches.push(PitchOutcome::InPlay(
+            PlayOutcome::double_play(bases_loaded, BattingPosition::Fourth).expect("Runner on first"),
+        ));
+        let box_score = play_out(&pitches).box_score();
+        assert_eq!(box_score.away().batter(1).map(BattingLine::runs), Some(1));
+        assert_eq!(box_score.away().batter(4).map(BattingLine::runs_batted_in), Some(0));
+
+        // A sacrifice fly scores the runner from third before the runner from first is doubled off
+        let first_and_third = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::First));
+        let sacrifice_fly = PlayOutcome::from_movements(
+            first_and_third,
+            &[
+                RunnerMovement::new(BattingPosition::First, Base::Third, Destination::Safe(Base::Home)),
+                RunnerMovement::new(BattingPosition::Third, Base::Home, Destination::Out(Base::Home)),
+                RunnerMovement::new(BattingPosition::Second, Base::First, Destination::Out(Base::First)),
+            ],
+        )
+        .expect("Runners on first and third")
+        .with_kind(PlayKind::SacrificeFly);
+        let mut pitches = vec![PitchOutcome::InPlay(PlayOutcome::triple(
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        ))];
+        pitches.extend(WALK);
+        pitches.push(PitchOutcome::InPlay(sacrifice_fly));
+        let box_score = play_out(&pitches).box_score();
+        assert_eq!(box_score.away().batter(1).map(BattingLine::runs), Some(1));
+        assert_eq!(box_score.away().batter(3).map(BattingLine::runs_batted_in), Some(1));
+    }
+
+    #[test]
+    fn test_run_scoring_on_an_error_is_not_batted_in() {
+        let third = BaserunnerState::new().set_third(Some(BattingPosition::First));
+        let error = PlayOutcome::from_movements(
+            third,
+            &[
+                RunnerMovement::new(BattingPosition::First, Base::Third, Destination::Safe(Base::Home))
+                    .with_advancement(Advancement::Error),
+                RunnerMovement::new(BattingPosition::Second, Base::Home, Destination::Safe(Base::First))
+                    .with_advancement(Advancement::Error),
+            ],
+        )
+        .expect("Runner on third");
+        let pitches = [
+            PitchOutcome::InPlay(PlayOutcome::triple(BaserunnerState::empty(), BattingPosition::First)),
+            PitchOutcome::InPlay(error),
+        ];
+        let box_score = play_out(&pitches).box_score();
+
+        let line = box_score.away().batter(2).expect("Second hitter should have a line");
+        assert_eq!(line.runs_batted_in(), 0);
+        assert_eq!(box_score.away().batter(1).map(BattingLine::runs), Some(1));
+    }
+
+    #[test]
+    fn test_pitching_lines() {
+        let mut pitches = vec![PitchOutcome::HomeRun];
+        pitches.extend(WALK);
+        let box_score = play_out(&pitches).box_score();
+
+        // The default lineup bats the pitcher ninth
+        let home_pitcher = box_score.home().pitcher(9).expect("Home pitcher should have a line");
+        assert_eq!(home_pitcher.innings_pitched(), InningsPitched::from_outs(27));
+        assert_eq!(home_pitcher.hits(), 1);
+        assert_eq!(home_pitcher.runs(), 1);
+        assert_eq!(home_pitcher.earned_runs(), 1);
+        assert_eq!(home_pitcher.walks(), 1);
+        assert_eq!(home_pitcher.strikeouts(), 27);
+        assert_eq!(home_pitcher.pitches(), 1 + 4 + 27 * 3);
+
+        let away_pitcher = box_score.away().pitcher(9).expect("Away pitcher should have a line");
+        assert_eq!(away_pitcher.innings_pitched().to_string(), "9.0");
+        assert_eq!(away_pitcher.pitches(), 27 * 3);
+        assert_eq!(box_score.away().pitching().len(), 1);
+    }
+
+    #[test]
+    fn test_placed_runner_scores_unearned_run() {
+        // Scoreless through nine, then a two-run homer with the placed runner on second
+        let mut pitches: Vec<PitchOutcome> = STRIKEOUT.iter().cycle().take(54 * 3).copied().collect();
+        pitches.push(PitchOutcome::HomeRun);
+        let game = Game::new().with_rules(GameRules::major_league());
+        let box_score = play_out_game(game, &pitches).box_score();
+
+        let home_pitcher = box_score.home().pitcher(9).expect("Home pitcher should have a line");
+        assert_eq!(home_pitcher.runs(), 2);
+        assert_eq!(home_pitcher.earned_runs(), 1);
+
+        let away = box_score.away();
+        assert_eq!(away.batter(9).map(BattingLine::runs), Some(1));
+        assert_eq!(away.batter(1).map(BattingLine::runs_batted_in), Some(2));
+    }
+
+    #[test]
+    fn test_inherited_runner_is_charged_to_the_pitcher_who_put_them_on() {
+        let mut game = Game::new()
+            .with_rules(GameRules::new().with_three_batter_minimum(false))
+            .advance(PitchOutcome::InPlay(PlayOutcome::single(
+                BaserunnerState::empty(),
+                BattingPosition::First,
+            )))
+            .game()
+            .expect("Game should continue");
+        game.change_pitcher(Team::Home, Player::new("Reliever", 40, Bats::Left, Throws::Left))
+            .expect("Pitching change should be allowed");
+        let summary = play_out_game(game, &[PitchOutcome::HomeRun]);
+        let box_score = summary.box_score();
+
+        let starter = box_score.home().pitcher(9).expect("Starter should have a line");
+        assert_eq!(starter.runs(), 1);
+        assert_eq!(starter.earned_runs(), 1);
+        assert_eq!(starter.innings_pitched().outs(), 0);
+        let reliever = box_score.home().pitcher(40).expect("Reliever should have a line");
+        assert_eq!(reliever.runs(), 1);
+        assert_eq!(reliever.hits(), 1);
+
+        // The inherited runner was the go-ahead run
+        assert_eq!(summary.decisions().losing_pitcher().map(Player::number), Some(9));
+        assert_eq!(
+            box_score.home().pitcher(9).and_then(PitchingLine::decision),
+            Some(Decision::Loss)
+        );
+    }
+
+    #[test]
+    fn test_bases_loaded_walk() {
+        let pitches: Vec<PitchOutcome> = WALK.iter().cycle().take(16).copied().collect();
+        let box_score = play_out(&pitches).box_score();
+        let away = box_score.away();
+
+        let forced_in = away.batter(4).expect("Fourth hitter should have a line");
+        assert_eq!(forced_in.runs_batted_in(), 1);
+        assert_eq!(forced_in.walks(), 1);
+        assert_eq!(away.batter(1).map(BattingLine::runs), Some(1));
+        assert_eq!(away.batter(2).map(BattingLine::runs), Some(0));
+    }
+
+    #[test]
+    fn test_substitutes_follow_the_player_they_replace() {
+        let mut game = Game::new();
+        game.pinch_hit(Player::new("Pinch Hitter", 30, Bats::Left, Throws::Left))
+            .expect("Pinch hitter should be allowed");
+        let box_score = play_out_game(game, &[PitchOutcome::HomeRun]).box_score();
+
+        let numbers: Vec<u8> = box_score
+            .away()
+            .batting()
+            .iter()
+            .map(|line| line.player().number())
+            .collect();
+        assert_eq!(&numbers[..2], &[30, 2]);
+        assert_eq!(box_score.away().batter(30).map(BattingLine::at_bats), Some(4));
+        assert_eq!(box_score.away().batter(30).map(BattingLine::runs), Some(1));
+        assert_eq!(box_score.away().batter(1), None);
+    }
+
+    #[test]
+    fn test_pitching_decisions() {
+        // The visitors score in the first and bring in a closer for the bottom of the ninth
+        let mut result = GameResult::InProgress(Game::new()).advance(PitchOutcome::HomeRun);
+        while result
+            .game_ref()
+            .is_some_and(|game| game.current_inning() != InningNumber::Ninth || game.batting_team() == Team::Away)
+        {
+            result = STRIKEOUT.iter().fold(result, |result, pitch| result.advance(*pitch));
+        }
+        let mut game = result.game().expect("Game should continue");
+        let closer = Player::new("Closer", 40, Bats::Left, Throws::Left);
+        game.change_pitcher(Team::Away, closer.clone())
+            .expect("Pitching change should be allowed");
+        let summary = play_out_game(game, &[]);
+
+        let decisions = summary.decisions();
+        assert_eq!(decisions.winning_pitcher().map(Player::number), Some(9));
+        assert_eq!(decisions.save(), Some(&closer));
+        assert!(
+            summary
+                .to_string()
+                .ends_with("\nW: #9 Batter 9  L: #9 Batter 9  S: #40 Closer")
+        );
+        let box_score = summary.box_score();
+        assert_eq!(
+            box_score.away().pitcher(9).and_then(PitchingLine::decision),
+            Some(Decision::Win)
+        );
+        assert_eq!(
+            box_score.away().pitcher(40).and_then(PitchingLine::decision),
+            Some(Decision::Save)
+        );
+        assert_eq!(
+            box_score.home().pitcher(9).and_then(PitchingLine::decision),
+            Some(Decision::Loss)
+        );
+    }
+
+    #[test]
+    fn test_text_output() {
+        let rendered = play_out(&[PitchOutcome::HomeRun]).box_score().to_string();
+        assert!(rendered.contains("Away                  AB   R   H RBI  BB  SO LOB"));
+        assert!(rendered.contains("#1 Batter 1            4   1   1   1   0   3   0"));
+        assert!(rendered.contains("Home Pitching          IP   H   R  ER  BB  SO  PC"));
+        assert!(rendered.contains("#9 Batter 9 (L)       9.0   1   1   1   0  27  82"));
+        assert!(rendered.contains("#9 Batter 9 (W)       9.0   0   0   0   0  27  81"));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::baseball::{
+    baserunners::{base_number, start_number},
+    events::{PitchEvent, PlacedRunner},
+    game::InningNumber,
+    inning::InningHalf,
+    lineup::{BattingPosition, Player},
+    movement::{Advancement, RunnerMovement},
+    plate_appearance::{PitchOutcome, PlateAppearanceResult},
+};
+
+/// A run and the pitcher it is charged to.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChargedRun {
+    pitcher: Option<Player>,
+    earned: bool,
+}
+
+impl ChargedRun {
+    /// `None` if the fielding team named no pitcher.
+    pub(crate) fn pitcher(&self) -> Option<&Player> {
+        self.pitcher.as_ref()
+    }
+
+    pub(crate) fn is_earned(&self) -> bool {
+        self.earned
+    }
+}
+
+/// A runner on base and how they got there.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Runner {
+    position: BattingPosition,
+    /// The pitcher responsible for the runner, filled in at the first pitch for a placed runner.
+    pitcher: Option<Player>,
+    /// Reached base without an error, passed ball or catcher's interference.
+    earned: bool,
+    /// Bases taken on errors and passed balls, which the runner would still need without them.
+    bases_owed: u8,
+}
+
+/// Replays each half inning as it would have gone without errors and passed balls, to decide which
+/// runs are earned and which pitcher each is charged to (Rule 9.16).
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct EarnedRuns {
+    half_inning: Option<(InningNumber, InningHalf)>,
+    runners: Vec<Runner>,
+    /// Outs the defense would have made but for errors, passed balls and catcher's interference.
+    extra_outs: u8,
+    /// The extra outs already given when each pitcher came in (Rule 9.16(i)).
+    entries: Vec<(Option<Player>, u8)>,
+}
+
+impl EarnedRuns {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// A runner placed on second to start an extra inning. Their run is unearned (Rule 7.01(b)).
+    pub(crate) fn place_runner(&mut self, placed: &PlacedRunner) {
+        self.start(placed.inning(), placed.half());
+        self.runners.push(Runner {
+            position: placed.batting_position(),
+            pitcher: None,
+            earned: false,
+            bases_owed: 0,
+        });
+    }
+
+    /// Follows the runners through `pitch`, returning who is charged with each run it scores.
+    pub(crate) fn record(&mut self, pitch: &PitchEvent) -> Vec<ChargedRun> {
+        self.start(pitch.inning(), pitch.half());
+        let pitcher = pitch.pitcher().cloned();
+        if !self.entries.iter().any(|(entered, _)| *entered == pitcher) {
+            self.entries.push((pitcher.clone(), self.extra_outs));
+        }
+        for runner in self.runners.iter_mut().filter(|runner| runner.pitcher.is_none()) {
+            runner.pitcher = pitcher.clone();
+        }
+
+        let outs = pitch.outs_before().as_number();
+        let movements = pitch.movements();
+        let scorers = pitch.scorers();
+        let batter = movements.iter().find(|movement| movement.is_batter());
+        // How far the batter's hit would have moved a runner who is behind on errors
+        let hit_bases = match (pitch.result(), batter) {
+            (PlateAppearanceResult::HomeRun, _) => 4,
+            (PlateAppearanceResult::InPlay(play), Some(batter)) if play.is_hit() && !batter.is_out() => {
+                base_number(batter.to().base())
+            }
+            _ => 0,
+        };
+
+        let mut charged = Vec::new();
+        // A runner put out by the batter's action is replaced by the batter-runner, who stays the
+        // earlier pitcher's responsibility (Rule 9.16(g))
+        let mut replaced: Option<Option<Player>> = None;
+        for movement in movements.iter().filter(|movement| !movement.is_batter()) {
+            let Some(index) = self
+                .runners
+                .iter()
+                .position(|runner| runner.position == movement.runner())
+            else {
+                continue;
+            };
+            if movement.is_out() {
+                let runner = self.runners.remove(index);
+                if !matches!(pitch.pitch(), PitchOutcome::Running(_)) {
+                    replaced.get_or_insert(runner.pitcher);
+                }
+            } else if movement.scored() {
+                let runner = self.runners.remove(index);
+                if scorers.contains(&runner.position) {
+                    let needed = 4 - start_number(movement.from()) + runner.bases_owed;
+                    let earned = runner.earned
+                        && !is_misplay(movement)
+                        && (runner.bases_owed == 0 || hit_bases >= needed)
+                        && outs + self.extra_outs_for(runner.pitcher.as_ref()) < 3;
+                    charged.push(ChargedRun {
+                        pitcher: runner.pitcher,
+                        earned,
+                    });
+                }
+            } else if is_misplay(movement) {
+                self.runners[index].bases_owed += base_number(movement.to().base()) - start_number(movement.from());
+            }
+        }
+
+        if let Some(batter) = batter {
+            let reached_on_misplay = is_misplay(batter) || pitch.result() == PlateAppearanceResult::CatcherInterference;
+            let runner = Runner {
+                position: batter.runner(),
+                pitcher: replaced.unwrap_or(pitcher),
+                earned: !reached_on_misplay,
+                bases_owed: 0,
+            };
+            if batter.scored() && scorers.contains(&runner.position) {
+                let earned = runner.earned && outs + self.extra_outs_for(runner.pitcher.as_ref()) < 3;
+                charged.push(ChargedRun {
+                    pitcher: runner.pitcher,
+                    earned,
+                });
+            } else if !batter.is_out() && !batter.scored() {
+                self.runners.push(runner);
+            }
+            // Without the misplay the batter would have been out
+            if reached_on_misplay {
+                self.extra_outs += 1;
+            }
+        }
+        charged
+    }
+
+    fn start(&mut self, inning: InningNumber, half: InningHalf) {
+        if self.half_inning != Some((inning, half)) {
+            *self = EarnedRuns {
+                half_inning: Some((inning, half)),
+                ..Self::default()
+            };
+        }
+    }
+
+    /// The outs given away while `pitcher` has been in this half inning.
+    fn extra_outs_for(&self, pitcher: Option<&Player>) -> u8 {
+        let given_before = self
+            .entries
+            .iter()
+            .find(|(entered, _)| entered.as_ref() == pitcher)
+            .map_or(0, |(_, extra_outs)| *extra_outs);
+        self.extra_outs - given_before
+    }
+}
+
+/// Bases taken on an error or passed ball do not count against the pitcher (Rule 9.16(a)).
+fn is_misplay(movement: &RunnerMovement) -> bool {
+    matches!(movement.advancement(), Advancement::Error | Advancement::PassedBall)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseball::{
+        baserunners::{Base, PlayOutcome},
+        inning::{HalfInning, HalfInningResult},
+        lineup::{Bats, Throws},
+        running::RunningPlay,
+    };
+
+    /// Plays the half inning, each pitch thrown by the pitcher paired with it, returning whether
+    /// each run was earned and the number of the pitcher charged with it.
+    fn play(pitches: &[(u8, fn(HalfInning) -> PitchOutcome)]) -> Vec<(Option<u8>, bool)> {
+        let mut earned_runs = EarnedRuns::new();
+        let mut half_inning = HalfInning::new(InningHalf::Top, BattingPosition::First);
+        let mut charged = Vec::new();
+        for (number, pitch) in pitches {
+            let pitch = pitch(half_inning);
+            let result = half_inning.advance(pitch);
+            let batter = Player::new("Batter", 1, Bats::Right, Throws::Right);
+            let pitcher = Player::new("Pitcher", *number, Bats::Right, Throws::Right);
+            let event = PitchEvent::new(InningNumber::First, batter, Some(pitcher), pitch, &half_inning, &result);
+            charged.extend(
+                earned_runs
+                    .record(&event)
+                    .into_iter()
+                    .map(|run| (run.pitcher().map(Player::number), run.is_earned())),
+            );
+            match result {
+                HalfInningResult::InProgress(next) => half_inning = next,
+                HalfInningResult::Complete(_) => break,
+            }
+        }
+        charged
+    }
+
+    fn flyout(half_inning: HalfInning) -> PitchOutcome {
+        PitchOutcome::InPlay(PlayOutcome::flyout_with(
+            half_inning.baserunners(),
+            half_inning.current_batter(),
+        ))
+    }
+
+    fn error(half_inning: HalfInning) -> PitchOutcome {
+        PitchOutcome::InPlay(PlayOutcome::reached_on_error(
+            half_inning.baserunners(),
+            half_inning.current_batter(),
+        ))
+    }
+
+    fn double(half_inning: HalfInning) -> PitchOutcome {
+        PitchOutcome::InPlay(PlayOutcome::double(
+            half_inning.baserunners(),
+            half_inning.current_batter(),
+        ))
+    }
+
+    fn single(half_inning: HalfInning) -> PitchOutcome {
+        PitchOutcome::InPlay(PlayOutcome::single(
+            half_inning.baserunners(),
+            half_inning.current_batter(),
+        ))
+    }
+
+    fn home_run(_: HalfInning) -> PitchOutcome {
+        PitchOutcome::HomeRun
+    }
+
+    #[test]
+    fn test_runs_after_the_inning_should_have_ended_are_unearned() {
+        let charged = play(&[(1, flyout), (1, flyout), (1, error), (1, home_run)]);
+        assert_eq!(charged, vec![(Some(1), false), (Some(1), false)]);
+
+        // With one out the error only makes the runner's own run unearned
+        let charged = play(&[(1, flyout), (1, error), (1, home_run)]);
+        assert_eq!(charged, vec![(Some(1), false), (Some(1), true)]);
+    }
+
+    #[test]
+    fn test_bases_taken_on_a_passed_ball_must_be_made_up() {
+        fn passed_ball(half_inning: HalfInning) -> PitchOutcome {
+            PitchOutcome::Running(RunningPlay::passed_ball(half_inning.baserunners()).expect("Runner on base"))
+        }
+        fn stolen_base(half_inning: HalfInning) -> PitchOutcome {
+            let steal = RunningPlay::stolen_base(half_inning.baserunners(), Base::First);
+            PitchOutcome::Running(steal.expect("Runner on first"))
+        }
+
+        // Without the passed ball the runner would have stopped at third on the double
+        let charged = play(&[(1, single), (1, passed_ball), (1, double)]);
+        assert_eq!(charged, vec![(Some(1), false)]);
+        let charged = play(&[(1, single), (1, stolen_base), (1, double)]);
+        assert_eq!(charged, vec![(Some(1), true)]);
+        let charged = play(&[(1, single), (1, passed_ball), (1, home_run)]);
+        assert_eq!(charged, vec![(Some(1), true), (Some(1), true)]);
+    }
+
+    #[test]
+    fn test_inherited_runners_are_charged_to_the_pitcher_who_put_them_on() {
+        fn fielders_choice(half_inning: HalfInning) -> PitchOutcome {
+            let play = PlayOutcome::fielders_choice(half_inning.baserunners(), half_inning.current_batter());
+            PitchOutcome::InPlay(play.expect("Runner on first"))
+        }
+
+        // The batter forcing out the inherited runner takes their place
+        let charged = play(&[(1, single), (2, fielders_choice), (2, home_run)]);
+        assert_eq!(charged, vec![(Some(1), true), (Some(2), true)]);
+    }
+
+    #[test]
+    fn test_reliever_does_not_benefit_from_earlier_errors() {
+        let charged = play(&[(1, flyout), (1, error), (2, flyout), (2, home_run)]);
+        assert_eq!(charged, vec![(Some(1), false), (Some(2), true)]);
+
+        let charged = play(&[(1, flyout), (1, error), (1, flyout), (1, home_run)]);
+        assert_eq!(charged, vec![(Some(1), false), (Some(1), false)]);
+    }
+}
@@ -0,0 +1,481 @@
+use std::fmt::Display;
+
+use crate::{
+    Runs,
+    baseball::{
+        baserunners::{BaseOutcome, BaserunnerState, HomeOutcome, PlayOutcome},
+        game::InningNumber,
+        inning::{HalfInning, HalfInningResult, InningHalf, Outs},
+        lineup::{BattingPosition, Player},
+        movement::{Advancement, RunnerMovement},
+        plate_appearance::{Count, PitchOutcome, PlateAppearanceResult},
+        substitution::Substitution,
+    },
+};
+
+/// Everything that happened on a single pitch, with the game situation before and after it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PitchEvent {
+    inning: InningNumber,
+    half: InningHalf,
+    batting_position: BattingPosition,
+    batter: Player,
+    pitcher: Option<Player>,
+    pitch: PitchOutcome,
+    count_before: Count,
+    result: PlateAppearanceResult,
+    outs_before: Outs,
+    outs_after: Outs,
+    baserunners_before: BaserunnerState,
+    baserunners_after: BaserunnerState,
+    runs_scored: Runs,
+}
+
+impl Display for PitchEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let half = match self.half {
+            InningHalf::Top => "Top",
+            InningHalf::Bottom => "Bottom",
+        };
+        write!(
+            f,
+            "{} {}, {} outs, {} batting ({}): {}",
+            half,
+            self.inning.as_number(),
+            self.outs_before,
+            self.batter,
+            self.count_before,
+            self.pitch
+        )?;
+
+        if self.runs_scored > 0 {
+            write!(f, ", {} run(s) score", self.runs_scored)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PitchEvent {
+    /// Records `pitch` thrown by `pitcher` to `batter` in the half inning `before`, which advanced to `after`.
+    pub fn new(
+        inning: InningNumber,
+        batter: Player,
+        pitcher: Option<Player>,
+        pitch: PitchOutcome,
+        before: &HalfInning,
+        after: &HalfInningResult,
+    ) -> Self {
+        let (outs_after, baserunners_after, runs_after) = match after {
+            HalfInningResult::InProgress(half_inning) => {
+                (half_inning.outs(), half_inning.baserunners(), half_inning.runs_scored())
+            }
+            HalfInningResult::Complete(summary) => (Outs::Three, summary.stranded(), summary.runs_scored()),
+        };
+
+        PitchEvent {
+            inning,
+            half: before.half(),
+            batting_position: before.current_batter(),
+            batter,
+            pitcher,
+            pitch,
+            count_before: before.current_plate_appearance().count(),
+            result: before.current_plate_appearance().advance(pitch),
+            outs_before: before.outs(),
+            outs_after,
+            baserunners_before: before.baserunners(),
+            baserunners_after,
+            runs_scored: runs_after - before.runs_scored(),
+        }
+    }
+
+    pub fn inning(&self) -> InningNumber {
+        self.inning
+    }
+
+    pub fn half(&self) -> InningHalf {
+        self.half
+    }
+
+    pub fn batting_position(&self) -> BattingPosition {
+        self.batting_position
+    }
+
+    pub fn batter(&self) -> &Player {
+        &self.batter
+    }
+
+    /// The fielding team's pitcher, if one has been named. A team using a DH names theirs with
+    /// `Game::with_starting_pitcher`.
+    pub fn pitcher(&self) -> Option<&Player> {
+        self.pitcher.as_ref()
+    }
+
+    pub fn pitch(&self) -> PitchOutcome {
+        self.pitch
+    }
+
+    pub fn count_before(&self) -> Count {
+        self.count_before
+    }
+
+    /// The count after the pitch, or `None` if the pitch ended the plate appearance.
+    pub fn count_after(&self) -> Option<Count> {
+        self.result.plate_appearance().map(|pa| pa.count())
+    }
+
+    pub fn result(&self) -> PlateAppearanceResult {
+        self.result
+    }
+
+    pub fn ends_plate_appearance(&self) -> bool {
+        self.result.is_complete()
+    }
+
+    pub fn outs_before(&self) -> Outs {
+        self.outs_before
+    }
+
+    pub fn outs_after(&self) -> Outs {
+        self.outs_after
+    }
+
+    pub fn baserunners_before(&self) -> BaserunnerState {
+        self.baserunners_before
+    }
+
+    pub fn baserunners_after(&self) -> BaserunnerState {
+        self.baserunners_after
+    }
+
+    pub fn runs_scored(&self) -> Runs {
+        self.runs_scored
+    }
+
+    /// Each runner's part in this pitch, lead runner first and the batter last. Only a running play
+    /// moves anyone without ending the plate appearance.
+    pub fn movements(&self) -> Vec<RunnerMovement> {
+        let play = match (self.pitch, self.result) {
+            (PitchOutcome::Running(play), _) => return play.movements().collect(),
+            (_, PlateAppearanceResult::InProgress(_)) => return Vec::new(),
+            (PitchOutcome::StrikeoutPlay(play), _) | (_, PlateAppearanceResult::InPlay(play)) => play,
+            _ => {
+                let after = self.baserunners_after;
+                let slot = |runner: Option<BattingPosition>| runner.map_or(BaseOutcome::None, BaseOutcome::Runner);
+                PlayOutcome::new(
+                    slot(after.first()),
+                    slot(after.second()),
+                    slot(after.third()),
+                    HomeOutcome::new_with_runs(self.runs_scored),
+                )
+            }
+        };
+        play.runner_movements(&self.baserunners_before, self.batting_position)
+    }
+
+    /// Errors charged to the fielding team on this pitch. The engine does not record which fielder
+    /// misplayed the ball, so a play on which runners move up on errors counts as one.
+    pub fn errors(&self) -> u8 {
+        let on_error = self
+            .movements()
+            .iter()
+            .any(|movement| movement.advancement() == Advancement::Error);
+        u8::from(on_error)
+    }
+
+    /// Who crossed the plate on this pitch, lead runner first.
+    pub fn scorers(&self) -> Vec<BattingPosition> {
+        self.movements()
+            .into_iter()
+            .filter(|movement| movement.scored())
+            .map(|movement| movement.runner())
+            .take(self.runs_scored as usize)
+            .collect()
+    }
+}
+
+/// A runner put on second base to start an extra inning (Rule 7.01(b)). Any run they score is
+/// unearned.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlacedRunner {
+    inning: InningNumber,
+    half: InningHalf,
+    batting_position: BattingPosition,
+    runner: Player,
+}
+
+impl Display for PlacedRunner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let half = match self.half {
+            InningHalf::Top => "Top",
+            InningHalf::Bottom => "Bottom",
+        };
+        write!(
+            f,
+            "{} {}, {} placed on second base",
+            half,
+            self.inning.as_number(),
+            self.runner
+        )
+    }
+}
+
+impl PlacedRunner {
+    pub fn new(inning: InningNumber, half: InningHalf, batting_position: BattingPosition, runner: Player) -> Self {
+        PlacedRunner {
+            inning,
+            half,
+            batting_position,
+            runner,
+        }
+    }
+
+    pub fn inning(&self) -> InningNumber {
+        self.inning
+    }
+
+    pub fn half(&self) -> InningHalf {
+        self.half
+    }
+
+    pub fn batting_position(&self) -> BattingPosition {
+        self.batting_position
+    }
+
+    pub fn runner(&self) -> &Player {
+        &self.runner
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameEvent {
+    Pitch(PitchEvent),
+    Substitution(Substitution),
+    PlacedRunner(PlacedRunner),
+}
+
+impl Display for GameEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameEvent::Pitch(pitch) => write!(f, "{}", pitch),
+            GameEvent::Substitution(substitution) => write!(f, "{}", substitution),
+            GameEvent::PlacedRunner(placed_runner) => write!(f, "{}", placed_runner),
+        }
+    }
+}
+
+/// Append-only record of everything that happened in a game, in order.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameLog {
+    events: Vec<GameEvent>,
+}
+
+impl GameLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn push(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &GameEvent> {
+        self.events.iter()
+    }
+
+    pub fn pitches(&self) -> impl DoubleEndedIterator<Item = &PitchEvent> {
+        self.events.iter().filter_map(|event| match event {
+            GameEvent::Pitch(pitch) => Some(pitch),
+            GameEvent::Substitution(_) | GameEvent::PlacedRunner(_) => None,
+        })
+    }
+
+    /// The pitches and running plays in `half` of `inning`, in order.
+    pub fn half_inning(&self, inning: InningNumber, half: InningHalf) -> impl DoubleEndedIterator<Item = &PitchEvent> {
+        self.pitches()
+            .filter(move |pitch| pitch.inning() == inning && pitch.half() == half)
+    }
+
+    /// The pitches and running plays of each plate appearance, in order. The last one is still going
+    /// if it has not ended yet, and one cut short by the third out on a running play ends with the
+    /// half inning.
+    pub fn plate_appearances(&self) -> Vec<Vec<&PitchEvent>> {
+        let mut plate_appearances: Vec<Vec<&PitchEvent>> = Vec::new();
+        let mut previous: Option<&PitchEvent> = None;
+        for pitch in self.pitches() {
+            let same = previous.is_some_and(|previous| {
+                !previous.ends_plate_appearance()
+                    && previous.inning() == pitch.inning()
+                    && previous.half() == pitch.half()
+            });
+            match plate_appearances.last_mut() {
+                Some(plate_appearance) if same => plate_appearance.push(pitch),
+                _ => plate_appearances.push(vec![pitch]),
+            }
+            previous = Some(pitch);
+        }
+        plate_appearances
+    }
+
+    pub fn substitutions(&self) -> impl DoubleEndedIterator<Item = &Substitution> {
+        self.events.iter().filter_map(|event| match event {
+            GameEvent::Substitution(substitution) => Some(substitution),
+            GameEvent::Pitch(_) | GameEvent::PlacedRunner(_) => None,
+        })
+    }
+}
+
+impl<'a> IntoIterator for &'a GameLog {
+    type Item = &'a GameEvent;
+    type IntoIter = std::slice::Iter<'a, GameEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseball::{
+        baserunners::PlayOutcome,
+        game::{Game, GameResult},
+        plate_appearance::{Balls, Strikes},
+    };
+
+    fn play(pitches: &[PitchOutcome]) -> GameResult {
+        pitches
+            .iter()
+            .fold(GameResult::InProgress(Game::new()), |result, pitch| {
+                result.advance(*pitch)
+            })
+    }
+
+    #[test]
+    fn test_pitches_are_logged_in_order() {
+        let result = play(&[PitchOutcome::Ball, PitchOutcome::Strike, PitchOutcome::Foul]);
+        let game = result.game().expect("Game should continue");
+
+        let pitches: Vec<&PitchEvent> = game.log().pitches().collect();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(
+            pitches.iter().map(|event| event.pitch()).collect::<Vec<_>>(),
+            vec![PitchOutcome::Ball, PitchOutcome::Strike, PitchOutcome::Foul]
+        );
+
+        assert_eq!(pitches[0].count_before(), Count::default());
+        assert_eq!(pitches[0].count_after(), Some(Count::new(Balls::One, Strikes::Zero)));
+        assert_eq!(pitches[2].count_before(), Count::new(Balls::One, Strikes::One));
+        assert_eq!(pitches[2].count_after(), Some(Count::new(Balls::One, Strikes::Two)));
+        assert!(pitches.iter().all(|event| event.batter().name() == "Batter 1"));
+        assert!(pitches.iter().all(|event| !event.ends_plate_appearance()));
+    }
+
+    #[test]
+    fn test_plate_appearance_ending_pitch() {
+        let result = play(&[
+            PitchOutcome::Ball,
+            PitchOutcome::Ball,
+            PitchOutcome::Ball,
+            PitchOutcome::Ball,
+            PitchOutcome::HomeRun,
+        ]);
+        let game = result.game().expect("Game should continue");
+        let pitches: Vec<&PitchEvent> = game.log().pitches().collect();
+
+        let walk = pitches[3];
+        assert!(walk.ends_plate_appearance());
+        assert_eq!(walk.count_after(), None);
+        assert_eq!(walk.result(), PlateAppearanceResult::Walk);
+        assert!(walk.baserunners_before().is_empty());
+        assert_eq!(walk.baserunners_after().first(), Some(BattingPosition::First));
+
+        let home_run = pitches[4];
+        assert_eq!(home_run.batting_position(), BattingPosition::Second);
+        assert_eq!(home_run.runs_scored(), 2);
+        assert!(home_run.baserunners_after().is_empty());
+        assert_eq!(home_run.inning(), InningNumber::First);
+        assert_eq!(home_run.half(), InningHalf::Top);
+    }
+
+    #[test]
+    fn test_inning_ending_pitch() {
+        let groundout = PitchOutcome::InPlay(PlayOutcome::groundout());
+        let result = play(&[groundout, groundout, groundout, PitchOutcome::Strike]);
+        let game = result.game().expect("Game should continue");
+        let pitches: Vec<&PitchEvent> = game.log().pitches().collect();
+
+        assert_eq!(pitches[2].outs_before(), Outs::Two);
+        assert_eq!(pitches[2].outs_after(), Outs::Three);
+        assert_eq!(pitches[3].half(), InningHalf::Bottom);
+        assert_eq!(pitches[3].outs_before(), Outs::Zero);
+    }
+
+    #[test]
+    fn test_half_innings_and_plate_appearances() {
+        let groundout = PitchOutcome::InPlay(PlayOutcome::groundout());
+        let result = play(&[
+            PitchOutcome::Ball,
+            PitchOutcome::HomeRun,
+            groundout,
+            PitchOutcome::Strike,
+            groundout,
+            groundout,
+            PitchOutcome::Foul,
+        ]);
+        let game = result.game().expect("Game should continue");
+        let log = game.log();
+
+        let top: Vec<&PitchEvent> = log.half_inning(InningNumber::First, InningHalf::Top).collect();
+        assert_eq!(top.len(), 6);
+        assert!(top.last().is_some_and(|pitch| pitch.outs_after() == Outs::Three));
+        assert_eq!(log.half_inning(InningNumber::First, InningHalf::Bottom).count(), 1);
+
+        let plate_appearances = log.plate_appearances();
+        assert_eq!(
+            plate_appearances.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![2, 1, 2, 1, 1]
+        );
+        assert_eq!(plate_appearances[2][0].batting_position(), BattingPosition::Third);
+        assert_eq!(
+            plate_appearances[2][1].count_before(),
+            Count::new(Balls::Zero, Strikes::One)
+        );
+        // The bottom half's leadoff batter is still up
+        assert!(!plate_appearances[4][0].ends_plate_appearance());
+        assert_eq!(plate_appearances[4][0].half(), InningHalf::Bottom);
+    }
+
+    #[test]
+    fn test_log_survives_game_completion() {
+        let groundout = PitchOutcome::InPlay(PlayOutcome::groundout());
+        let mut result = GameResult::InProgress(Game::new()).advance(PitchOutcome::HomeRun);
+        while !result.is_complete() {
+            result = result.advance(groundout);
+        }
+
+        let summary = result.summary().expect("Game should be complete");
+        assert_eq!(summary.log().pitches().count(), 1 + 9 * 3 + 9 * 3);
+        assert_eq!(summary.log().pitches().map(PitchEvent::runs_scored).sum::<Runs>(), 1);
+        assert_eq!(summary.events().count(), summary.log().len());
+        assert!(
+            summary
+                .events()
+                .next()
+                .is_some_and(|event| event.to_string().starts_with("Top 1"))
+        );
+    }
+}
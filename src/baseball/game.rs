@@ -1,15 +1,26 @@
 use std::fmt::Display;
 
+use tracing::warn;
+
 use crate::{
     Runs,
     baseball::{
+        baserunners::{Base, BaserunnerState, PlayError},
+        box_score::BoxScore,
+        earned_runs::{ChargedRun, EarnedRuns},
+        events::{GameEvent, GameLog, PitchEvent, PlacedRunner},
         inning::{HalfInning, HalfInningResult, InningHalf},
-        lineup::BattingPosition,
+        line_score::LineScore,
+        lineup::{BattingPosition, Lineup, Player, PlayerPosition},
+        pitching::{LeadChange, PitchingDecisions, PitchingStint},
         plate_appearance::PitchOutcome,
+        rules::GameRules,
+        substitution::{LineupChange, Substitution, SubstitutionError, SubstitutionKind},
     },
 };
 
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InningNumber {
     #[default]
     First,
@@ -61,6 +72,7 @@ impl InningNumber {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameScore {
     away: Runs,
     home: Runs,
@@ -113,37 +125,111 @@ impl Default for GameScore {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameWinner {
     Away,
     Home,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Team {
+    Away,
+    Home,
+}
+
+impl Team {
+    pub fn opponent(self) -> Team {
+        match self {
+            Team::Away => Team::Home,
+            Team::Home => Team::Away,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameSummary {
     final_score: GameScore,
     innings_played: InningNumber,
-    winner: GameWinner,
+    winner: Option<GameWinner>,
+    line_score: LineScore,
+    away_starters: Lineup,
+    home_starters: Lineup,
+    away_pitchers: Vec<PitchingStint>,
+    home_pitchers: Vec<PitchingStint>,
+    lead_changes: Vec<LeadChange>,
+    log: GameLog,
 }
 
 impl Display for GameSummary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
+        writeln!(
             f,
             "Final Score: Away: {} - Home: {}",
             self.final_score.away, self.final_score.home
-        )
+        )?;
+        write!(f, "{}", self.line_score)?;
+
+        let decisions = self.decisions();
+        let credited = [
+            ("W", decisions.winning_pitcher()),
+            ("L", decisions.losing_pitcher()),
+            ("S", decisions.save()),
+        ];
+        for (index, (decision, pitcher)) in credited.into_iter().enumerate() {
+            if let Some(pitcher) = pitcher {
+                let separator = if index == 0 { "\n" } else { "  " };
+                write!(f, "{}{}: {}", separator, decision, pitcher)?;
+            }
+        }
+        Ok(())
     }
 }
 
 impl GameSummary {
-    pub fn new(final_score: GameScore, innings_played: InningNumber, winner: GameWinner) -> Self {
+    pub fn new(final_score: GameScore, innings_played: InningNumber, winner: Option<GameWinner>) -> Self {
         GameSummary {
             final_score,
             innings_played,
             winner,
+            line_score: LineScore::new().finish(),
+            away_starters: Lineup::default(),
+            home_starters: Lineup::default(),
+            away_pitchers: Vec::new(),
+            home_pitchers: Vec::new(),
+            lead_changes: Vec::new(),
+            log: GameLog::new(),
         }
     }
 
+    pub fn with_starting_lineups(mut self, away: Lineup, home: Lineup) -> Self {
+        self.away_starters = away;
+        self.home_starters = home;
+        self
+    }
+
+    pub fn with_line_score(mut self, line_score: LineScore) -> Self {
+        self.line_score = line_score;
+        self
+    }
+
+    pub fn with_log(mut self, log: GameLog) -> Self {
+        self.log = log;
+        self
+    }
+
+    pub fn with_pitchers(mut self, away: Vec<PitchingStint>, home: Vec<PitchingStint>) -> Self {
+        self.away_pitchers = away;
+        self.home_pitchers = home;
+        self
+    }
+
+    pub fn with_lead_changes(mut self, lead_changes: Vec<LeadChange>) -> Self {
+        self.lead_changes = lead_changes;
+        self
+    }
+
     pub fn final_score(&self) -> GameScore {
         self.final_score
     }
@@ -152,12 +238,66 @@ impl GameSummary {
         self.innings_played
     }
 
-    pub fn winner(&self) -> GameWinner {
+    /// The team that won, or `None` if the game ended in a tie.
+    pub fn winner(&self) -> Option<GameWinner> {
         self.winner
     }
+
+    pub fn line_score(&self) -> &LineScore {
+        &self.line_score
+    }
+
+    pub fn box_score(&self) -> BoxScore {
+        BoxScore::new(self)
+    }
+
+    /// The lineup a team started the game with, before any substitutions.
+    pub fn starting_lineup(&self, team: Team) -> &Lineup {
+        match team {
+            Team::Away => &self.away_starters,
+            Team::Home => &self.home_starters,
+        }
+    }
+
+    /// Every pitcher who pitched for a team, in the order they entered.
+    pub fn pitchers(&self, team: Team) -> &[PitchingStint] {
+        match team {
+            Team::Away => &self.away_pitchers,
+            Team::Home => &self.home_pitchers,
+        }
+    }
+
+    /// Each time a team went ahead, in order. The last one names the pitchers of record.
+    pub fn lead_changes(&self) -> &[LeadChange] {
+        &self.lead_changes
+    }
+
+    /// The win, loss, save and holds (Rules 9.17 and 9.19).
+    pub fn decisions(&self) -> PitchingDecisions {
+        PitchingDecisions::new(
+            self.winner,
+            &self.away_pitchers,
+            &self.home_pitchers,
+            &self.lead_changes,
+        )
+    }
+
+    /// Every pitch, substitution and placed runner of the game, in order.
+    pub fn events(&self) -> impl Iterator<Item = &GameEvent> {
+        self.log.iter()
+    }
+
+    pub fn log(&self) -> &GameLog {
+        &self.log
+    }
+
+    pub fn substitutions(&self) -> impl Iterator<Item = &Substitution> {
+        self.log.substitutions()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameState {
     Inning(InningHalf),
     InningEnd(InningHalf),
@@ -175,6 +315,7 @@ impl GameState {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Game {
     current_inning: InningNumber,
     state: GameState,
@@ -182,6 +323,17 @@ pub struct Game {
     current_half_inning: HalfInning,
     away_batting_order: BattingPosition,
     home_batting_order: BattingPosition,
+    away_lineup: Lineup,
+    home_lineup: Lineup,
+    away_starters: Lineup,
+    home_starters: Lineup,
+    away_pitchers: Vec<PitchingStint>,
+    home_pitchers: Vec<PitchingStint>,
+    lead_changes: Vec<LeadChange>,
+    earned_runs: EarnedRuns,
+    line_score: LineScore,
+    log: GameLog,
+    rules: GameRules,
 }
 
 impl Display for Game {
@@ -192,6 +344,29 @@ impl Display for Game {
 
 impl Game {
     pub fn new() -> Self {
+        Self::with_lineups(Lineup::default(), Lineup::default())
+    }
+
+    /// Starts a game with each team's first batter taken from the given slot in the batting order.
+    pub fn with_batting_orders(away_order: BattingPosition, home_order: BattingPosition) -> Self {
+        Game {
+            current_half_inning: HalfInning::new(InningHalf::Top, away_order),
+            away_batting_order: away_order,
+            home_batting_order: home_order,
+            ..Self::new()
+        }
+    }
+
+    /// Starts a game between two lineups. A lineup with a pitcher batting names its starting
+    /// pitcher; one using a designated hitter needs `with_starting_pitcher`.
+    pub fn with_lineups(away_lineup: Lineup, home_lineup: Lineup) -> Self {
+        let starter = |lineup: &Lineup| {
+            lineup
+                .pitcher()
+                .map(|pitcher| PitchingStint::new(pitcher.clone(), InningNumber::First, InningHalf::Top))
+                .into_iter()
+                .collect()
+        };
         Game {
             current_inning: InningNumber::First,
             state: GameState::Inning(InningHalf::Top),
@@ -199,20 +374,35 @@ impl Game {
             current_half_inning: HalfInning::new(InningHalf::Top, BattingPosition::First),
             away_batting_order: BattingPosition::First,
             home_batting_order: BattingPosition::First,
+            away_starters: away_lineup.clone(),
+            home_starters: home_lineup.clone(),
+            away_pitchers: starter(&away_lineup),
+            home_pitchers: starter(&home_lineup),
+            lead_changes: Vec::new(),
+            earned_runs: EarnedRuns::new(),
+            away_lineup,
+            home_lineup,
+            line_score: LineScore::new(),
+            log: GameLog::new(),
+            rules: GameRules::default(),
         }
     }
 
-    pub fn with_batting_orders(away_order: BattingPosition, home_order: BattingPosition) -> Self {
-        let first_half = HalfInning::new(InningHalf::Top, away_order);
+    /// Names the starting pitcher for a team whose lineup uses a designated hitter, so the pitcher
+    /// does not bat.
+    pub fn with_starting_pitcher(mut self, team: Team, pitcher: Player) -> Self {
+        *self.pitchers_mut(team) = vec![PitchingStint::new(pitcher, InningNumber::First, InningHalf::Top)];
+        self
+    }
 
-        Game {
-            current_inning: InningNumber::First,
-            state: GameState::Inning(InningHalf::Top),
-            score: GameScore::new(),
-            current_half_inning: first_half,
-            away_batting_order: away_order,
-            home_batting_order: home_order,
-        }
+    /// Plays the game under `rules` instead of the standard nine innings.
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    pub fn rules(&self) -> &GameRules {
+        &self.rules
     }
 
     pub fn current_inning(&self) -> InningNumber {
@@ -227,20 +417,334 @@ impl Game {
         self.score
     }
 
+    pub fn line_score(&self) -> &LineScore {
+        &self.line_score
+    }
+
     pub fn current_half_inning(&self) -> &HalfInning {
         &self.current_half_inning
     }
 
-    pub fn advance(mut self, outcome: PitchOutcome) -> GameResult {
-        match self.current_half_inning.advance(outcome) {
+    pub fn away_lineup(&self) -> &Lineup {
+        &self.away_lineup
+    }
+
+    pub fn home_lineup(&self) -> &Lineup {
+        &self.home_lineup
+    }
+
+    /// The lineup of the team currently at the plate.
+    pub fn batting_lineup(&self) -> &Lineup {
+        self.lineup(self.batting_team())
+    }
+
+    /// The lineup of the team currently in the field.
+    pub fn fielding_lineup(&self) -> &Lineup {
+        self.lineup(self.batting_team().opponent())
+    }
+
+    /// The lineup a team started the game with, before any substitutions.
+    pub fn starting_lineup(&self, team: Team) -> &Lineup {
+        match team {
+            Team::Away => &self.away_starters,
+            Team::Home => &self.home_starters,
+        }
+    }
+
+    pub fn lineup(&self, team: Team) -> &Lineup {
+        match team {
+            Team::Away => &self.away_lineup,
+            Team::Home => &self.home_lineup,
+        }
+    }
+
+    /// The pitcher currently in the game for `team`, if one has been named.
+    pub fn pitcher(&self, team: Team) -> Option<&Player> {
+        self.pitching_stint(team).map(PitchingStint::pitcher)
+    }
+
+    /// The current pitcher's time on the mound, with their pitch count and batters faced.
+    pub fn pitching_stint(&self, team: Team) -> Option<&PitchingStint> {
+        self.pitchers(team).last()
+    }
+
+    /// Every pitcher who has pitched for `team`, in the order they entered. The last is still in the game.
+    pub fn pitchers(&self, team: Team) -> &[PitchingStint] {
+        match team {
+            Team::Away => &self.away_pitchers,
+            Team::Home => &self.home_pitchers,
+        }
+    }
+
+    fn pitchers_mut(&mut self, team: Team) -> &mut Vec<PitchingStint> {
+        match team {
+            Team::Away => &mut self.away_pitchers,
+            Team::Home => &mut self.home_pitchers,
+        }
+    }
+
+    /// Each time a team has gone ahead so far, in order.
+    pub fn lead_changes(&self) -> &[LeadChange] {
+        &self.lead_changes
+    }
+
+    pub fn batting_team(&self) -> Team {
+        match self.current_half_inning.half() {
+            InningHalf::Top => Team::Away,
+            InningHalf::Bottom => Team::Home,
+        }
+    }
+
+    /// Every pitch, substitution and placed runner so far, in order.
+    pub fn events(&self) -> impl Iterator<Item = &GameEvent> {
+        self.log.iter()
+    }
+
+    pub fn log(&self) -> &GameLog {
+        &self.log
+    }
+
+    pub fn substitutions(&self) -> impl Iterator<Item = &Substitution> {
+        self.log.substitutions()
+    }
+
+    pub fn current_batter(&self) -> &Player {
+        self.batting_lineup().player(self.current_half_inning.current_batter())
+    }
+
+    pub fn runner_on(&self, base: Base) -> Option<&Player> {
+        self.current_half_inning
+            .baserunners()
+            .runner_on(base)
+            .map(|runner| self.batting_lineup().player(runner))
+    }
+
+    /// Sends a pinch hitter up in place of the current batter.
+    pub fn pinch_hit(&mut self, player: Player) -> Result<(), SubstitutionError> {
+        let team = self.batting_team();
+        let batting_position = self.current_half_inning.current_batter();
+        let position = self.lineup(team).position(batting_position);
+        let change = LineupChange::new(batting_position, player, position);
+
+        let lineup = self.lineup(team).substitute(&change)?;
+        self.record_substitution(SubstitutionKind::PinchHitter, team, change, lineup);
+        Ok(())
+    }
+
+    /// Replaces the runner on `base` with a pinch runner.
+    pub fn pinch_run(&mut self, base: Base, player: Player) -> Result<(), SubstitutionError> {
+        let team = self.batting_team();
+        let batting_position = self
+            .current_half_inning
+            .baserunners()
+            .runner_on(base)
+            .ok_or(SubstitutionError::NoRunnerOn(base))?;
+        let position = self.lineup(team).position(batting_position);
+        let change = LineupChange::new(batting_position, player, position);
+
+        let lineup = self.lineup(team).substitute(&change)?;
+        self.record_substitution(SubstitutionKind::PinchRunner, team, change, lineup);
+        Ok(())
+    }
+
+    pub fn defensive_replacement(&mut self, team: Team, change: LineupChange) -> Result<(), SubstitutionError> {
+        let lineup = self.lineup(team).substitute(&change)?;
+        self.check_pitching_change(team, lineup.pitcher())?;
+        self.record_substitution(SubstitutionKind::Defensive, team, change, lineup);
+        self.sync_pitcher(team);
+        Ok(())
+    }
+
+    /// Brings in `pitcher` for `team`. A lineup with the pitcher batting puts them in the old
+    /// pitcher's spot in the order; with a designated hitter the new pitcher does not bat.
+    pub fn change_pitcher(&mut self, team: Team, pitcher: Player) -> Result<(), SubstitutionError> {
+        let pitcher_slot = self
+            .lineup(team)
+            .iter()
+            .find(|(_, slot)| slot.position() == PlayerPosition::Pitcher)
+            .map(|(batting_position, _)| batting_position);
+        if let Some(batting_position) = pitcher_slot {
+            let change = LineupChange::new(batting_position, pitcher, PlayerPosition::Pitcher);
+            return self.defensive_replacement(team, change);
+        }
+
+        let lineup = self.lineup(team).replace_pitcher(self.pitcher(team), &pitcher)?;
+        self.check_pitching_change(team, Some(&pitcher))?;
+        match team {
+            Team::Away => self.away_lineup = lineup,
+            Team::Home => self.home_lineup = lineup,
+        }
+        if let Some(outgoing) = self.pitcher(team) {
+            self.log.push(GameEvent::Substitution(Substitution::pitching_change(
+                team,
+                self.current_inning,
+                self.current_half_inning.half(),
+                outgoing.clone(),
+                pitcher.clone(),
+            )));
+        }
+        let stint = PitchingStint::new(pitcher, self.current_inning, self.current_half_inning.half());
+        self.pitchers_mut(team).push(stint);
+        Ok(())
+    }
+
+    /// Checks that the pitcher on the mound may leave for `incoming` under the three-batter minimum.
+    fn check_pitching_change(&self, team: Team, incoming: Option<&Player>) -> Result<(), SubstitutionError> {
+        let (Some(incoming), Some(stint)) = (incoming, self.pitching_stint(team)) else {
+            return Ok(());
+        };
+        if !self.rules.three_batter_minimum() || incoming == stint.pitcher() || stint.can_be_replaced() {
+            return Ok(());
+        }
+        Err(SubstitutionError::ThreeBatterMinimum(stint.batters_faced()))
+    }
+
+    /// Starts a new stint when the lineup's pitcher is not the one on the mound.
+    fn sync_pitcher(&mut self, team: Team) {
+        let Some(pitcher) = self.lineup(team).pitcher() else {
+            return;
+        };
+        if self.pitcher(team) != Some(pitcher) {
+            let stint = PitchingStint::new(pitcher.clone(), self.current_inning, self.current_half_inning.half());
+            self.pitchers_mut(team).push(stint);
+        }
+    }
+
+    /// Two simultaneous substitutions that also swap places in the batting order,
+    /// typically a new pitcher taking the slot of a position player who is due up later.
+    pub fn double_switch(
+        &mut self,
+        team: Team,
+        first: LineupChange,
+        second: LineupChange,
+    ) -> Result<(), SubstitutionError> {
+        let lineup = self.lineup(team).double_switch(&first, &second)?;
+        self.check_pitching_change(team, lineup.pitcher())?;
+        let outgoing = self.lineup(team).player(second.batting_position()).clone();
+
+        self.record_substitution(SubstitutionKind::DoubleSwitch, team, first, lineup);
+        self.log.push(GameEvent::Substitution(Substitution::new(
+            SubstitutionKind::DoubleSwitch,
+            team,
+            self.current_inning,
+            self.current_half_inning.half(),
+            outgoing,
+            second,
+        )));
+        self.sync_pitcher(team);
+        Ok(())
+    }
+
+    /// Defensive changes made together, such as a new fielder coming in while others switch
+    /// positions. Only players entering the game are recorded as substitutions.
+    pub fn realign(&mut self, team: Team, changes: &[LineupChange]) -> Result<(), SubstitutionError> {
+        let lineup = self.lineup(team).realign(changes)?;
+        self.check_pitching_change(team, lineup.pitcher())?;
+
+        for change in changes {
+            let outgoing = self.lineup(team).player(change.batting_position()).clone();
+            if outgoing.number() != change.player().number() {
+                self.log.push(GameEvent::Substitution(Substitution::new(
+                    SubstitutionKind::Defensive,
+                    team,
+                    self.current_inning,
+                    self.current_half_inning.half(),
+                    outgoing,
+                    change.clone(),
+                )));
+            }
+        }
+
+        match team {
+            Team::Away => self.away_lineup = lineup,
+            Team::Home => self.home_lineup = lineup,
+        }
+        self.sync_pitcher(team);
+        Ok(())
+    }
+
+    fn record_substitution(&mut self, kind: SubstitutionKind, team: Team, change: LineupChange, lineup: Lineup) {
+        let outgoing = self.lineup(team).player(change.batting_position()).clone();
+        self.log.push(GameEvent::Substitution(Substitution::new(
+            kind,
+            team,
+            self.current_inning,
+            self.current_half_inning.half(),
+            outgoing,
+            change,
+        )));
+
+        match team {
+            Team::Away => self.away_lineup = lineup,
+            Team::Home => self.home_lineup = lineup,
+        }
+    }
+
+    /// Checks that a ball in play or running play fits the runners on base and the batter at the plate.
+    pub fn validate(&self, outcome: PitchOutcome) -> Result<(), PlayError> {
+        self.current_half_inning.validate(outcome)
+    }
+
+    /// Throws a pitch or makes a running play. A play that fails `validate` is rejected: nothing is
+    /// logged and the game stays as it was. Use `try_advance` to find out when that happens.
+    pub fn advance(self, outcome: PitchOutcome) -> GameResult {
+        self.try_advance(outcome).unwrap_or_else(|rejected| {
+            warn!("{}", rejected);
+            GameResult::InProgress(rejected.into_game())
+        })
+    }
+
+    /// Throws a pitch or makes a running play, or hands the game back untouched with the reason
+    /// `validate` rejects the play.
+    pub fn try_advance(self, outcome: PitchOutcome) -> Result<GameResult, RejectedPlay> {
+        match self.validate(outcome) {
+            Ok(()) => Ok(self.play(outcome)),
+            Err(error) => Err(RejectedPlay {
+                game: Box::new(self),
+                outcome,
+                error,
+            }),
+        }
+    }
+
+    /// Throws a pitch or makes a running play that has already passed `validate`.
+    fn play(mut self, outcome: PitchOutcome) -> GameResult {
+        let team = self.batting_team();
+        self.sync_pitcher(team.opponent());
+        let outcome = self.walk_off(outcome);
+        let before = self.current_half_inning;
+        let result = self.current_half_inning.advance(outcome);
+        let event = PitchEvent::new(
+            self.current_inning,
+            self.current_batter().clone(),
+            self.pitcher(team.opponent()).cloned(),
+            outcome,
+            &before,
+            &result,
+        );
+
+        if event.result().is_hit() {
+            self.line_score.add_hit(team);
+        }
+        self.line_score.add_runs(team, event.runs_scored());
+        self.line_score.add_errors(team.opponent(), event.errors());
+        let lead = self.lead(team.opponent(), before.runs_scored());
+        if let Some(stint) = self.pitchers_mut(team.opponent()).last_mut() {
+            stint.record(&event, lead);
+        }
+        let charged = self.earned_runs.record(&event);
+        self.record_lead_change(before.runs_scored(), event.runs_scored(), &charged);
+        self.log.push(GameEvent::Pitch(event));
+
+        match result {
             HalfInningResult::InProgress(half_inning) => {
                 self.current_half_inning = half_inning;
                 let pending_runs = self.current_half_inning.runs_scored();
-                if self.is_bottom_of_ninth() && self.should_end_game(pending_runs) {
+                if self.state.is_bottom() && self.should_end_game(pending_runs) {
+                    let left_on_base = self.current_half_inning.baserunners().runner_count();
+                    self.line_score.add_left_on_base(team, left_on_base);
                     self.complete_half_inning(pending_runs);
-                    let winner = self.score.winner().expect("Game should have winner");
-                    let game_summary = GameSummary::new(self.score, self.current_inning, winner);
-                    return GameResult::Complete(game_summary);
+                    return self.finish();
                 }
 
                 GameResult::InProgress(self)
@@ -248,13 +752,16 @@ impl Game {
 
             HalfInningResult::Complete(summary) => {
                 // Half inning completed, update score and advance
+                match self.current_half_inning.half() {
+                    InningHalf::Top => self.away_batting_order = summary.next_batter(),
+                    InningHalf::Bottom => self.home_batting_order = summary.next_batter(),
+                }
+                self.line_score.add_left_on_base(team, summary.left_on_base());
                 self.complete_half_inning(summary.runs_scored());
 
                 // Check if game should end
                 if self.should_end_game(0) {
-                    let winner = self.score.winner().expect("Game should have winner");
-                    let game_summary = GameSummary::new(self.score, self.current_inning, winner);
-                    return GameResult::Complete(game_summary);
+                    return self.finish();
                 }
 
                 // Start next half inning
@@ -264,6 +771,150 @@ impl Game {
         }
     }
 
+    /// The runs `team` leads by, counting `pending_runs` scored by the batting team in the half
+    /// inning so far, or zero if they are not ahead.
+    fn lead(&self, team: Team, pending_runs: Runs) -> Runs {
+        let (away, home) = match self.batting_team() {
+            Team::Away => (self.score.away() + pending_runs, self.score.home()),
+            Team::Home => (self.score.away(), self.score.home() + pending_runs),
+        };
+        match team {
+            Team::Away => away.saturating_sub(home),
+            Team::Home => home.saturating_sub(away),
+        }
+    }
+
+    /// Notes the batting team going ahead when the runs on a pitch take them from `pending_runs`
+    /// past the other team. Their pitcher is in line for the win, and the pitcher charged with the
+    /// go-ahead run for the loss.
+    fn record_lead_change(&mut self, pending_runs: Runs, runs_scored: Runs, charged: &[ChargedRun]) {
+        let team = self.batting_team();
+        let (batting, fielding) = match team {
+            Team::Away => (self.score.away(), self.score.home()),
+            Team::Home => (self.score.home(), self.score.away()),
+        };
+        let before = batting + pending_runs;
+        if before > fielding || before + runs_scored <= fielding {
+            return;
+        }
+        let losing_pitcher = charged
+            .get(usize::from(fielding - before))
+            .map_or(self.pitcher(team.opponent()), ChargedRun::pitcher);
+        self.lead_changes.push(LeadChange::new(
+            team,
+            self.current_inning,
+            self.current_half_inning.half(),
+            self.pitcher(team).cloned(),
+            losing_pitcher.cloned(),
+        ));
+    }
+
+    /// Scores a ball in play, strikeout play or running play that ends the game with only the runs needed to win
+    /// (Rule 7.01(g)). A home run hit out of the park is the exception, and every run on it counts. When the play
+    /// also makes the third out after the winning run scores, the game ends before that out.
+    fn walk_off(&self, outcome: PitchOutcome) -> PitchOutcome {
+        let half_inning = &self.current_half_inning;
+        let (batter, outs_before) = (half_inning.current_batter(), half_inning.outs());
+        let (runs, outs, counts) = match outcome {
+            PitchOutcome::InPlay(play) | PitchOutcome::CaughtFoul(play) | PitchOutcome::StrikeoutPlay(play) => (
+                play.runs_scored(),
+                play.outs(),
+                play.scores_on_third_out(batter, outs_before),
+            ),
+            PitchOutcome::Running(play) => (play.runs_scored(), play.outs(), true),
+            _ => return outcome,
+        };
+        let third_out = outs_before.as_number() + outs >= 3;
+        if !self.state.is_bottom() || (third_out && !counts) {
+            return outcome;
+        }
+
+        // Without the third out, a play that needs every run it scores is already scored as it stands
+        let pending_runs = half_inning.runs_scored();
+        let Some(runs_needed) =
+            (1..runs + Runs::from(third_out)).find(|runs| self.should_end_game(pending_runs + runs))
+        else {
+            return outcome;
+        };
+        let baserunners = half_inning.baserunners();
+        match outcome {
+            PitchOutcome::InPlay(play) => {
+                PitchOutcome::InPlay(play.walk_off(baserunners, batter, outs_before, runs_needed))
+            }
+            PitchOutcome::CaughtFoul(play) => {
+                PitchOutcome::CaughtFoul(play.walk_off(baserunners, batter, outs_before, runs_needed))
+            }
+            PitchOutcome::StrikeoutPlay(play) => {
+                PitchOutcome::StrikeoutPlay(play.walk_off(baserunners, batter, outs_before, runs_needed))
+            }
+            PitchOutcome::Running(play) => PitchOutcome::Running(play.walk_off(outs_before, runs_needed)),
+            _ => outcome,
+        }
+    }
+
+    /// Whether the game would count if it were called now: the official number of innings have
+    /// been played, or the home team is ahead in the bottom of the last of them (Rule 7.01(b)).
+    pub fn is_regulation_game(&self) -> bool {
+        let inning = self.current_inning.as_number();
+        let official_innings = self.rules.official_innings();
+        let home = self.score.home() + self.current_half_inning.runs_scored();
+        inning > official_innings || (self.state.is_bottom() && inning == official_innings && home > self.score.away())
+    }
+
+    /// Ends the game where it stands, as when weather stops play. The score is the one at the moment
+    /// the game is called. A game that is not yet a regulation game, or in which the visitors have
+    /// tied or taken the lead in the unfinished inning, is suspended instead (Rule 7.02(a)).
+    pub fn call(mut self) -> GameResult {
+        if !self.is_regulation_game() {
+            return GameResult::Suspended(self);
+        }
+
+        let pending_runs = self.current_half_inning.runs_scored();
+        let (away, home) = (self.score.away(), self.score.home());
+        let (away_before, final_away, final_home) = if self.state.is_bottom() {
+            let top_runs = self.line_score.away().inning(self.current_inning).unwrap_or(0);
+            (away - top_runs, away, home + pending_runs)
+        } else {
+            (away, away + pending_runs, home)
+        };
+        let visitors_caught_up = away_before <= home && final_away >= final_home && final_away > away_before;
+        let suspended_tie = final_away == final_home && self.rules.suspend_called_ties();
+        if visitors_caught_up || suspended_tie {
+            return GameResult::Suspended(self);
+        }
+
+        let left_on_base = self.current_half_inning.baserunners().runner_count();
+        self.line_score.add_left_on_base(self.batting_team(), left_on_base);
+        self.complete_half_inning(pending_runs);
+        let summary = self.summary();
+        match summary.winner() {
+            Some(_) => GameResult::Called(summary),
+            None => GameResult::Tied(summary),
+        }
+    }
+
+    /// Stops play so the game can be resumed later from exactly this point.
+    pub fn suspend(self) -> GameResult {
+        GameResult::Suspended(self)
+    }
+
+    fn finish(self) -> GameResult {
+        let summary = self.summary();
+        match summary.winner() {
+            Some(_) => GameResult::Complete(summary),
+            None => GameResult::Tied(summary),
+        }
+    }
+
+    fn summary(self) -> GameSummary {
+        GameSummary::new(self.score, self.current_inning, self.score.winner())
+            .with_line_score(self.line_score.finish())
+            .with_starting_lineups(self.away_starters, self.home_starters)
+            .with_pitchers(self.away_pitchers, self.home_pitchers)
+            .with_lead_changes(self.lead_changes)
+            .with_log(self.log)
+    }
+
     fn complete_half_inning(&mut self, pending_runs: Runs) {
         match self.state {
             GameState::Inning(InningHalf::Top) => {
@@ -282,49 +933,20 @@ impl Game {
 
     fn should_end_game(&self, pending_runs: Runs) -> bool {
         // The state represents the NEXT half inning to be played after completing a half
-        match self.current_inning {
-            // Regular innings 1-8: never end
-            InningNumber::First
-            | InningNumber::Second
-            | InningNumber::Third
-            | InningNumber::Fourth
-            | InningNumber::Fifth
-            | InningNumber::Sixth
-            | InningNumber::Seventh
-            | InningNumber::Eighth => false,
-
-            // 9th inning: special ending rules
-            InningNumber::Ninth => {
-                match self.state {
-                    GameState::InningEnd(InningHalf::Top) => {
-                        // Just finished top of 9th
-                        // Game ends if home team is winning
-                        self.score.home() > self.score.away()
-                    }
-                    GameState::InningEnd(InningHalf::Bottom) => {
-                        // Just finished bottom of 9th
-                        // Game ends if any team is winning
-                        self.score.home() != self.score.away()
-                    }
-                    GameState::Inning(InningHalf::Top) => false,
-                    GameState::Inning(InningHalf::Bottom) => self.score().home() + pending_runs > self.score().away(),
-                    GameState::Complete => true,
-                }
-            }
-
-            // Extra innings (10th, 11th, etc.)
-            InningNumber::Extra(_) => {
-                match self.state {
-                    GameState::InningEnd(InningHalf::Top) => false,
-                    GameState::InningEnd(InningHalf::Bottom) => {
-                        // Just finished bottom of extra inning
-                        // Game ends if any team is winning
-                        self.score.home() != self.score.away()
-                    }
-                    GameState::Inning(_) => false,
-                    GameState::Complete => true,
-                }
+        let inning = self.current_inning.as_number();
+        let (away, home) = (self.score.away(), self.score.home());
+        match self.state {
+            // Home team leads, so the bottom half is not needed
+            GameState::InningEnd(InningHalf::Top) => self.rules.is_decided(inning, home.saturating_sub(away)),
+            GameState::InningEnd(InningHalf::Bottom) => {
+                self.rules.is_decided(inning, home.abs_diff(away)) || (home == away && self.rules.ends_in_tie(inning))
             }
+            GameState::Inning(InningHalf::Top) => false,
+            // Walk-off: the home team takes a lead that cannot be answered
+            GameState::Inning(InningHalf::Bottom) => self
+                .rules
+                .is_decided(inning, (home + pending_runs).saturating_sub(away)),
+            GameState::Complete => true,
         }
     }
 
@@ -342,7 +964,23 @@ impl Game {
             self.current_inning = self.current_inning.next();
         }
 
+        match half {
+            InningHalf::Top => self.line_score.start_half(Team::Away),
+            InningHalf::Bottom => self.line_score.start_half(Team::Home),
+        }
+
         self.current_half_inning = HalfInning::new(half, batting_order);
+        if self.rules.extra_inning_runner() && self.rules.is_extra_inning(self.current_inning.as_number()) {
+            // The batter due up ahead of the leadoff hitter starts the inning on second base
+            let runner = batting_order.previous();
+            self.current_half_inning = self
+                .current_half_inning
+                .with_baserunners(BaserunnerState::new().set_second(Some(runner)));
+            let player = self.batting_lineup().player(runner).clone();
+            let placed = PlacedRunner::new(self.current_inning, half, runner, player);
+            self.earned_runs.place_runner(&placed);
+            self.log.push(GameEvent::PlacedRunner(placed));
+        }
         self.state = GameState::Inning(half);
         self
     }
@@ -373,10 +1011,6 @@ impl Game {
 
         format!("{} of the {}", half_text, inning_text)
     }
-
-    fn is_bottom_of_ninth(&self) -> bool {
-        self.current_inning == InningNumber::Ninth && self.state.is_bottom()
-    }
 }
 
 impl Default for Game {
@@ -386,9 +1020,51 @@ impl Default for Game {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameResult {
     InProgress(Game),
+    /// Played until one team won.
     Complete(GameSummary),
+    /// A regulation game ended early with one team ahead.
+    Called(GameSummary),
+    /// Ended level, either by the inning limit or by being called with the score tied.
+    Tied(GameSummary),
+    /// Stopped to be resumed later from the same point (Rule 7.02).
+    Suspended(Game),
+}
+
+/// A play `Game::try_advance` turned down, with the game as it stood before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedPlay {
+    game: Box<Game>,
+    outcome: PitchOutcome,
+    error: PlayError,
+}
+
+impl Display for RejectedPlay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Rejected {}: {}", self.outcome, self.error)
+    }
+}
+
+impl std::error::Error for RejectedPlay {}
+
+impl RejectedPlay {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn into_game(self) -> Game {
+        *self.game
+    }
+
+    pub fn outcome(&self) -> PitchOutcome {
+        self.outcome
+    }
+
+    pub fn error(&self) -> PlayError {
+        self.error
+    }
 }
 
 impl Display for GameResult {
@@ -396,6 +1072,9 @@ impl Display for GameResult {
         match self {
             GameResult::InProgress(game) => write!(f, "{}", game),
             GameResult::Complete(summary) => write!(f, "{}", summary),
+            GameResult::Called(summary) => write!(f, "Called game\n{}", summary),
+            GameResult::Tied(summary) => write!(f, "Tie game\n{}", summary),
+            GameResult::Suspended(game) => write!(f, "Suspended: {}", game),
         }
     }
 }
@@ -404,39 +1083,68 @@ impl GameResult {
     pub fn advance(self, outcome: PitchOutcome) -> GameResult {
         match self {
             GameResult::InProgress(game) => game.advance(outcome),
-            GameResult::Complete(_) => self, // Already complete, ignore the pitch
+            // Over or suspended, ignore the pitch
+            GameResult::Complete(_) | GameResult::Called(_) | GameResult::Tied(_) | GameResult::Suspended(_) => self,
         }
     }
 
+    /// Calls a game in progress; see [`Game::call`].
+    pub fn call(self) -> GameResult {
+        match self {
+            GameResult::InProgress(game) => game.call(),
+            _ => self,
+        }
+    }
+
+    pub fn suspend(self) -> GameResult {
+        match self {
+            GameResult::InProgress(game) => game.suspend(),
+            _ => self,
+        }
+    }
+
+    /// Picks a suspended game back up where it stopped.
+    pub fn resume(self) -> GameResult {
+        match self {
+            GameResult::Suspended(game) => GameResult::InProgress(game),
+            _ => self,
+        }
+    }
+
+    /// Whether the game is over, however it ended. A suspended game is not over.
     pub fn is_complete(&self) -> bool {
-        matches!(self, GameResult::Complete(_))
+        self.summary_ref().is_some()
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        matches!(self, GameResult::Suspended(_))
     }
 
     pub fn game(self) -> Option<Game> {
         match self {
             GameResult::InProgress(game) => Some(game),
-            GameResult::Complete(_) => None,
+            _ => None,
         }
     }
 
     pub fn game_ref(&self) -> Option<&Game> {
         match self {
             GameResult::InProgress(game) => Some(game),
-            GameResult::Complete(_) => None,
+            _ => None,
         }
     }
 
     pub fn summary(self) -> Option<GameSummary> {
         match self {
-            GameResult::InProgress(_) => None,
-            GameResult::Complete(summary) => Some(summary),
+            GameResult::Complete(summary) | GameResult::Called(summary) | GameResult::Tied(summary) => Some(summary),
+            GameResult::InProgress(_) | GameResult::Suspended(_) => None,
         }
     }
 
     pub fn summary_ref(&self) -> Option<&GameSummary> {
         match self {
-            GameResult::InProgress(_) => None,
-            GameResult::Complete(summary) => Some(summary),
+            GameResult::Complete(summary) | GameResult::Called(summary) | GameResult::Tied(summary) => Some(summary),
+            GameResult::InProgress(_) | GameResult::Suspended(_) => None,
         }
     }
 }
@@ -446,7 +1154,14 @@ mod tests {
     use tracing::{error, info};
 
     use super::*;
-    use crate::baseball::{baserunners::PlayOutcome, plate_appearance::PitchOutcome};
+    use crate::baseball::{
+        baserunners::{Base, BaserunnerState, PlayKind, PlayOutcome},
+        inning::Outs,
+        lineup::{Bats, LineupError, PlayerPosition, Roster, Throws},
+        movement::{Destination, RunnerMovement},
+        plate_appearance::{PitchOutcome, PlateAppearanceResult},
+        running::RunningPlay,
+    };
 
     #[test]
     fn test_inning_number_progression() {
@@ -459,6 +1174,16 @@ mod tests {
         assert!(tenth.is_extra());
     }
 
+    /// Grounds out the batter due up, forcing any runners along.
+    fn ground_out(result: GameResult) -> GameResult {
+        let Some(game) = result.game_ref() else {
+            return result;
+        };
+        let half_inning = game.current_half_inning();
+        let play = PlayOutcome::groundout_with(half_inning.baserunners(), half_inning.current_batter());
+        result.advance(PitchOutcome::InPlay(play))
+    }
+
     #[test]
     fn test_game_creation() {
         let game = Game::new();
@@ -524,6 +1249,570 @@ mod tests {
         assert_eq!(game.score().home(), 0);
     }
 
+    #[test]
+    fn test_invalid_play_is_rejected() {
+        let result = GameResult::InProgress(Game::new()).advance(PitchOutcome::InPlay(PlayOutcome::single(
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        )));
+        let game = result.game().expect("Game should continue");
+
+        // The runner on first vanishes without being put out
+        let groundout = PitchOutcome::InPlay(PlayOutcome::groundout());
+        assert_eq!(
+            game.validate(groundout),
+            Err(PlayError::RunnersNotConserved {
+                runners: 2,
+                accounted_for: 1
+            })
+        );
+
+        let rejected = game
+            .clone()
+            .try_advance(groundout)
+            .expect_err("Play should be rejected");
+        assert_eq!(rejected.outcome(), groundout);
+        assert_eq!(
+            rejected.error(),
+            PlayError::RunnersNotConserved {
+                runners: 2,
+                accounted_for: 1
+            }
+        );
+        assert_eq!(rejected.into_game(), game);
+
+        let unchanged = game.clone().advance(groundout).game().expect("Game should continue");
+        assert_eq!(unchanged, game);
+    }
+
+    #[test]
+    fn test_batting_order_carries_over_between_innings() {
+        let three_outs = |result: GameResult| ground_out(ground_out(ground_out(result)));
+
+        // Top 1st: single, then three outs
+        let result = GameResult::InProgress(Game::new()).advance(PitchOutcome::InPlay(PlayOutcome::single(
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        )));
+        let result = three_outs(result);
+
+        // Bottom 1st: three up, three down
+        let result = three_outs(result);
+
+        let game = result.game().expect("Game should continue");
+        assert_eq!(game.current_inning(), InningNumber::Second);
+        assert_eq!(game.current_half_inning().current_batter(), BattingPosition::Fifth);
+        assert_eq!(game.away_batting_order, BattingPosition::Fifth);
+        assert_eq!(game.home_batting_order, BattingPosition::Fourth);
+    }
+
+    #[test]
+    fn test_game_with_batting_orders() {
+        let game = Game::with_batting_orders(BattingPosition::Third, BattingPosition::Seventh);
+        assert_eq!(game.current_half_inning().current_batter(), BattingPosition::Third);
+        assert_eq!(game.current_batter().name(), "Batter 3");
+
+        let result = ground_out(ground_out(ground_out(GameResult::InProgress(game))));
+        let game = result.game().expect("Game should continue");
+        assert_eq!(game.state(), GameState::Inning(InningHalf::Bottom));
+        assert_eq!(game.current_half_inning().current_batter(), BattingPosition::Seventh);
+    }
+
+    #[test]
+    fn test_line_score() {
+        let strikeout = |result: GameResult| {
+            result
+                .advance(PitchOutcome::Strike)
+                .advance(PitchOutcome::Strike)
+                .advance(PitchOutcome::Strike)
+        };
+
+        // Top 1st: home run, single, then three strikeouts strand the runner
+        let mut result = GameResult::InProgress(Game::new())
+            .advance(PitchOutcome::HomeRun)
+            .advance(PitchOutcome::InPlay(PlayOutcome::single(
+                BaserunnerState::empty(),
+                BattingPosition::Second,
+            )));
+        for _ in 0..3 {
+            result = strikeout(result);
+        }
+
+        // Bottom 1st: reaching on a hit by pitch is not a base hit
+        result = result.advance(PitchOutcome::HitByPitch);
+        for _ in 0..3 {
+            result = strikeout(result);
+        }
+
+        let game = result.game_ref().expect("Game should continue");
+        let line_score = game.line_score();
+        assert_eq!(line_score.away().runs_by_inning(), &[1, 0]);
+        assert_eq!(line_score.home().runs_by_inning(), &[0]);
+        assert_eq!(line_score.away().hits(), 2);
+        assert_eq!(line_score.home().hits(), 0);
+        assert_eq!(line_score.away().left_on_base(), 1);
+        assert_eq!(line_score.home().left_on_base(), 1);
+        assert_eq!(line_score.away().errors(), 0);
+        assert_eq!(line_score.away().runs(), game.score().away());
+
+        while !result.is_complete() {
+            result = strikeout(result);
+        }
+
+        let summary = result.summary().expect("Game should be complete");
+        let line_score = summary.line_score();
+        assert!(line_score.is_final());
+        assert_eq!(line_score.innings(), 9);
+        assert_eq!(line_score.away().runs_by_inning().len(), 9);
+        // Home trails after the top of the 9th, so it bats in the bottom half
+        assert_eq!(line_score.home().runs_by_inning().len(), 9);
+        assert!(
+            summary
+                .to_string()
+                .contains("Away    1  0  0  0  0  0  0  0  0    1  2  0")
+        );
+    }
+
+    #[test]
+    fn test_line_score_skips_unplayed_bottom_ninth() {
+        let mut result = GameResult::InProgress(Game::new());
+        while result.game_ref().is_some_and(|game| game.batting_team() == Team::Away) {
+            result = result.advance(PitchOutcome::InPlay(PlayOutcome::groundout()));
+        }
+
+        // Bottom 1st: home team goes ahead and holds on
+        result = result.advance(PitchOutcome::HomeRun);
+        while !result.is_complete() {
+            result = result.advance(PitchOutcome::InPlay(PlayOutcome::groundout()));
+        }
+
+        let summary = result.summary().expect("Game should be complete");
+        assert_eq!(summary.line_score().home().runs_by_inning().len(), 8);
+        assert!(
+            summary
+                .to_string()
+                .contains("Home    1  0  0  0  0  0  0  0  X    1  1  0")
+        );
+    }
+
+    #[test]
+    fn test_game_with_lineups() {
+        let roster = (1..=9).fold(Roster::new("Visitors"), |roster, number| {
+            roster.with_player(Player::new(
+                format!("Visitor {}", number),
+                number,
+                Bats::Left,
+                Throws::Left,
+            ))
+        });
+        let away = roster
+            .lineup([
+                (1, PlayerPosition::CenterField),
+                (2, PlayerPosition::Shortstop),
+                (3, PlayerPosition::SecondBase),
+                (4, PlayerPosition::FirstBase),
+                (5, PlayerPosition::RightField),
+                (6, PlayerPosition::ThirdBase),
+                (7, PlayerPosition::LeftField),
+                (8, PlayerPosition::Catcher),
+                (9, PlayerPosition::Pitcher),
+            ])
+            .expect("lineup should be valid");
+
+        let game = Game::with_lineups(away, Lineup::default());
+        assert_eq!(game.current_batter().name(), "Visitor 1");
+        assert!(game.runner_on(Base::First).is_none());
+
+        let game = game
+            .advance(PitchOutcome::InPlay(PlayOutcome::single(
+                BaserunnerState::empty(),
+                BattingPosition::First,
+            )))
+            .game()
+            .expect("Game should continue");
+
+        assert_eq!(game.current_batter().name(), "Visitor 2");
+        assert_eq!(game.runner_on(Base::First).map(Player::name), Some("Visitor 1"));
+        assert_eq!(game.home_lineup(), &Lineup::default());
+        assert_eq!(
+            game.away_lineup().position(BattingPosition::First),
+            PlayerPosition::CenterField
+        );
+    }
+
+    fn bench_player(number: u8) -> Player {
+        Player::new(format!("Bench {}", number), number, Bats::Right, Throws::Right)
+    }
+
+    #[test]
+    fn test_pinch_hitter() {
+        let mut game = Game::new();
+        game.pinch_hit(bench_player(20)).expect("pinch hitter should be legal");
+
+        assert_eq!(game.current_batter().name(), "Bench 20");
+        assert_eq!(
+            game.away_lineup().position(BattingPosition::First),
+            PlayerPosition::Shortstop
+        );
+        assert_eq!(game.away_lineup().removed().len(), 1);
+
+        let substitution = game.substitutions().next().expect("substitution should be recorded");
+        assert_eq!(substitution.kind(), SubstitutionKind::PinchHitter);
+        assert_eq!(substitution.team(), Team::Away);
+        assert_eq!(substitution.outgoing().name(), "Batter 1");
+        assert_eq!(substitution.incoming().name(), "Bench 20");
+        assert_eq!(substitution.batting_position(), Some(BattingPosition::First));
+    }
+
+    #[test]
+    fn test_pinch_runner() {
+        let mut game = Game::new();
+        assert_eq!(
+            game.pinch_run(Base::First, bench_player(20)),
+            Err(SubstitutionError::NoRunnerOn(Base::First))
+        );
+
+        let mut game = game
+            .advance(PitchOutcome::InPlay(PlayOutcome::single(
+                BaserunnerState::empty(),
+                BattingPosition::First,
+            )))
+            .game()
+            .expect("Game should continue");
+        game.pinch_run(Base::First, bench_player(20))
+            .expect("pinch runner should be legal");
+
+        assert_eq!(game.runner_on(Base::First).map(Player::name), Some("Bench 20"));
+        assert_eq!(game.current_batter().name(), "Batter 2");
+        assert_eq!(
+            game.substitutions().map(Substitution::kind).collect::<Vec<_>>(),
+            vec![SubstitutionKind::PinchRunner]
+        );
+    }
+
+    #[test]
+    fn test_removed_player_may_not_reenter() {
+        let mut game = Game::new();
+        let starter = game.current_batter().clone();
+        game.pinch_hit(bench_player(20)).expect("pinch hitter should be legal");
+
+        assert_eq!(
+            game.defensive_replacement(
+                Team::Away,
+                LineupChange::new(BattingPosition::First, starter, PlayerPosition::Shortstop)
+            ),
+            Err(SubstitutionError::Reentry(1))
+        );
+        assert_eq!(game.substitutions().count(), 1);
+    }
+
+    #[test]
+    fn test_player_already_in_lineup() {
+        let mut game = Game::new();
+        let cleanup = game.away_lineup().player(BattingPosition::Fourth).clone();
+
+        assert_eq!(game.pinch_hit(cleanup), Err(SubstitutionError::AlreadyInLineup(4)));
+        assert_eq!(game.substitutions().count(), 0);
+    }
+
+    #[test]
+    fn test_defensive_replacement() {
+        let mut game = Game::new();
+
+        assert_eq!(
+            game.defensive_replacement(
+                Team::Home,
+                LineupChange::new(BattingPosition::Third, bench_player(30), PlayerPosition::Shortstop)
+            ),
+            Err(SubstitutionError::InvalidLineup(LineupError::DuplicatePosition(
+                PlayerPosition::Shortstop
+            )))
+        );
+
+        game.defensive_replacement(
+            Team::Home,
+            LineupChange::new(BattingPosition::Third, bench_player(30), PlayerPosition::CenterField),
+        )
+        .expect("defensive replacement should be legal");
+
+        assert_eq!(game.home_lineup().player(BattingPosition::Third).name(), "Bench 30");
+        assert_eq!(game.away_lineup(), &Lineup::default());
+        assert_eq!(
+            game.substitutions().map(Substitution::kind).collect::<Vec<_>>(),
+            vec![SubstitutionKind::Defensive]
+        );
+    }
+
+    #[test]
+    fn test_double_switch() {
+        let mut game = Game::new().with_rules(GameRules::new().with_three_batter_minimum(false));
+        let new_pitcher = LineupChange::new(BattingPosition::Seventh, bench_player(40), PlayerPosition::Pitcher);
+        let new_left_fielder = LineupChange::new(BattingPosition::Ninth, bench_player(41), PlayerPosition::LeftField);
+
+        // Neither half of the switch is legal on its own
+        assert!(game.defensive_replacement(Team::Home, new_pitcher.clone()).is_err());
+
+        game.double_switch(Team::Home, new_pitcher, new_left_fielder)
+            .expect("double switch should be legal");
+
+        let lineup = game.home_lineup();
+        assert_eq!(lineup.player(BattingPosition::Seventh).name(), "Bench 40");
+        assert_eq!(lineup.position(BattingPosition::Seventh), PlayerPosition::Pitcher);
+        assert_eq!(lineup.player(BattingPosition::Ninth).name(), "Bench 41");
+        assert_eq!(lineup.position(BattingPosition::Ninth), PlayerPosition::LeftField);
+
+        let outgoing: Vec<&str> = game.substitutions().map(|sub| sub.outgoing().name()).collect();
+        assert_eq!(outgoing, vec!["Batter 7", "Batter 9"]);
+        assert!(
+            game.substitutions()
+                .all(|sub| sub.kind() == SubstitutionKind::DoubleSwitch)
+        );
+    }
+
+    #[test]
+    fn test_realign_moves_fielders_and_brings_in_a_replacement() {
+        let mut game = Game::new();
+        // The left fielder moves to first base and a new left fielder takes the first baseman's slot
+        let changes = [
+            LineupChange::new(
+                BattingPosition::Seventh,
+                Player::new("Batter 7", 7, Bats::Right, Throws::Right),
+                PlayerPosition::FirstBase,
+            ),
+            LineupChange::new(BattingPosition::Fourth, bench_player(42), PlayerPosition::LeftField),
+        ];
+
+        game.realign(Team::Home, &changes).expect("realignment should be legal");
+
+        let lineup = game.home_lineup();
+        assert_eq!(lineup.position(BattingPosition::Seventh), PlayerPosition::FirstBase);
+        assert_eq!(lineup.player(BattingPosition::Fourth).name(), "Bench 42");
+        assert_eq!(lineup.position(BattingPosition::Fourth), PlayerPosition::LeftField);
+
+        let substitutions: Vec<&Substitution> = game.substitutions().collect();
+        assert_eq!(substitutions.len(), 1);
+        assert_eq!(substitutions[0].outgoing().name(), "Batter 4");
+        assert_eq!(substitutions[0].kind(), SubstitutionKind::Defensive);
+
+        // A position switch on its own still has to leave a valid defense
+        let switch = [LineupChange::new(
+            BattingPosition::First,
+            Player::new("Batter 1", 1, Bats::Right, Throws::Right),
+            PlayerPosition::Catcher,
+        )];
+        assert!(game.realign(Team::Away, &switch).is_err());
+    }
+
+    #[test]
+    fn test_pitch_counts_and_pitching_changes() {
+        let mut game = Game::new()
+            .with_rules(GameRules::new().with_three_batter_minimum(false))
+            .advance(PitchOutcome::Ball)
+            .advance(PitchOutcome::Strike)
+            .game()
+            .expect("Game should continue");
+        game = ground_out(GameResult::InProgress(game))
+            .game()
+            .expect("Game should continue");
+
+        assert_eq!(game.pitcher(Team::Home).map(Player::name), Some("Batter 9"));
+        let stint = game.pitching_stint(Team::Home).expect("home starter");
+        assert_eq!(stint.pitches(), 3);
+        assert_eq!(stint.batters_faced(), 1);
+        assert_eq!(stint.outs(), 1);
+        assert_eq!(game.pitching_stint(Team::Away).map(PitchingStint::pitches), Some(0));
+
+        game.change_pitcher(Team::Home, bench_player(40))
+            .expect("no minimum without the rule");
+        assert_eq!(game.home_lineup().player(BattingPosition::Ninth).name(), "Bench 40");
+        assert_eq!(game.pitchers(Team::Home).len(), 2);
+        assert_eq!(
+            game.substitutions().map(Substitution::kind).collect::<Vec<_>>(),
+            vec![SubstitutionKind::Defensive]
+        );
+
+        let result = GameResult::InProgress(game).advance(PitchOutcome::Ball);
+        let Some(GameEvent::Pitch(pitch)) = result.game_ref().and_then(|game| game.log().iter().last()) else {
+            panic!("expected a pitch");
+        };
+        assert_eq!(pitch.pitcher().map(Player::name), Some("Bench 40"));
+    }
+
+    #[test]
+    fn test_three_batter_minimum() {
+        let mut game = Game::new();
+        assert_eq!(
+            game.change_pitcher(Team::Home, bench_player(40)),
+            Err(SubstitutionError::ThreeBatterMinimum(0))
+        );
+
+        game = ground_out(GameResult::InProgress(game))
+            .game()
+            .expect("Game should continue");
+        let new_pitcher = LineupChange::new(BattingPosition::Seventh, bench_player(40), PlayerPosition::Pitcher);
+        let new_left_fielder = LineupChange::new(BattingPosition::Ninth, bench_player(41), PlayerPosition::LeftField);
+        assert_eq!(
+            game.double_switch(Team::Home, new_pitcher, new_left_fielder),
+            Err(SubstitutionError::ThreeBatterMinimum(1))
+        );
+        assert_eq!(game.pitchers(Team::Home).len(), 1);
+        assert_eq!(game.substitutions().count(), 0);
+
+        // Other defensive changes are still allowed
+        game.defensive_replacement(
+            Team::Home,
+            LineupChange::new(BattingPosition::Third, bench_player(30), PlayerPosition::CenterField),
+        )
+        .expect("defensive replacement should be legal");
+
+        // Retiring the side meets the minimum
+        game = ground_out(ground_out(GameResult::InProgress(game)))
+            .game()
+            .expect("Game should continue");
+        game.change_pitcher(Team::Home, bench_player(40))
+            .expect("the starter finished the half inning");
+        assert_eq!(game.pitcher(Team::Home).map(Player::name), Some("Bench 40"));
+        assert_eq!(
+            game.pitching_stint(Team::Home).map(PitchingStint::half),
+            Some(InningHalf::Bottom)
+        );
+    }
+
+    #[test]
+    fn test_change_pitcher_with_designated_hitter() {
+        let roster = (1..=10).fold(Roster::new("Home"), |roster, number| {
+            roster.with_player(Player::new(
+                format!("Home {}", number),
+                number,
+                Bats::Left,
+                Throws::Left,
+            ))
+        });
+        let order = [
+            (1, PlayerPosition::CenterField),
+            (2, PlayerPosition::Shortstop),
+            (3, PlayerPosition::SecondBase),
+            (4, PlayerPosition::FirstBase),
+            (5, PlayerPosition::RightField),
+            (6, PlayerPosition::ThirdBase),
+            (7, PlayerPosition::LeftField),
+            (8, PlayerPosition::Catcher),
+            (10, PlayerPosition::DesignatedHitter),
+        ];
+        let home = roster.lineup(order).expect("lineup should be valid");
+        let game = Game::with_lineups(Lineup::default(), home.clone());
+        assert_eq!(game.pitcher(Team::Home), None);
+
+        let starter = roster.player(9).expect("on the roster").clone();
+        let mut game = Game::with_lineups(Lineup::default(), home)
+            .with_rules(GameRules::new().with_three_batter_minimum(false))
+            .with_starting_pitcher(Team::Home, starter.clone())
+            .advance(PitchOutcome::Ball)
+            .game()
+            .expect("Game should continue");
+        assert_eq!(game.pitching_stint(Team::Home).map(PitchingStint::pitches), Some(1));
+
+        let designated_hitter = roster.player(10).expect("on the roster").clone();
+        assert_eq!(
+            game.change_pitcher(Team::Home, designated_hitter),
+            Err(SubstitutionError::AlreadyInLineup(10))
+        );
+        game.change_pitcher(Team::Home, bench_player(40))
+            .expect("pitching change should be legal");
+        assert_eq!(game.pitcher(Team::Home).map(Player::name), Some("Bench 40"));
+        let substitutions: Vec<&Substitution> = game.substitutions().collect();
+        assert_eq!(substitutions.len(), 1);
+        assert_eq!(substitutions[0].team(), Team::Home);
+        assert_eq!(substitutions[0].outgoing(), &starter);
+        assert_eq!(substitutions[0].incoming().name(), "Bench 40");
+        assert_eq!(substitutions[0].position(), PlayerPosition::Pitcher);
+        assert_eq!(substitutions[0].batting_position(), None);
+        assert_eq!(
+            game.change_pitcher(Team::Home, starter),
+            Err(SubstitutionError::Reentry(9))
+        );
+
+        // The new pitcher does not bat either
+        assert_eq!(game.home_lineup().pitcher(), None);
+    }
+
+    #[test]
+    fn test_replaced_pitcher_may_not_pinch_hit_or_run() {
+        let roster = (1..=10).fold(Roster::new("Away"), |roster, number| {
+            roster.with_player(Player::new(
+                format!("Away {}", number),
+                number,
+                Bats::Right,
+                Throws::Right,
+            ))
+        });
+        let order = [
+            (1, PlayerPosition::CenterField),
+            (2, PlayerPosition::Shortstop),
+            (3, PlayerPosition::SecondBase),
+            (4, PlayerPosition::FirstBase),
+            (5, PlayerPosition::RightField),
+            (6, PlayerPosition::ThirdBase),
+            (7, PlayerPosition::LeftField),
+            (8, PlayerPosition::Catcher),
+            (9, PlayerPosition::DesignatedHitter),
+        ];
+        let away = roster.lineup(order).expect("lineup should be valid");
+        let starter = roster.player(10).expect("on the roster").clone();
+        let mut game = Game::with_lineups(away, Lineup::default())
+            .with_rules(GameRules::new().with_three_batter_minimum(false))
+            .with_starting_pitcher(Team::Away, starter.clone());
+
+        game.change_pitcher(Team::Away, bench_player(40))
+            .expect("pitching change should be legal");
+        assert_eq!(game.away_lineup().removed(), std::slice::from_ref(&starter));
+        assert_eq!(game.pinch_hit(starter.clone()), Err(SubstitutionError::Reentry(10)));
+
+        let mut game = game
+            .advance(PitchOutcome::InPlay(PlayOutcome::single(
+                BaserunnerState::empty(),
+                BattingPosition::First,
+            )))
+            .game()
+            .expect("Game should continue");
+        assert_eq!(
+            game.pinch_run(Base::First, starter),
+            Err(SubstitutionError::Reentry(10))
+        );
+    }
+
+    #[test]
+    fn test_lead_changes() {
+        let mut game = Game::new().with_rules(GameRules::new().with_three_batter_minimum(false));
+        game.change_pitcher(Team::Home, bench_player(40))
+            .expect("no minimum without the rule");
+        let result = game.advance(PitchOutcome::HomeRun);
+        let game = ground_out(ground_out(ground_out(result)))
+            .game()
+            .expect("Game should continue");
+
+        let lead_changes = game.lead_changes();
+        assert_eq!(lead_changes.len(), 1);
+        assert_eq!(lead_changes[0].team(), Team::Away);
+        assert_eq!(lead_changes[0].winning_pitcher().map(Player::name), Some("Batter 9"));
+        assert_eq!(lead_changes[0].losing_pitcher().map(Player::name), Some("Bench 40"));
+
+        // Tying the game is not a lead change, going ahead is
+        let game = game.advance(PitchOutcome::HomeRun);
+        assert_eq!(game.game_ref().map(|game| game.lead_changes().len()), Some(1));
+        let game = game
+            .advance(PitchOutcome::HomeRun)
+            .game()
+            .expect("Game should continue");
+        let lead_changes = game.lead_changes();
+        assert_eq!(lead_changes.len(), 2);
+        assert_eq!(lead_changes[1].team(), Team::Home);
+        assert_eq!(lead_changes[1].inning(), InningNumber::First);
+        assert_eq!(lead_changes[1].half(), InningHalf::Bottom);
+        assert_eq!(lead_changes[1].winning_pitcher().map(Player::name), Some("Bench 40"));
+        assert_eq!(lead_changes[1].losing_pitcher().map(Player::name), Some("Batter 9"));
+    }
+
     #[test]
     fn test_inning_description() {
         let game = Game::new();
@@ -567,6 +1856,313 @@ mod tests {
         assert!(!game.should_end_game(0))
     }
 
+    fn ground_out_until(mut result: GameResult, done: impl Fn(&Game) -> bool) -> GameResult {
+        while result.game_ref().is_some_and(|game| !done(game)) {
+            result = ground_out(result);
+        }
+        result
+    }
+
+    #[test]
+    fn test_seven_inning_game() {
+        let result = GameResult::InProgress(Game::new().with_rules(GameRules::doubleheader()));
+        let result = ground_out_until(result, |game| game.batting_team() == Team::Home);
+        let result = ground_out_until(result.advance(PitchOutcome::HomeRun), |_| false);
+
+        let summary = result.summary().expect("Game should be complete");
+        assert_eq!(summary.innings_played(), InningNumber::Seventh);
+        assert_eq!(summary.winner(), Some(GameWinner::Home));
+        assert_eq!(summary.line_score().home().runs_by_inning().len(), 6);
+    }
+
+    #[test]
+    fn test_mercy_rule() {
+        let result = GameResult::InProgress(Game::new().with_rules(GameRules::little_league()));
+        let mut result = ground_out_until(result, |game| game.batting_team() == Team::Home);
+        for _ in 0..10 {
+            result = result.advance(PitchOutcome::HomeRun);
+        }
+
+        // A ten-run lead stands once the visitors have batted in the fourth
+        let result = ground_out_until(result, |_| false);
+        let summary = result.summary().expect("Game should be complete");
+        assert_eq!(summary.innings_played(), InningNumber::Fourth);
+        assert_eq!(summary.final_score().home(), 10);
+        assert_eq!(summary.line_score().home().runs_by_inning().len(), 3);
+    }
+
+    #[test]
+    fn test_mercy_rule_walk_off() {
+        let mut game = Game::new().with_rules(GameRules::little_league());
+        game.current_inning = InningNumber::Fourth;
+        game.state = GameState::Inning(InningHalf::Bottom);
+        game.score = GameScore::new().add_home_runs(5).add_away_runs(1);
+        assert!(!game.should_end_game(5));
+        assert!(game.should_end_game(6));
+
+        game.current_inning = InningNumber::Third;
+        assert!(!game.should_end_game(6));
+    }
+
+    #[test]
+    fn test_max_innings_ends_in_tie() {
+        let result = GameResult::InProgress(Game::new().with_rules(GameRules::new().with_max_innings(12)));
+        let result = ground_out_until(result, |_| false);
+
+        assert!(matches!(result, GameResult::Tied(_)));
+        let summary = result.summary().expect("Game should be complete");
+        assert_eq!(summary.innings_played(), InningNumber::Extra(12));
+        assert_eq!(summary.winner(), None);
+        assert_eq!(summary.final_score(), GameScore::new());
+    }
+
+    #[test]
+    fn test_walk_off_in_extra_innings() {
+        let result = GameResult::InProgress(Game::new());
+        let result = ground_out_until(result, |game| {
+            game.current_inning() == InningNumber::Extra(10) && game.batting_team() == Team::Home
+        });
+        let result = result.advance(PitchOutcome::HomeRun);
+
+        let summary = result.summary().expect("Game should end on the home run");
+        assert_eq!(summary.innings_played(), InningNumber::Extra(10));
+        assert_eq!(summary.winner(), Some(GameWinner::Home));
+    }
+
+    fn bases_loaded_in_bottom_half(inning: InningNumber) -> (Game, BaserunnerState) {
+        let loaded = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Third))
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::First));
+        let mut game = Game::new();
+        game.current_inning = inning;
+        game.state = GameState::Inning(InningHalf::Bottom);
+        game.score = GameScore::new().add_home_runs(3).add_away_runs(3);
+        game.current_half_inning =
+            HalfInning::new(InningHalf::Bottom, BattingPosition::Fourth).with_baserunners(loaded);
+        (game, loaded)
+    }
+
+    #[test]
+    fn test_walk_off_counts_only_the_winning_run() {
+        let (game, loaded) = bases_loaded_in_bottom_half(InningNumber::Extra(11));
+        let double = PlayOutcome::double(loaded, BattingPosition::Fourth);
+        let summary = game
+            .advance(PitchOutcome::InPlay(double))
+            .summary()
+            .expect("Game should end on the hit");
+
+        assert_eq!(summary.final_score().home(), 4);
+        assert_eq!(summary.winner(), Some(GameWinner::Home));
+        let pitch = summary.log().pitches().last().expect("Hit should be logged");
+        assert_eq!(pitch.runs_scored(), 1);
+        assert_eq!(pitch.baserunners_after().runner_count(), 3);
+        let PlateAppearanceResult::InPlay(play) = pitch.result() else {
+            panic!("Hit should be a ball in play");
+        };
+        assert_eq!(play.kind(), PlayKind::Single);
+    }
+
+    #[test]
+    fn test_walk_off_ends_before_the_third_out() {
+        // Two out, runners on second and third: both runners come home on a single, but the game ends with the
+        // first, before the batter is thrown out trying for second
+        let mut game = Game::new();
+        game.current_inning = InningNumber::Ninth;
+        game.state = GameState::Inning(InningHalf::Bottom);
+        let second_and_third = BaserunnerState::new()
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::Third));
+        game.score = GameScore::new().add_home_runs(3).add_away_runs(3);
+        game.current_half_inning = HalfInning::new(InningHalf::Bottom, BattingPosition::Fourth)
+            .with_baserunners(second_and_third)
+            .set_outs(Outs::Two);
+        let single = PlayOutcome::from_movements(
+            second_and_third,
+            &[
+                RunnerMovement::new(BattingPosition::Third, Base::Third, Destination::Safe(Base::Home)),
+                RunnerMovement::new(BattingPosition::Second, Base::Second, Destination::Safe(Base::Home)),
+                RunnerMovement::new(BattingPosition::Fourth, Base::Home, Destination::Out(Base::Second)),
+            ],
+        )
+        .expect("Runners on second and third")
+        .with_kind(PlayKind::Single);
+        let summary = game
+            .advance(PitchOutcome::InPlay(single))
+            .summary()
+            .expect("Game should end on the hit");
+
+        assert_eq!(summary.final_score().home(), 4);
+        let pitch = summary.log().pitches().last().expect("Hit should be logged");
+        assert_eq!(pitch.runs_scored(), 1);
+        assert_eq!(pitch.scorers(), vec![BattingPosition::Third]);
+        assert_eq!(pitch.outs_after(), Outs::Two);
+        assert_eq!(pitch.baserunners_after().first(), Some(BattingPosition::Fourth));
+        assert_eq!(pitch.baserunners_after().third(), Some(BattingPosition::Second));
+        let PlateAppearanceResult::InPlay(play) = pitch.result() else {
+            panic!("Hit should be a ball in play");
+        };
+        assert_eq!(play.kind(), PlayKind::Single);
+        assert_eq!(play.outs(), 0);
+    }
+
+    #[test]
+    fn test_walk_off_balk() {
+        let (game, loaded) = bases_loaded_in_bottom_half(InningNumber::Ninth);
+        let balk = RunningPlay::balk(loaded).expect("Bases loaded");
+        let summary = game
+            .advance(PitchOutcome::Running(balk))
+            .summary()
+            .expect("Game should end on the balk");
+
+        assert_eq!(summary.final_score().home(), 4);
+        let pitch = summary.log().pitches().last().expect("Balk should be logged");
+        assert_eq!(pitch.runs_scored(), 1);
+        assert_eq!(pitch.scorers(), vec![BattingPosition::First]);
+        assert!(!pitch.ends_plate_appearance());
+    }
+
+    #[test]
+    fn test_walk_off_grand_slam_counts_every_run() {
+        let (game, _) = bases_loaded_in_bottom_half(InningNumber::Ninth);
+        let summary = game
+            .advance(PitchOutcome::HomeRun)
+            .summary()
+            .expect("Game should end on the home run");
+
+        assert_eq!(summary.final_score().home(), 7);
+    }
+
+    #[test]
+    fn test_runs_before_the_ninth_all_count() {
+        let (game, loaded) = bases_loaded_in_bottom_half(InningNumber::Eighth);
+        let game = game
+            .advance(PitchOutcome::InPlay(PlayOutcome::double(
+                loaded,
+                BattingPosition::Fourth,
+            )))
+            .game()
+            .expect("Game should continue");
+
+        assert_eq!(game.current_half_inning().runs_scored(), 2);
+    }
+
+    #[test]
+    fn test_called_regulation_game() {
+        let result = GameResult::InProgress(Game::new());
+        let result = ground_out_until(result, |game| game.batting_team() == Team::Home);
+        let result = result.advance(PitchOutcome::HomeRun);
+        let result = ground_out_until(result, |game| game.current_inning() == InningNumber::Sixth);
+
+        let result = result.call();
+        assert!(matches!(result, GameResult::Called(_)));
+        assert!(result.is_complete());
+        let summary = result.summary().expect("Called game should have a summary");
+        assert_eq!(summary.winner(), Some(GameWinner::Home));
+        assert_eq!(summary.innings_played(), InningNumber::Sixth);
+    }
+
+    #[test]
+    fn test_called_before_regulation_is_suspended() {
+        let result = GameResult::InProgress(Game::new());
+        let result = ground_out_until(result, |game| game.current_inning() == InningNumber::Third);
+
+        let result = result.call();
+        assert!(result.is_suspended());
+        assert!(!result.is_complete());
+        assert!(result.clone().advance(PitchOutcome::Ball).is_suspended());
+
+        let game = result.resume().game().expect("Resumed game should be in progress");
+        assert_eq!(game.current_inning(), InningNumber::Third);
+        assert_eq!(game.state(), GameState::Inning(InningHalf::Top));
+    }
+
+    #[test]
+    fn test_regulation_game_after_four_and_a_half() {
+        let mut game = Game::new();
+        game.current_inning = InningNumber::Fifth;
+        game.state = GameState::Inning(InningHalf::Top);
+        assert!(!game.is_regulation_game());
+
+        game.state = GameState::Inning(InningHalf::Bottom);
+        game.score = GameScore::new().add_away_runs(1);
+        assert!(!game.is_regulation_game());
+
+        game.score = GameScore::new().add_home_runs(2).add_away_runs(1);
+        assert!(game.is_regulation_game());
+
+        game.current_inning = InningNumber::Sixth;
+        game.state = GameState::Inning(InningHalf::Top);
+        game.score = GameScore::new();
+        assert!(game.is_regulation_game());
+    }
+
+    #[test]
+    fn test_visitors_tying_in_unfinished_inning_suspends() {
+        let mut game = Game::new();
+        game.current_inning = InningNumber::Seventh;
+        game.score = GameScore::new().add_home_runs(2).add_away_runs(1);
+        game.current_half_inning = HalfInning::new(InningHalf::Top, BattingPosition::First);
+
+        let result = game.clone().advance(PitchOutcome::HomeRun).call();
+        assert!(result.is_suspended());
+
+        // A run that does not catch up still counts in the called score
+        game.score = GameScore::new().add_home_runs(3).add_away_runs(1);
+        let summary = game
+            .advance(PitchOutcome::HomeRun)
+            .call()
+            .summary()
+            .expect("Game should be called");
+        assert_eq!(summary.final_score().away(), 2);
+        assert_eq!(summary.winner(), Some(GameWinner::Home));
+    }
+
+    #[test]
+    fn test_visitors_leading_from_tie_in_unfinished_inning_suspends() {
+        let mut game = Game::new();
+        game.current_inning = InningNumber::Seventh;
+        game.current_half_inning = HalfInning::new(InningHalf::Top, BattingPosition::First);
+
+        let result = game.advance(PitchOutcome::HomeRun).call();
+        assert!(result.is_suspended());
+    }
+
+    #[test]
+    fn test_called_tie() {
+        let result = GameResult::InProgress(Game::new());
+        let result = ground_out_until(result, |game| game.current_inning() == InningNumber::Seventh);
+        assert!(matches!(result.clone().call(), GameResult::Tied(_)));
+
+        let result = GameResult::InProgress(Game::new().with_rules(GameRules::major_league()));
+        let result = ground_out_until(result, |game| game.current_inning() == InningNumber::Seventh);
+        assert!(result.call().is_suspended());
+    }
+
+    #[test]
+    fn test_extra_inning_runner() {
+        let result = GameResult::InProgress(Game::new().with_rules(GameRules::major_league()));
+        let result = ground_out_until(result, |game| game.current_inning() == InningNumber::Ninth);
+        let game = result.game_ref().expect("Game should be in progress");
+        assert!(game.current_half_inning().baserunners().is_empty());
+
+        let result = ground_out_until(result, |game| game.current_inning() == InningNumber::Extra(10));
+        let game = result.game_ref().expect("Game should be in progress");
+        let half_inning = game.current_half_inning();
+        assert_eq!(half_inning.current_batter(), BattingPosition::First);
+        assert_eq!(half_inning.baserunners().second(), Some(BattingPosition::Ninth));
+        assert_eq!(half_inning.baserunners().runner_count(), 1);
+
+        let Some(GameEvent::PlacedRunner(placed_runner)) = game.events().last() else {
+            panic!("Placing the runner should be logged");
+        };
+        assert_eq!(placed_runner.inning(), InningNumber::Extra(10));
+        assert_eq!(
+            placed_runner.runner(),
+            game.away_lineup().player(BattingPosition::Ninth)
+        );
+    }
+
     #[test]
     fn demo_baseball_game() {
         info!("Starting a new baseball game...");
@@ -755,7 +2351,49 @@ mod tests {
             info!("  Winner: {:?} team!", summary.winner());
             info!("  Innings played: {}", summary.innings_played().as_number());
             info!("  Type-safe baseball game simulation complete! ⚾");
-            return;
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_game_json_round_trip_mid_inning() {
+        let mut game = GameResult::InProgress(Game::new())
+            .advance(PitchOutcome::Ball)
+            .advance(PitchOutcome::InPlay(PlayOutcome::single(
+                BaserunnerState::empty(),
+                BattingPosition::First,
+            )))
+            .advance(PitchOutcome::Strike)
+            .game()
+            .expect("Game should continue");
+        game.pinch_run(Base::First, bench_player(30))
+            .expect("Pinch runner should be allowed");
+
+        let json = serde_json::to_string(&game).expect("Game should serialize");
+        let restored: Game = serde_json::from_str(&json).expect("Game should deserialize");
+        assert_eq!(restored, game);
+
+        // Play resumes exactly where it left off
+        let pitches = [PitchOutcome::Strike, PitchOutcome::Strike, PitchOutcome::HomeRun];
+        let original = pitches
+            .iter()
+            .fold(GameResult::InProgress(game), |result, pitch| result.advance(*pitch));
+        let resumed = pitches
+            .iter()
+            .fold(GameResult::InProgress(restored), |result, pitch| result.advance(*pitch));
+        assert_eq!(resumed, original);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_summary_json_round_trip() {
+        let mut result = GameResult::InProgress(Game::new()).advance(PitchOutcome::HomeRun);
+        while !result.is_complete() {
+            result = result.advance(PitchOutcome::InPlay(PlayOutcome::groundout()));
+        }
+
+        let json = serde_json::to_string(&result).expect("Result should serialize");
+        let restored: GameResult = serde_json::from_str(&json).expect("Result should deserialize");
+        assert_eq!(restored, result);
+    }
 }
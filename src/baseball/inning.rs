@@ -1,17 +1,19 @@
 use std::fmt::Display;
 
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::{
     Runs,
     baseball::{
-        baserunners::BaserunnerState,
-        lineup::BattingPosition,
-        plate_appearance::{PitchOutcome, PlateAppearance, PlateAppearanceResult},
+        baserunners::{BaserunnerState, PlayError, PlayOutcome},
+        lineup::{BattingPosition, Lineup},
+        plate_appearance::{PitchOutcome, PlateAppearance, PlateAppearanceResult, Strikes},
+        running::RunningPlay,
     },
 };
 
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InningHalf {
     #[default]
     Top,
@@ -19,6 +21,7 @@ pub enum InningHalf {
 }
 
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Outs {
     #[default]
     Zero,
@@ -54,6 +57,7 @@ impl Outs {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HalfInning {
     half: InningHalf,
     outs: Outs,
@@ -113,38 +117,103 @@ impl HalfInning {
     }
 
     fn increment_outs(self, n: u8) -> HalfInningResult {
+        match self.record_outs(n, self.current_batter.next()) {
+            HalfInningResult::InProgress(half_inning) => half_inning.advance_batter(),
+            complete => complete,
+        }
+    }
+
+    /// Adds `n` outs while the batter stays at the plate. The third out ends the half inning with
+    /// `next_batter` leading off this team's next one.
+    fn record_outs(self, n: u8, next_batter: BattingPosition) -> HalfInningResult {
         let mut outs = self.outs;
         for _ in 0..n {
             outs = outs.add_out();
 
             if matches!(outs, Outs::Three) {
                 debug!("Inning over, runs scored: {}", self.runs_scored);
-                return HalfInningResult::Complete(HalfInningSummary::new(self.runs_scored));
+                return HalfInningResult::Complete(HalfInningSummary::new(
+                    self.runs_scored,
+                    next_batter,
+                    self.baserunners,
+                ));
+            }
+        }
+
+        HalfInningResult::in_progress(self.set_outs(outs))
+    }
+
+    /// Checks that a ball in play or running play fits the runners on base and the batter at the plate.
+    /// A strikeout play must come on strike three, and the batter can only reach first on it when
+    /// first base is open or there are two out (Rule 5.05(a)(2)). A caught foul puts the batter out.
+    pub fn validate(&self, outcome: PitchOutcome) -> Result<(), PlayError> {
+        match outcome {
+            PitchOutcome::InPlay(play) => play.validate(&self.baserunners, self.current_batter),
+            PitchOutcome::CaughtFoul(play) => {
+                play.validate(&self.baserunners, self.current_batter)?;
+                let batter_out = play
+                    .runner_movements(&self.baserunners, self.current_batter)
+                    .iter()
+                    .any(|movement| movement.is_batter() && movement.is_out());
+                match batter_out {
+                    true => Ok(()),
+                    false => Err(PlayError::BatterSafeOnCaughtFoul),
+                }
+            }
+            PitchOutcome::StrikeoutPlay(play) => {
+                if self.current_pa.count().strikes() != Strikes::Two {
+                    return Err(PlayError::NotStrikeThree);
+                }
+                play.validate(&self.baserunners, self.current_batter)?;
+                let batter_safe = play
+                    .runner_movements(&self.baserunners, self.current_batter)
+                    .iter()
+                    .any(|movement| movement.is_batter() && !movement.is_out());
+                match batter_safe && !self.baserunners.batter_can_run_on_third_strike(self.outs) {
+                    true => Err(PlayError::FirstBaseOccupied),
+                    false => Ok(()),
+                }
             }
+            PitchOutcome::Running(play) => play.apply(&self.baserunners).map(|_| ()),
+            _ => Ok(()),
         }
+    }
 
-        self.set_outs(outs).advance_batter()
+    /// Throws a pitch or makes a running play. A play that fails `validate` is rejected and leaves
+    /// the half inning as it was; use `try_advance` to find out why.
+    pub fn advance(self, outcome: PitchOutcome) -> HalfInningResult {
+        self.try_advance(outcome).unwrap_or_else(|error| {
+            warn!("Rejected {}: {}", outcome, error);
+            HalfInningResult::in_progress(self)
+        })
     }
 
-    pub fn advance(mut self, outcome: PitchOutcome) -> HalfInningResult {
+    /// Throws a pitch or makes a running play, or returns why `validate` rejects it.
+    pub fn try_advance(mut self, outcome: PitchOutcome) -> Result<HalfInningResult, PlayError> {
+        self.validate(outcome)?;
+
+        if let PitchOutcome::Running(play) = outcome {
+            return Ok(self.run(play));
+        }
+
         let pa = self.current_pa.advance(outcome);
 
-        match pa {
-            PlateAppearanceResult::Strikeout => self.increment_outs(1),
-            PlateAppearanceResult::InPlay(outcome) => {
-                let outs = outcome.outs();
-                let baserunners = outcome.baserunners();
-                let runs_scored = outcome.runs_scored();
-                self.add_runs(runs_scored)
-                    .with_baserunners(baserunners)
-                    .increment_outs(outs)
-            }
+        let result = match pa {
+            PlateAppearanceResult::Strikeout => match outcome {
+                PitchOutcome::StrikeoutPlay(play) => self.complete_play(play),
+                _ => self.increment_outs(1),
+            },
+            PlateAppearanceResult::InPlay(outcome) => self.complete_play(outcome),
             PlateAppearanceResult::Walk => {
                 let (baserunners, runs) = self.baserunners.walk(self.current_batter);
                 self.add_runs(runs).with_baserunners(baserunners).advance_batter()
             }
             PlateAppearanceResult::HitByPitch => {
-                let (baserunners, runs) = self.baserunners.walk(self.current_batter);
+                let (baserunners, runs) = self.baserunners.hit_by_pitch(self.current_batter);
+                self.add_runs(runs).with_baserunners(baserunners).advance_batter()
+            }
+            PlateAppearanceResult::CatcherInterference => {
+                let (baserunners, runs) = self.baserunners.catcher_interference(self.current_batter);
                 self.add_runs(runs).with_baserunners(baserunners).advance_batter()
             }
             PlateAppearanceResult::HomeRun => {
@@ -158,10 +227,41 @@ impl HalfInning {
                 self.current_pa = pa;
                 HalfInningResult::in_progress(self)
             }
-        }
-    }
-
-    fn set_outs(mut self, outs: Outs) -> Self {
+        };
+        Ok(result)
+    }
+
+    /// Ends the plate appearance with the batter and runners moved as `play` describes. Runs do not
+    /// count when the third out is a force or the batter-runner put out before reaching first
+    /// (Rule 5.08(a)).
+    fn complete_play(self, play: PlayOutcome) -> HalfInningResult {
+        let outs = play.outs();
+        let baserunners = play.baserunners();
+        let third_out = self.outs.as_number() + outs >= 3;
+        let runs_scored = match third_out && !play.scores_on_third_out(self.current_batter, self.outs) {
+            true => 0,
+            false => play.runs_scored(),
+        };
+        self.add_runs(runs_scored)
+            .with_baserunners(baserunners)
+            .increment_outs(outs)
+    }
+
+    /// Moves the runners without ending the plate appearance. Runs count even when the play makes
+    /// the third out: no one is forced, and runners are taken to cross the plate before the out
+    /// (Rule 5.08(a)). A batter still at the plate after the third out leads off the next inning
+    /// (Rule 5.04(a)(2)).
+    fn run(self, play: RunningPlay) -> HalfInningResult {
+        let Ok((baserunners, runs)) = play.apply(&self.baserunners) else {
+            return HalfInningResult::in_progress(self);
+        };
+        let next_batter = self.current_batter;
+        self.add_runs(runs)
+            .with_baserunners(baserunners)
+            .record_outs(play.outs(), next_batter)
+    }
+
+    pub(crate) fn set_outs(mut self, outs: Outs) -> Self {
         self.outs = outs;
         self
     }
@@ -178,12 +278,12 @@ impl HalfInning {
         self
     }
 
-    fn with_baserunners(mut self, baserunners: BaserunnerState) -> Self {
+    pub(crate) fn with_baserunners(mut self, baserunners: BaserunnerState) -> Self {
         self.baserunners = baserunners;
         self
     }
 
-    pub fn summary(&self) -> Result<String, std::fmt::Error> {
+    pub fn summary(&self, lineup: &Lineup) -> Result<String, std::fmt::Error> {
         use std::fmt::Write;
 
         let baserunners = self.baserunners();
@@ -195,39 +295,61 @@ impl HalfInning {
             writeln!(message, "    Bases empty")?;
         } else {
             if let Some(runner) = baserunners.first() {
-                writeln!(message, "    1st: Batter #{}", runner.as_number())?;
+                writeln!(message, "    1st: {}", lineup.player(runner))?;
             }
             if let Some(runner) = baserunners.second() {
-                writeln!(message, "    2nd: Batter #{}", runner.as_number())?;
+                writeln!(message, "    2nd: {}", lineup.player(runner))?;
             }
             if let Some(runner) = baserunners.third() {
-                writeln!(message, "    3rd: Batter #{}", runner.as_number())?;
+                writeln!(message, "    3rd: {}", lineup.player(runner))?;
             }
         }
 
         writeln!(message, "  Runs scored this inning: {}", self.runs_scored)?;
-        write!(message, "  Current batter: #{}", self.current_batter().as_number())?;
+        write!(message, "  Current batter: {}", lineup.player(self.current_batter()))?;
 
         Ok(message)
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HalfInningSummary {
     runs_scored: Runs,
+    next_batter: BattingPosition,
+    stranded: BaserunnerState,
 }
 
 impl HalfInningSummary {
-    pub fn new(runs_scored: Runs) -> Self {
-        HalfInningSummary { runs_scored }
+    pub fn new(runs_scored: Runs, next_batter: BattingPosition, stranded: BaserunnerState) -> Self {
+        HalfInningSummary {
+            runs_scored,
+            next_batter,
+            stranded,
+        }
     }
 
     pub fn runs_scored(&self) -> Runs {
         self.runs_scored
     }
+
+    /// Who leads off this team's next half inning.
+    pub fn next_batter(&self) -> BattingPosition {
+        self.next_batter
+    }
+
+    /// Runners stranded on base when the third out was made.
+    pub fn stranded(&self) -> BaserunnerState {
+        self.stranded
+    }
+
+    pub fn left_on_base(&self) -> u8 {
+        self.stranded.runner_count()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HalfInningResult {
     InProgress(HalfInning),
     Complete(HalfInningSummary),
@@ -269,7 +391,11 @@ mod tests {
     use tracing::info;
 
     use super::*;
-    use crate::baseball::{baserunners::PlayOutcome, plate_appearance::PitchOutcome};
+    use crate::baseball::{
+        baserunners::{Base, BaseOutcome, PlayOutcome},
+        movement::{Advancement, Destination, RunnerMovement},
+        plate_appearance::{Balls, Count, PitchOutcome, Strikes},
+    };
 
     #[test]
     fn test_batting_position_as_number() {
@@ -345,6 +471,172 @@ mod tests {
         assert_eq!(half_inning.current_batter().as_number(), 2); // Next batter
     }
 
+    #[test]
+    fn test_half_inning_walk_with_runner_on_second() {
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Second)
+            .with_baserunners(BaserunnerState::new().set_second(Some(BattingPosition::First)));
+
+        let half_inning = half_inning
+            .advance(PitchOutcome::Ball)
+            .advance(PitchOutcome::Ball)
+            .advance(PitchOutcome::Ball)
+            .advance(PitchOutcome::Ball)
+            .half_inning()
+            .expect("unexpected inning end");
+
+        let baserunners = half_inning.baserunners();
+        assert_eq!(baserunners.first(), Some(BattingPosition::Second));
+        assert_eq!(baserunners.second(), Some(BattingPosition::First));
+        assert_eq!(baserunners.third(), None);
+        assert_eq!(half_inning.runs_scored(), 0);
+        assert_eq!(half_inning.current_batter(), BattingPosition::Third);
+    }
+
+    #[test]
+    fn test_half_inning_hit_by_pitch_with_bases_loaded() {
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Fourth).with_baserunners(
+            BaserunnerState::new()
+                .set_first(Some(BattingPosition::First))
+                .set_second(Some(BattingPosition::Second))
+                .set_third(Some(BattingPosition::Third)),
+        );
+
+        let half_inning = half_inning
+            .advance(PitchOutcome::HitByPitch)
+            .half_inning()
+            .expect("unexpected inning end");
+
+        let baserunners = half_inning.baserunners();
+        assert_eq!(baserunners.first(), Some(BattingPosition::Fourth));
+        assert_eq!(baserunners.second(), Some(BattingPosition::First));
+        assert_eq!(baserunners.third(), Some(BattingPosition::Second));
+        assert_eq!(half_inning.runs_scored(), 1);
+        assert_eq!(half_inning.outs(), Outs::Zero);
+    }
+
+    fn two_strikes(half_inning: HalfInning) -> HalfInning {
+        half_inning
+            .advance(PitchOutcome::Strike)
+            .advance(PitchOutcome::Strike)
+            .half_inning()
+            .expect("unexpected inning end")
+    }
+
+    #[test]
+    fn test_dropped_third_strike() {
+        let half_inning = two_strikes(HalfInning::new(InningHalf::Top, BattingPosition::First));
+        let dropped = PlayOutcome::dropped_third_strike(
+            BaserunnerState::empty(),
+            BattingPosition::First,
+            Outs::Zero,
+            Advancement::WildPitch,
+        )
+        .expect("First base is open");
+        let half_inning = half_inning
+            .advance(PitchOutcome::StrikeoutPlay(dropped))
+            .half_inning()
+            .expect("unexpected inning end");
+
+        assert_eq!(half_inning.outs(), Outs::Zero);
+        assert_eq!(half_inning.baserunners().first(), Some(BattingPosition::First));
+        assert_eq!(half_inning.current_batter(), BattingPosition::Second);
+    }
+
+    #[test]
+    fn test_dropped_third_strike_with_first_base_occupied() {
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::First));
+        assert_eq!(
+            PlayOutcome::dropped_third_strike(first, BattingPosition::Second, Outs::One, Advancement::PassedBall),
+            Err(PlayError::FirstBaseOccupied)
+        );
+
+        // A play built for two out does not fit the half inning with one
+        let dropped =
+            PlayOutcome::dropped_third_strike(first, BattingPosition::Second, Outs::Two, Advancement::PassedBall)
+                .expect("Two out");
+        let half_inning = two_strikes(
+            HalfInning::new(InningHalf::Top, BattingPosition::Second)
+                .with_baserunners(first)
+                .set_outs(Outs::One),
+        );
+        assert_eq!(
+            half_inning.validate(PitchOutcome::StrikeoutPlay(dropped)),
+            Err(PlayError::FirstBaseOccupied)
+        );
+
+        // With two out the runner is forced to second
+        let half_inning = half_inning
+            .set_outs(Outs::Two)
+            .advance(PitchOutcome::StrikeoutPlay(dropped))
+            .half_inning()
+            .expect("unexpected inning end");
+        assert_eq!(half_inning.outs(), Outs::Two);
+        assert_eq!(half_inning.baserunners().first(), Some(BattingPosition::Second));
+        assert_eq!(half_inning.baserunners().second(), Some(BattingPosition::First));
+    }
+
+    #[test]
+    fn test_caught_foul_puts_the_batter_out() {
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::First);
+        let single = PlayOutcome::single(BaserunnerState::empty(), BattingPosition::First);
+        assert_eq!(
+            half_inning.validate(PitchOutcome::CaughtFoul(single)),
+            Err(PlayError::BatterSafeOnCaughtFoul)
+        );
+
+        let popup = PlayOutcome::flyout();
+        let half_inning = half_inning
+            .advance(PitchOutcome::CaughtFoul(popup))
+            .half_inning()
+            .expect("unexpected inning end");
+        assert_eq!(half_inning.outs(), Outs::One);
+        assert_eq!(half_inning.current_batter(), BattingPosition::Second);
+    }
+
+    #[test]
+    fn test_strikeout_double_play() {
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::First));
+        let double_play =
+            PlayOutcome::strikeout_double_play(first, BattingPosition::Second, Base::First).expect("Runner on first");
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Second).with_baserunners(first);
+
+        // The play is strike three, so it needs two strikes first
+        assert_eq!(
+            half_inning.validate(PitchOutcome::StrikeoutPlay(double_play)),
+            Err(PlayError::NotStrikeThree)
+        );
+        assert_eq!(
+            half_inning.try_advance(PitchOutcome::StrikeoutPlay(double_play)).err(),
+            Some(PlayError::NotStrikeThree)
+        );
+
+        let half_inning = two_strikes(half_inning)
+            .advance(PitchOutcome::StrikeoutPlay(double_play))
+            .half_inning()
+            .expect("unexpected inning end");
+        assert_eq!(half_inning.outs(), Outs::Two);
+        assert!(half_inning.baserunners().is_empty());
+        assert_eq!(half_inning.current_batter(), BattingPosition::Third);
+    }
+
+    #[test]
+    fn test_half_inning_catcher_interference() {
+        let half_inning = HalfInning::new(InningHalf::Bottom, BattingPosition::Fifth)
+            .with_baserunners(BaserunnerState::new().set_third(Some(BattingPosition::Fourth)));
+
+        let half_inning = half_inning
+            .advance(PitchOutcome::CatcherInterference)
+            .half_inning()
+            .expect("unexpected inning end");
+
+        let baserunners = half_inning.baserunners();
+        assert_eq!(baserunners.first(), Some(BattingPosition::Fifth));
+        assert_eq!(baserunners.second(), None);
+        assert_eq!(baserunners.third(), None);
+        assert_eq!(half_inning.runs_scored(), 1);
+        assert_eq!(half_inning.current_batter(), BattingPosition::Sixth);
+    }
+
     #[test]
     fn test_three_outs_ends_half_inning() {
         let batting_pos = BattingPosition::First;
@@ -360,6 +652,207 @@ mod tests {
             .advance(PitchOutcome::InPlay(PlayOutcome::groundout()));
 
         assert!(advance.is_complete());
+        if let HalfInningResult::Complete(summary) = advance {
+            assert_eq!(summary.next_batter(), BattingPosition::Fourth);
+            assert_eq!(summary.left_on_base(), 0);
+        }
+    }
+
+    #[test]
+    fn test_no_run_scores_on_third_out_force_play() {
+        let bases_loaded = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::Third));
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Fourth)
+            .with_baserunners(bases_loaded)
+            .set_outs(Outs::One);
+
+        let double_play = PlayOutcome::double_play(bases_loaded, BattingPosition::Fourth).expect("Runner on first");
+        assert_eq!(double_play.runs_scored(), 1);
+
+        let HalfInningResult::Complete(summary) = half_inning.advance(PitchOutcome::InPlay(double_play)) else {
+            panic!("Double play should end the half inning");
+        };
+        assert_eq!(summary.runs_scored(), 0);
+    }
+
+    #[test]
+    fn test_run_scores_before_third_out_on_timing_play() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Second));
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Third)
+            .with_baserunners(baserunners)
+            .set_outs(Outs::Two);
+
+        // The run crosses the plate before the runner from first is tagged out at third
+        let single = PlayOutcome::single(baserunners, BattingPosition::Third)
+            .with_second(BaseOutcome::None)
+            .with_third(BaseOutcome::TagOut);
+        let HalfInningResult::Complete(summary) = half_inning.advance(PitchOutcome::InPlay(single)) else {
+            panic!("Tag out should end the half inning");
+        };
+        assert_eq!(summary.runs_scored(), 1);
+    }
+
+    #[test]
+    fn test_run_counts_when_third_out_follows_force() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Second));
+        let batter = BattingPosition::Third;
+        let half_inning = HalfInning::new(InningHalf::Top, batter)
+            .with_baserunners(baserunners)
+            .set_outs(Outs::One);
+
+        // Force at second for the second out, the run scores, then the batter-runner is tagged out
+        // stretching for second
+        let play = PlayOutcome::from_movements(
+            baserunners,
+            &[
+                RunnerMovement::new(BattingPosition::First, Base::First, Destination::ForceOut(Base::Second)),
+                RunnerMovement::new(BattingPosition::Second, Base::Third, Destination::Safe(Base::Home)),
+                RunnerMovement::new(batter, Base::Home, Destination::Out(Base::Second)),
+            ],
+        )
+        .expect("Play fits the runners");
+        assert!(play.scores_on_third_out(batter, Outs::One));
+
+        let HalfInningResult::Complete(summary) = half_inning.advance(PitchOutcome::InPlay(play)) else {
+            panic!("Tag out should end the half inning");
+        };
+        assert_eq!(summary.runs_scored(), 1);
+    }
+
+    #[test]
+    fn test_sacrifice_fly_scores_with_one_out() {
+        let baserunners = BaserunnerState::new().set_third(Some(BattingPosition::Second));
+        let half_inning = HalfInning::new(InningHalf::Bottom, BattingPosition::Third)
+            .with_baserunners(baserunners)
+            .set_outs(Outs::One);
+
+        let sacrifice_fly = PlayOutcome::sacrifice_fly(baserunners, BattingPosition::Third).expect("Runner on third");
+        let half_inning = half_inning
+            .advance(PitchOutcome::InPlay(sacrifice_fly))
+            .half_inning()
+            .expect("unexpected inning end");
+        assert_eq!(half_inning.runs_scored(), 1);
+        assert_eq!(half_inning.outs(), Outs::Two);
+        assert!(half_inning.baserunners().is_empty());
+    }
+
+    #[test]
+    fn test_stolen_base_keeps_the_count() {
+        let baserunners = BaserunnerState::new().set_first(Some(BattingPosition::First));
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Second)
+            .with_baserunners(baserunners)
+            .advance(PitchOutcome::Ball)
+            .half_inning()
+            .expect("unexpected inning end");
+
+        let steal = RunningPlay::stolen_base(baserunners, Base::First).expect("Runner on first");
+        let half_inning = half_inning
+            .advance(PitchOutcome::Running(steal))
+            .half_inning()
+            .expect("unexpected inning end");
+        assert_eq!(half_inning.baserunners().second(), Some(BattingPosition::First));
+        assert_eq!(half_inning.current_batter(), BattingPosition::Second);
+        assert_eq!(
+            half_inning.current_plate_appearance().count(),
+            Count::new(Balls::One, Strikes::Zero)
+        );
+
+        // The runner has already left first
+        assert_eq!(
+            half_inning.validate(PitchOutcome::Running(steal)),
+            Err(PlayError::UnknownRunner(BattingPosition::First))
+        );
+    }
+
+    #[test]
+    fn test_caught_stealing_for_the_third_out() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Third))
+            .set_third(Some(BattingPosition::Second));
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Fourth)
+            .with_baserunners(baserunners)
+            .set_outs(Outs::Two);
+
+        let caught_stealing = RunningPlay::caught_stealing(baserunners, Base::First).expect("Runner on first");
+        let HalfInningResult::Complete(summary) = half_inning.advance(PitchOutcome::Running(caught_stealing)) else {
+            panic!("Caught stealing should end the half inning");
+        };
+        // The batter never finished the plate appearance, so they lead off next time
+        assert_eq!(summary.next_batter(), BattingPosition::Fourth);
+        assert_eq!(summary.left_on_base(), 1);
+        assert_eq!(summary.runs_scored(), 0);
+    }
+
+    #[test]
+    fn test_wild_pitch_scores_a_run() {
+        let baserunners = BaserunnerState::new().set_third(Some(BattingPosition::First));
+        let half_inning = HalfInning::new(InningHalf::Bottom, BattingPosition::Second).with_baserunners(baserunners);
+
+        let wild_pitch = RunningPlay::wild_pitch(baserunners).expect("Runner on third");
+        let half_inning = half_inning
+            .advance(PitchOutcome::Running(wild_pitch))
+            .half_inning()
+            .expect("unexpected inning end");
+        assert_eq!(half_inning.runs_scored(), 1);
+        assert!(half_inning.baserunners().is_empty());
+        assert_eq!(half_inning.outs(), Outs::Zero);
+    }
+
+    #[test]
+    fn test_invalid_play_leaves_half_inning_unchanged() {
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Second)
+            .with_baserunners(BaserunnerState::new().set_first(Some(BattingPosition::First)));
+
+        // A runner appears on third from nowhere
+        let play = PlayOutcome::single(half_inning.baserunners(), BattingPosition::Second)
+            .with_third(BaseOutcome::Runner(BattingPosition::Seventh));
+        assert_eq!(
+            half_inning.validate(PitchOutcome::InPlay(play)),
+            Err(PlayError::UnknownRunner(BattingPosition::Seventh))
+        );
+
+        let advance = half_inning.advance(PitchOutcome::InPlay(play));
+        assert_eq!(advance.half_inning(), Some(half_inning));
+    }
+
+    #[test]
+    fn test_runners_left_on_base() {
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::First));
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Third)
+            .set_outs(Outs::Two)
+            .with_baserunners(baserunners);
+
+        let advance = [PitchOutcome::Strike, PitchOutcome::Strike, PitchOutcome::Strike]
+            .into_iter()
+            .fold(HalfInningResult::in_progress(half_inning), HalfInningResult::advance);
+
+        match advance {
+            HalfInningResult::Complete(summary) => {
+                assert_eq!(summary.left_on_base(), 2);
+                assert_eq!(summary.runs_scored(), 0);
+            }
+            HalfInningResult::InProgress(_) => panic!("Strikeout should end the half inning"),
+        }
+    }
+
+    #[test]
+    fn test_half_inning_summary_names_players() {
+        let lineup = Lineup::default();
+        let half_inning = HalfInning::new(InningHalf::Top, BattingPosition::Second)
+            .with_baserunners(BaserunnerState::new().set_third(Some(BattingPosition::First)));
+
+        let summary = half_inning.summary(&lineup).expect("half_inning should be valid");
+
+        assert!(summary.contains("3rd: #1 Batter 1"));
+        assert!(summary.contains("Current batter: #2 Batter 2"));
     }
 
     #[test]
@@ -406,9 +899,10 @@ mod tests {
 
         let batting_pos = BattingPosition::First;
         let mut half_inning = HalfInning::new(InningHalf::Bottom, batting_pos);
+        let lineup = Lineup::default();
 
         info!("Initial state: No runners on base");
-        info!("{}", half_inning.summary().expect("half_inning should be valid"));
+        info!("{}", half_inning.summary(&lineup).expect("half_inning should be valid"));
 
         // Start with the advance wrapper
         let mut advance = HalfInningResult::InProgress(half_inning);
@@ -420,7 +914,7 @@ mod tests {
             half_inning.current_batter(),
         )));
         if let Some(hi) = advance.half_inning() {
-            info!("{}", hi.summary().expect("half_inning should be valid"));
+            info!("{}", hi.summary(&lineup).expect("half_inning should be valid"));
         } else {
             info!("  Half inning ended unexpectedly");
             return;
@@ -444,7 +938,7 @@ mod tests {
         advance = advance.advance(PitchOutcome::Ball);
         if let Some(hi) = advance.half_inning() {
             half_inning = hi;
-            info!("{}", hi.summary().expect("half_inning should be valid"));
+            info!("{}", hi.summary(&lineup).expect("half_inning should be valid"));
         } else {
             return;
         }
@@ -457,7 +951,7 @@ mod tests {
         )));
         if let Some(hi) = advance.half_inning() {
             half_inning = hi;
-            info!("{}", hi.summary().expect("half_inning should be valid"));
+            info!("{}", hi.summary(&lineup).expect("half_inning should be valid"));
         } else {
             return;
         }
@@ -469,7 +963,7 @@ mod tests {
             half_inning.current_batter(),
         )));
         if let Some(hi) = advance.half_inning() {
-            info!("{}", hi.summary().expect("half_inning should be valid"));
+            info!("{}", hi.summary(&lineup).expect("half_inning should be valid"));
         } else {
             return;
         }
@@ -478,7 +972,7 @@ mod tests {
         info!("🏏 Batter #5: Home Run");
         advance = advance.advance(PitchOutcome::HomeRun);
         if let Some(hi) = advance.half_inning() {
-            info!("{}", hi.summary().expect("half_inning should be valid"));
+            info!("{}", hi.summary(&lineup).expect("half_inning should be valid"));
         } else {
             info!("  Half inning complete after home run");
             return;
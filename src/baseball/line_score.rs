@@ -0,0 +1,256 @@
+use std::fmt::Display;
+
+use crate::{
+    Runs,
+    baseball::game::{InningNumber, Team},
+};
+
+/// Innings shown on a line score even when the game ends early.
+const REGULATION_INNINGS: usize = 9;
+
+/// One team's row of the line score.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TeamLine {
+    runs_by_inning: Vec<Runs>,
+    hits: u8,
+    errors: u8,
+    left_on_base: u8,
+}
+
+impl TeamLine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs scored in each inning this team has batted, starting with the 1st.
+    pub fn runs_by_inning(&self) -> &[Runs] {
+        &self.runs_by_inning
+    }
+
+    /// Runs scored in `inning`, or `None` if the team has not batted in it.
+    pub fn inning(&self, inning: InningNumber) -> Option<Runs> {
+        self.runs_by_inning.get(inning.as_number() as usize - 1).copied()
+    }
+
+    pub fn runs(&self) -> Runs {
+        self.runs_by_inning.iter().sum()
+    }
+
+    pub fn hits(&self) -> u8 {
+        self.hits
+    }
+
+    pub fn errors(&self) -> u8 {
+        self.errors
+    }
+
+    pub fn left_on_base(&self) -> u8 {
+        self.left_on_base
+    }
+
+    fn start_inning(&mut self) {
+        self.runs_by_inning.push(0);
+    }
+
+    fn add_runs(&mut self, runs: Runs) {
+        if let Some(inning) = self.runs_by_inning.last_mut() {
+            *inning += runs;
+        }
+    }
+}
+
+/// Runs by inning plus hits, errors and runners left on base for both teams.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineScore {
+    away: TeamLine,
+    home: TeamLine,
+    is_final: bool,
+}
+
+impl Display for LineScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let innings = self.innings().max(REGULATION_INNINGS);
+
+        write!(f, "{:<6}", "")?;
+        for inning in 1..=innings {
+            write!(f, "{:>3}", inning)?;
+        }
+        writeln!(f, "  {:>3}{:>3}{:>3}", "R", "H", "E")?;
+
+        self.fmt_row(f, "Away", &self.away, innings)?;
+        writeln!(f)?;
+        self.fmt_row(f, "Home", &self.home, innings)
+    }
+}
+
+impl LineScore {
+    /// A line score for a game that has not started: the away team is about to bat in the 1st.
+    pub fn new() -> Self {
+        let mut away = TeamLine::new();
+        away.start_inning();
+
+        LineScore {
+            away,
+            home: TeamLine::new(),
+            is_final: false,
+        }
+    }
+
+    pub fn away(&self) -> &TeamLine {
+        &self.away
+    }
+
+    pub fn home(&self) -> &TeamLine {
+        &self.home
+    }
+
+    pub fn team(&self, team: Team) -> &TeamLine {
+        match team {
+            Team::Away => &self.away,
+            Team::Home => &self.home,
+        }
+    }
+
+    /// Number of innings in which at least one team has batted.
+    pub fn innings(&self) -> usize {
+        self.away.runs_by_inning.len().max(self.home.runs_by_inning.len())
+    }
+
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
+
+    pub(crate) fn start_half(&mut self, team: Team) {
+        self.team_mut(team).start_inning();
+    }
+
+    pub(crate) fn add_runs(&mut self, team: Team, runs: Runs) {
+        self.team_mut(team).add_runs(runs);
+    }
+
+    pub(crate) fn add_hit(&mut self, team: Team) {
+        self.team_mut(team).hits += 1;
+    }
+
+    pub(crate) fn add_errors(&mut self, team: Team, errors: u8) {
+        self.team_mut(team).errors += errors;
+    }
+
+    pub(crate) fn add_left_on_base(&mut self, team: Team, runners: u8) {
+        self.team_mut(team).left_on_base += runners;
+    }
+
+    /// Marks the game as over, so an unplayed bottom half is shown as `X`.
+    pub(crate) fn finish(mut self) -> Self {
+        self.is_final = true;
+        self
+    }
+
+    fn team_mut(&mut self, team: Team) -> &mut TeamLine {
+        match team {
+            Team::Away => &mut self.away,
+            Team::Home => &mut self.home,
+        }
+    }
+
+    fn fmt_row(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        name: &str,
+        line: &TeamLine,
+        innings: usize,
+    ) -> std::fmt::Result {
+        write!(f, "{:<6}", name)?;
+        for inning in 0..innings {
+            match line.runs_by_inning.get(inning) {
+                Some(runs) => write!(f, "{:>3}", runs)?,
+                None if self.is_final && inning < self.innings() => write!(f, "{:>3}", "X")?,
+                None => write!(f, "{:>3}", "")?,
+            }
+        }
+        write!(f, "  {:>3}{:>3}{:>3}", line.runs(), line.hits, line.errors)
+    }
+}
+
+impl Default for LineScore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_score(away: &[Runs], home: &[Runs]) -> LineScore {
+        let mut line_score = LineScore::new();
+        for (inning, runs) in away.iter().enumerate() {
+            if inning > 0 {
+                line_score.start_half(Team::Away);
+            }
+            line_score.add_runs(Team::Away, *runs);
+
+            if let Some(runs) = home.get(inning) {
+                line_score.start_half(Team::Home);
+                line_score.add_runs(Team::Home, *runs);
+            }
+        }
+        line_score
+    }
+
+    #[test]
+    fn test_new_line_score() {
+        let line_score = LineScore::new();
+        assert_eq!(line_score.away().runs_by_inning(), &[0]);
+        assert!(line_score.home().runs_by_inning().is_empty());
+        assert_eq!(line_score.innings(), 1);
+        assert!(!line_score.is_final());
+    }
+
+    #[test]
+    fn test_runs_by_inning() {
+        let mut line_score = line_score(&[0, 2, 0], &[1, 0]);
+        line_score.add_runs(Team::Away, 1);
+
+        assert_eq!(line_score.away().runs_by_inning(), &[0, 2, 1]);
+        assert_eq!(line_score.away().inning(InningNumber::Second), Some(2));
+        assert_eq!(line_score.home().inning(InningNumber::Third), None);
+        assert_eq!(line_score.away().runs(), 3);
+        assert_eq!(line_score.home().runs(), 1);
+        assert_eq!(line_score.innings(), 3);
+    }
+
+    #[test]
+    fn test_display_in_progress() {
+        let mut line_score = line_score(&[0, 2], &[1]);
+        line_score.add_hit(Team::Away);
+        line_score.add_hit(Team::Away);
+        line_score.add_hit(Team::Home);
+
+        let rendered = line_score.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "        1  2  3  4  5  6  7  8  9    R  H  E");
+        assert_eq!(lines[1], "Away    0  2                         2  2  0");
+        assert_eq!(lines[2], "Home    1                            1  1  0");
+    }
+
+    #[test]
+    fn test_display_final_with_unplayed_bottom_half() {
+        let line_score = line_score(&[0; 9], &[0, 0, 0, 0, 0, 0, 0, 1]).finish();
+
+        let rendered = line_score.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[1], "Away    0  0  0  0  0  0  0  0  0    0  0  0");
+        assert_eq!(lines[2], "Home    0  0  0  0  0  0  0  1  X    1  0  0");
+    }
+
+    #[test]
+    fn test_display_extra_innings() {
+        let line_score = line_score(&[0; 10], &[0; 10]).finish();
+        let rendered = line_score.to_string();
+        assert!(rendered.lines().next().is_some_and(|header| header.contains(" 10")));
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
+use crate::baseball::substitution::{LineupChange, SubstitutionError};
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BattingPosition {
     #[default]
     First,
@@ -31,6 +34,18 @@ impl Display for BattingPosition {
 }
 
 impl BattingPosition {
+    pub const ALL: [BattingPosition; 9] = [
+        BattingPosition::First,
+        BattingPosition::Second,
+        BattingPosition::Third,
+        BattingPosition::Fourth,
+        BattingPosition::Fifth,
+        BattingPosition::Sixth,
+        BattingPosition::Seventh,
+        BattingPosition::Eighth,
+        BattingPosition::Ninth,
+    ];
+
     pub fn next(self) -> BattingPosition {
         match self {
             BattingPosition::First => BattingPosition::Second,
@@ -45,6 +60,20 @@ impl BattingPosition {
         }
     }
 
+    pub fn previous(self) -> BattingPosition {
+        match self {
+            BattingPosition::First => BattingPosition::Ninth,
+            BattingPosition::Second => BattingPosition::First,
+            BattingPosition::Third => BattingPosition::Second,
+            BattingPosition::Fourth => BattingPosition::Third,
+            BattingPosition::Fifth => BattingPosition::Fourth,
+            BattingPosition::Sixth => BattingPosition::Fifth,
+            BattingPosition::Seventh => BattingPosition::Sixth,
+            BattingPosition::Eighth => BattingPosition::Seventh,
+            BattingPosition::Ninth => BattingPosition::Eighth,
+        }
+    }
+
     pub fn as_number(self) -> u8 {
         match self {
             BattingPosition::First => 1,
@@ -58,9 +87,14 @@ impl BattingPosition {
             BattingPosition::Ninth => 9,
         }
     }
+
+    fn index(self) -> usize {
+        usize::from(self.as_number() - 1)
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlayerPosition {
     Pitcher,
     Catcher,
@@ -108,6 +142,329 @@ impl Display for PlayerPosition {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bats {
+    Left,
+    Right,
+    Switch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Throws {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
+    name: String,
+    number: u8,
+    bats: Bats,
+    throws: Throws,
+}
+
+impl Display for Player {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{} {}", self.number, self.name)
+    }
+}
+
+impl Player {
+    pub fn new(name: impl Into<String>, number: u8, bats: Bats, throws: Throws) -> Self {
+        Player {
+            name: name.into(),
+            number,
+            bats,
+            throws,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub fn bats(&self) -> Bats {
+        self.bats
+    }
+
+    pub fn throws(&self) -> Throws {
+        self.throws
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineupError {
+    UnknownPlayer(u8),
+    DuplicatePlayer(u8),
+    DuplicatePosition(PlayerPosition),
+    MissingPosition(PlayerPosition),
+}
+
+impl Display for LineupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineupError::UnknownPlayer(number) => write!(f, "No player wearing #{} on the roster", number),
+            LineupError::DuplicatePlayer(number) => write!(f, "Player #{} appears in the lineup twice", number),
+            LineupError::DuplicatePosition(position) => write!(f, "{} is filled more than once", position),
+            LineupError::MissingPosition(position) => write!(f, "Nobody is playing {}", position),
+        }
+    }
+}
+
+impl std::error::Error for LineupError {}
+
+/// Every player available to a team, starters and bench alike.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Roster {
+    team: String,
+    players: Vec<Player>,
+}
+
+impl Roster {
+    pub fn new(team: impl Into<String>) -> Self {
+        Roster {
+            team: team.into(),
+            players: Vec::new(),
+        }
+    }
+
+    pub fn with_player(mut self, player: Player) -> Self {
+        self.players.push(player);
+        self
+    }
+
+    pub fn team(&self) -> &str {
+        &self.team
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn player(&self, number: u8) -> Option<&Player> {
+        self.players.iter().find(|player| player.number() == number)
+    }
+
+    /// Builds a batting order from jersey numbers, leadoff hitter first.
+    pub fn lineup(&self, order: [(u8, PlayerPosition); 9]) -> Result<Lineup, LineupError> {
+        let mut slots = Vec::with_capacity(order.len());
+        for (number, position) in order {
+            let player = self.player(number).ok_or(LineupError::UnknownPlayer(number))?;
+            slots.push(LineupSlot::new(player.clone(), position));
+        }
+
+        let slots: [LineupSlot; 9] = slots.try_into().expect("batting order has nine slots");
+        Lineup::new(slots)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineupSlot {
+    player: Player,
+    position: PlayerPosition,
+}
+
+impl LineupSlot {
+    pub fn new(player: Player, position: PlayerPosition) -> Self {
+        LineupSlot { player, position }
+    }
+
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn position(&self) -> PlayerPosition {
+        self.position
+    }
+}
+
+/// A team's batting order, with the defensive position each hitter plays.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lineup {
+    slots: Box<[LineupSlot; 9]>,
+    removed: Vec<Player>,
+}
+
+impl Lineup {
+    /// The eight fielders every lineup needs; the ninth slot is the pitcher or a designated hitter.
+    const FIELDERS: [PlayerPosition; 8] = [
+        PlayerPosition::Catcher,
+        PlayerPosition::FirstBase,
+        PlayerPosition::SecondBase,
+        PlayerPosition::ThirdBase,
+        PlayerPosition::Shortstop,
+        PlayerPosition::LeftField,
+        PlayerPosition::CenterField,
+        PlayerPosition::RightField,
+    ];
+
+    pub fn new(slots: [LineupSlot; 9]) -> Result<Self, LineupError> {
+        for (i, slot) in slots.iter().enumerate() {
+            if slots[..i].iter().any(|other| other.player.number == slot.player.number) {
+                return Err(LineupError::DuplicatePlayer(slot.player.number));
+            }
+        }
+
+        Self::check_positions(&slots)?;
+
+        Ok(Lineup {
+            slots: Box::new(slots),
+            removed: Vec::new(),
+        })
+    }
+
+    fn check_positions(slots: &[LineupSlot; 9]) -> Result<(), LineupError> {
+        for (i, slot) in slots.iter().enumerate() {
+            if slots[..i].iter().any(|other| other.position == slot.position) {
+                return Err(LineupError::DuplicatePosition(slot.position));
+            }
+        }
+
+        match Self::FIELDERS
+            .into_iter()
+            .find(|position| slots.iter().all(|slot| slot.position != *position))
+        {
+            Some(missing) => Err(LineupError::MissingPosition(missing)),
+            None => Ok(()),
+        }
+    }
+
+    /// Puts a new player into a slot. The player being replaced is out of the game for good.
+    pub fn substitute(&self, change: &LineupChange) -> Result<Lineup, SubstitutionError> {
+        let mut lineup = self.clone();
+        lineup.swap_in(change)?;
+        Self::check_positions(&lineup.slots)?;
+        Ok(lineup)
+    }
+
+    /// Makes two substitutions at once, so the lineup only has to be valid after both.
+    pub fn double_switch(&self, first: &LineupChange, second: &LineupChange) -> Result<Lineup, SubstitutionError> {
+        let mut lineup = self.clone();
+        lineup.swap_in(first)?;
+        lineup.swap_in(second)?;
+        Self::check_positions(&lineup.slots)?;
+        Ok(lineup)
+    }
+
+    /// Applies any number of defensive changes at once. A change that names the player already
+    /// batting in that slot just moves them to a new position.
+    pub fn realign(&self, changes: &[LineupChange]) -> Result<Lineup, SubstitutionError> {
+        let mut lineup = self.clone();
+        for change in changes {
+            let slot = &mut lineup.slots[change.batting_position().index()];
+            if slot.player.number == change.player().number {
+                slot.position = change.position();
+            } else {
+                lineup.swap_in(change)?;
+            }
+        }
+        Self::check_positions(&lineup.slots)?;
+        Ok(lineup)
+    }
+
+    /// Replaces a pitcher who does not bat, as with a designated hitter. The outgoing pitcher is out
+    /// of the game for good, the same as a player substituted out of the batting order.
+    pub fn replace_pitcher(&self, outgoing: Option<&Player>, incoming: &Player) -> Result<Lineup, SubstitutionError> {
+        let mut lineup = self.clone();
+        lineup.check_incoming(incoming)?;
+        if outgoing.is_some_and(|outgoing| outgoing.number == incoming.number) {
+            return Err(SubstitutionError::AlreadyInLineup(incoming.number));
+        }
+        lineup.removed.extend(outgoing.cloned());
+        Ok(lineup)
+    }
+
+    fn check_incoming(&self, incoming: &Player) -> Result<(), SubstitutionError> {
+        if self.removed.iter().any(|player| player.number == incoming.number) {
+            return Err(SubstitutionError::Reentry(incoming.number));
+        }
+        if self.slots.iter().any(|slot| slot.player.number == incoming.number) {
+            return Err(SubstitutionError::AlreadyInLineup(incoming.number));
+        }
+        Ok(())
+    }
+
+    fn swap_in(&mut self, change: &LineupChange) -> Result<(), SubstitutionError> {
+        let incoming = change.player();
+        self.check_incoming(incoming)?;
+
+        let slot = &mut self.slots[change.batting_position().index()];
+        let outgoing = std::mem::replace(slot, LineupSlot::new(incoming.clone(), change.position()));
+        self.removed.push(outgoing.player);
+        Ok(())
+    }
+
+    /// Players who have been substituted out and may not re-enter (Rule 5.10).
+    pub fn removed(&self) -> &[Player] {
+        &self.removed
+    }
+
+    pub fn slot(&self, batting_position: BattingPosition) -> &LineupSlot {
+        &self.slots[batting_position.index()]
+    }
+
+    pub fn player(&self, batting_position: BattingPosition) -> &Player {
+        self.slot(batting_position).player()
+    }
+
+    pub fn position(&self, batting_position: BattingPosition) -> PlayerPosition {
+        self.slot(batting_position).position()
+    }
+
+    /// The pitcher, unless a designated hitter bats in their place.
+    pub fn pitcher(&self) -> Option<&Player> {
+        self.slots
+            .iter()
+            .find(|slot| slot.position == PlayerPosition::Pitcher)
+            .map(|slot| &slot.player)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (BattingPosition, &LineupSlot)> {
+        BattingPosition::ALL.into_iter().zip(self.slots.iter())
+    }
+}
+
+impl Default for Lineup {
+    /// A placeholder order of anonymous right-handed hitters, for games started without rosters.
+    fn default() -> Self {
+        let positions = [
+            PlayerPosition::Shortstop,
+            PlayerPosition::SecondBase,
+            PlayerPosition::CenterField,
+            PlayerPosition::FirstBase,
+            PlayerPosition::RightField,
+            PlayerPosition::ThirdBase,
+            PlayerPosition::LeftField,
+            PlayerPosition::Catcher,
+            PlayerPosition::Pitcher,
+        ];
+
+        let slots = BattingPosition::ALL.map(|batting_position| {
+            let number = batting_position.as_number();
+            LineupSlot::new(
+                Player::new(format!("Batter {}", number), number, Bats::Right, Throws::Right),
+                positions[batting_position.index()],
+            )
+        });
+
+        Lineup {
+            slots: Box::new(slots),
+            removed: Vec::new(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use tracing::info;
@@ -149,4 +506,145 @@ mod test {
 
         info!("No more .unwrap() calls needed! 🎉");
     }
+
+    fn test_roster() -> Roster {
+        [
+            ("Ortiz", 1, Bats::Left, Throws::Right),
+            ("Baker", 2, Bats::Right, Throws::Right),
+            ("Chen", 3, Bats::Switch, Throws::Right),
+            ("Diaz", 4, Bats::Right, Throws::Right),
+            ("Evans", 5, Bats::Left, Throws::Left),
+            ("Fox", 6, Bats::Right, Throws::Right),
+            ("Gray", 7, Bats::Right, Throws::Right),
+            ("Hill", 8, Bats::Right, Throws::Right),
+            ("Ito", 9, Bats::Left, Throws::Left),
+            ("Jones", 10, Bats::Right, Throws::Right),
+        ]
+        .into_iter()
+        .fold(Roster::new("Testers"), |roster, (name, number, bats, throws)| {
+            roster.with_player(Player::new(name, number, bats, throws))
+        })
+    }
+
+    fn test_order() -> [(u8, PlayerPosition); 9] {
+        [
+            (1, PlayerPosition::CenterField),
+            (2, PlayerPosition::Shortstop),
+            (3, PlayerPosition::SecondBase),
+            (4, PlayerPosition::FirstBase),
+            (5, PlayerPosition::RightField),
+            (6, PlayerPosition::ThirdBase),
+            (7, PlayerPosition::LeftField),
+            (8, PlayerPosition::Catcher),
+            (9, PlayerPosition::Pitcher),
+        ]
+    }
+
+    #[test]
+    fn test_roster_lookup() {
+        let roster = test_roster();
+
+        assert_eq!(roster.team(), "Testers");
+        assert_eq!(roster.players().len(), 10);
+        assert_eq!(roster.player(3).map(Player::name), Some("Chen"));
+        assert_eq!(roster.player(3).map(Player::bats), Some(Bats::Switch));
+        assert!(roster.player(42).is_none());
+    }
+
+    #[test]
+    fn test_lineup_from_roster() {
+        let lineup = test_roster().lineup(test_order()).expect("lineup should be valid");
+
+        assert_eq!(lineup.player(BattingPosition::First).name(), "Ortiz");
+        assert_eq!(lineup.position(BattingPosition::First), PlayerPosition::CenterField);
+        assert_eq!(lineup.player(BattingPosition::Ninth).throws(), Throws::Left);
+        assert_eq!(lineup.player(BattingPosition::Fourth).to_string(), "#4 Diaz");
+
+        let numbers: Vec<u8> = lineup.iter().map(|(_, slot)| slot.player().number()).collect();
+        assert_eq!(numbers, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_lineup_with_designated_hitter() {
+        let mut order = test_order();
+        order[8] = (10, PlayerPosition::DesignatedHitter);
+
+        let lineup = test_roster().lineup(order).expect("lineup should be valid");
+        assert_eq!(
+            lineup.position(BattingPosition::Ninth),
+            PlayerPosition::DesignatedHitter
+        );
+    }
+
+    #[test]
+    fn test_invalid_lineups() {
+        let roster = test_roster();
+
+        let mut order = test_order();
+        order[0] = (42, PlayerPosition::CenterField);
+        assert_eq!(roster.lineup(order), Err(LineupError::UnknownPlayer(42)));
+
+        let mut order = test_order();
+        order[1] = (1, PlayerPosition::Shortstop);
+        assert_eq!(roster.lineup(order), Err(LineupError::DuplicatePlayer(1)));
+
+        let mut order = test_order();
+        order[8] = (9, PlayerPosition::Catcher);
+        assert_eq!(
+            roster.lineup(order),
+            Err(LineupError::DuplicatePosition(PlayerPosition::Catcher))
+        );
+
+        let mut order = test_order();
+        order[0] = (1, PlayerPosition::DesignatedHitter);
+        assert_eq!(
+            roster.lineup(order),
+            Err(LineupError::MissingPosition(PlayerPosition::CenterField))
+        );
+    }
+
+    #[test]
+    fn test_realign_swaps_positions() {
+        let lineup = test_roster().lineup(test_order()).expect("lineup should be valid");
+        let roster = test_roster();
+        let player = |number| roster.player(number).cloned().expect("player should be on the roster");
+
+        let realigned = lineup
+            .realign(&[
+                LineupChange::new(BattingPosition::Second, player(2), PlayerPosition::SecondBase),
+                LineupChange::new(BattingPosition::Third, player(3), PlayerPosition::Shortstop),
+            ])
+            .expect("swapping middle infielders should be valid");
+        assert_eq!(realigned.position(BattingPosition::Second), PlayerPosition::SecondBase);
+        assert_eq!(realigned.position(BattingPosition::Third), PlayerPosition::Shortstop);
+        assert!(realigned.removed().is_empty());
+
+        let realigned = lineup
+            .realign(&[
+                LineupChange::new(BattingPosition::Seventh, player(7), PlayerPosition::ThirdBase),
+                LineupChange::new(BattingPosition::Sixth, player(10), PlayerPosition::LeftField),
+            ])
+            .expect("a replacement can take the vacated position");
+        assert_eq!(realigned.player(BattingPosition::Sixth).name(), "Jones");
+        assert_eq!(realigned.removed(), &[player(6)]);
+
+        assert_eq!(
+            lineup.realign(&[LineupChange::new(
+                BattingPosition::Seventh,
+                player(7),
+                PlayerPosition::ThirdBase
+            )]),
+            Err(SubstitutionError::InvalidLineup(LineupError::DuplicatePosition(
+                PlayerPosition::ThirdBase
+            )))
+        );
+    }
+
+    #[test]
+    fn test_default_lineup_is_valid() {
+        let lineup = Lineup::default();
+        let slots = lineup.iter().map(|(_, slot)| slot.clone()).collect::<Vec<_>>();
+
+        assert_eq!(Lineup::new(slots.try_into().expect("nine slots")), Ok(lineup));
+    }
 }
@@ -0,0 +1,436 @@
+use crate::baseball::{
+    lineup::Player,
+    plate_appearance::{Balls, Count, Strikes},
+    simulation::{PitchDistribution, PitchModel, SimulatedPitch},
+};
+
+/// Pitches thrown per plate appearance in a typical major league game, used to spread the
+/// hit-by-pitch rate over single pitches.
+const PITCHES_PER_PLATE_APPEARANCE: f64 = 3.9;
+
+/// How often a plate appearance ends each way, per plate appearance, for a batter, a pitcher or the
+/// league as a whole.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateStats {
+    strikeout: f64,
+    walk: f64,
+    hit_by_pitch: f64,
+    single: f64,
+    double: f64,
+    triple: f64,
+    home_run: f64,
+    /// The share of outs on balls in play hit on the ground rather than in the air.
+    ground_ball: f64,
+}
+
+impl RateStats {
+    /// A recent major league season's rates.
+    pub fn league_average() -> Self {
+        RateStats {
+            strikeout: 0.227,
+            walk: 0.086,
+            hit_by_pitch: 0.011,
+            single: 0.142,
+            double: 0.045,
+            triple: 0.004,
+            home_run: 0.031,
+            ground_ball: 0.45,
+        }
+    }
+
+    pub fn with_strikeout_rate(mut self, rate: f64) -> Self {
+        self.strikeout = rate;
+        self
+    }
+
+    pub fn with_walk_rate(mut self, rate: f64) -> Self {
+        self.walk = rate;
+        self
+    }
+
+    pub fn with_hit_by_pitch_rate(mut self, rate: f64) -> Self {
+        self.hit_by_pitch = rate;
+        self
+    }
+
+    pub fn with_single_rate(mut self, rate: f64) -> Self {
+        self.single = rate;
+        self
+    }
+
+    pub fn with_double_rate(mut self, rate: f64) -> Self {
+        self.double = rate;
+        self
+    }
+
+    pub fn with_triple_rate(mut self, rate: f64) -> Self {
+        self.triple = rate;
+        self
+    }
+
+    pub fn with_home_run_rate(mut self, rate: f64) -> Self {
+        self.home_run = rate;
+        self
+    }
+
+    /// Sets the share of outs in play that are ground balls; the rest are fly balls.
+    pub fn with_ground_ball_rate(mut self, rate: f64) -> Self {
+        self.ground_ball = rate;
+        self
+    }
+
+    pub fn strikeout_rate(&self) -> f64 {
+        self.strikeout
+    }
+
+    pub fn walk_rate(&self) -> f64 {
+        self.walk
+    }
+
+    pub fn hit_by_pitch_rate(&self) -> f64 {
+        self.hit_by_pitch
+    }
+
+    pub fn single_rate(&self) -> f64 {
+        self.single
+    }
+
+    pub fn double_rate(&self) -> f64 {
+        self.double
+    }
+
+    pub fn triple_rate(&self) -> f64 {
+        self.triple
+    }
+
+    pub fn home_run_rate(&self) -> f64 {
+        self.home_run
+    }
+
+    pub fn ground_ball_rate(&self) -> f64 {
+        self.ground_ball
+    }
+
+    /// Plate appearances that end with the ball in play, home runs included.
+    pub fn in_play_rate(&self) -> f64 {
+        (1.0 - self.strikeout - self.walk - self.hit_by_pitch).max(0.0)
+    }
+
+    /// Plate appearances that end with the batter out on a ball in play.
+    pub fn in_play_out_rate(&self) -> f64 {
+        (self.in_play_rate() - self.single - self.double - self.triple - self.home_run).max(0.0)
+    }
+}
+
+impl Default for RateStats {
+    fn default() -> Self {
+        Self::league_average()
+    }
+}
+
+/// Decides how likely each pitch is from the batter's and pitcher's rate stats and the count.
+/// Implement it to plug a different matchup model into `MatchupPitchModel`.
+pub trait MatchupModel: Sync {
+    fn distribution(&self, batter: &RateStats, pitcher: &RateStats, count: Count) -> PitchDistribution;
+}
+
+/// Combines batter and pitcher with the log5 (odds ratio) method: the matchup's odds of each
+/// result are the batter's odds times the pitcher's odds over the league's. The plate appearance
+/// is then played out pitch by pitch from how often pitches are balls, strikes, fouls and balls in
+/// play at each count in the league, with balls and strikes scaled to the matchup's walk and
+/// strikeout odds.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Log5Model {
+    league: RateStats,
+}
+
+impl Log5Model {
+    /// Compares players against `league`, the rates of the league they play in.
+    pub fn new(league: RateStats) -> Self {
+        Log5Model { league }
+    }
+
+    pub fn league(&self) -> &RateStats {
+        &self.league
+    }
+
+    /// The expected rates when `batter` faces `pitcher`. The hit rates are scaled back if they
+    /// leave no room for outs in play.
+    pub fn plate_appearance(&self, batter: &RateStats, pitcher: &RateStats) -> RateStats {
+        let league = &self.league;
+        let matchup = RateStats {
+            strikeout: log5(batter.strikeout, pitcher.strikeout, league.strikeout),
+            walk: log5(batter.walk, pitcher.walk, league.walk),
+            hit_by_pitch: log5(batter.hit_by_pitch, pitcher.hit_by_pitch, league.hit_by_pitch),
+            single: log5(batter.single, pitcher.single, league.single),
+            double: log5(batter.double, pitcher.double, league.double),
+            triple: log5(batter.triple, pitcher.triple, league.triple),
+            home_run: log5(batter.home_run, pitcher.home_run, league.home_run),
+            ground_ball: log5(batter.ground_ball, pitcher.ground_ball, league.ground_ball),
+        };
+
+        let total = matchup.strikeout
+            + matchup.walk
+            + matchup.hit_by_pitch
+            + matchup.single
+            + matchup.double
+            + matchup.triple
+            + matchup.home_run;
+        if total <= 1.0 {
+            return matchup;
+        }
+        RateStats {
+            strikeout: matchup.strikeout / total,
+            walk: matchup.walk / total,
+            hit_by_pitch: matchup.hit_by_pitch / total,
+            single: matchup.single / total,
+            double: matchup.double / total,
+            triple: matchup.triple / total,
+            home_run: matchup.home_run / total,
+            ground_ball: matchup.ground_ball,
+        }
+    }
+}
+
+impl MatchupModel for Log5Model {
+    fn distribution(&self, batter: &RateStats, pitcher: &RateStats, count: Count) -> PitchDistribution {
+        let matchup = self.plate_appearance(batter, pitcher);
+        let league = &self.league;
+        let (ball, strike, foul, in_play) = league_pitches(count);
+
+        let ball = ball * odds_ratio(matchup.walk, league.walk);
+        let strike = strike * odds_ratio(matchup.strikeout, league.strikeout);
+        let in_play_rate = matchup.in_play_rate();
+        let in_play = if league.in_play_rate() > 0.0 {
+            in_play * in_play_rate / league.in_play_rate()
+        } else {
+            0.0
+        };
+        // What share of balls in play end each way
+        let share = |rate: f64| {
+            if in_play_rate > 0.0 {
+                in_play * rate / in_play_rate
+            } else {
+                0.0
+            }
+        };
+        let outs = share(matchup.in_play_out_rate());
+
+        PitchDistribution::new()
+            .with_weight(SimulatedPitch::Ball, ball)
+            .with_weight(SimulatedPitch::Strike, strike)
+            .with_weight(SimulatedPitch::Foul, foul)
+            .with_weight(
+                SimulatedPitch::HitByPitch,
+                matchup.hit_by_pitch / PITCHES_PER_PLATE_APPEARANCE,
+            )
+            .with_weight(SimulatedPitch::Groundout, outs * matchup.ground_ball)
+            .with_weight(SimulatedPitch::Flyout, outs * (1.0 - matchup.ground_ball))
+            .with_weight(SimulatedPitch::Single, share(matchup.single))
+            .with_weight(SimulatedPitch::Double, share(matchup.double))
+            .with_weight(SimulatedPitch::Triple, share(matchup.triple))
+            .with_weight(SimulatedPitch::HomeRun, share(matchup.home_run))
+    }
+}
+
+/// A `PitchModel` that looks up each player's rate stats and hands them to a `MatchupModel`.
+/// Players without stats of their own hit and pitch like the league.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchupPitchModel<M = Log5Model> {
+    model: M,
+    default: RateStats,
+    batters: Vec<(Player, RateStats)>,
+    pitchers: Vec<(Player, RateStats)>,
+}
+
+impl MatchupPitchModel<Log5Model> {
+    /// Compares players with the log5 method against `league`.
+    pub fn log5(league: RateStats) -> Self {
+        Self::new(Log5Model::new(league), league)
+    }
+}
+
+impl<M: MatchupModel> MatchupPitchModel<M> {
+    /// Uses `model`, with `default` standing in for players without stats.
+    pub fn new(model: M, default: RateStats) -> Self {
+        MatchupPitchModel {
+            model,
+            default,
+            batters: Vec::new(),
+            pitchers: Vec::new(),
+        }
+    }
+
+    pub fn with_batter(mut self, batter: Player, stats: RateStats) -> Self {
+        self.batters.retain(|(player, _)| *player != batter);
+        self.batters.push((batter, stats));
+        self
+    }
+
+    pub fn with_pitcher(mut self, pitcher: Player, stats: RateStats) -> Self {
+        self.pitchers.retain(|(player, _)| *player != pitcher);
+        self.pitchers.push((pitcher, stats));
+        self
+    }
+
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    fn stats<'a>(&'a self, players: &'a [(Player, RateStats)], player: Option<&Player>) -> &'a RateStats {
+        player
+            .and_then(|player| players.iter().find(|(candidate, _)| candidate == player))
+            .map_or(&self.default, |(_, stats)| stats)
+    }
+}
+
+impl<M: MatchupModel> PitchModel for MatchupPitchModel<M> {
+    fn distribution(&self, batter: &Player, pitcher: Option<&Player>, count: Count) -> PitchDistribution {
+        let batting = self.stats(&self.batters, Some(batter));
+        let pitching = self.stats(&self.pitchers, pitcher);
+        self.model.distribution(batting, pitching, count)
+    }
+}
+
+/// The log5 estimate of how often a result happens when a batter who gets it at `batter` faces a
+/// pitcher who allows it at `pitcher`, in a league where it happens at `league`.
+pub fn log5(batter: f64, pitcher: f64, league: f64) -> f64 {
+    if batter <= 0.0 || pitcher <= 0.0 {
+        return 0.0;
+    }
+    if batter >= 1.0 || pitcher >= 1.0 {
+        return 1.0;
+    }
+    if league <= 0.0 || league >= 1.0 {
+        return (batter + pitcher) / 2.0;
+    }
+    let odds = odds(batter) * odds(pitcher) / odds(league);
+    odds / (1.0 + odds)
+}
+
+fn odds(rate: f64) -> f64 {
+    rate / (1.0 - rate)
+}
+
+/// How much likelier a result is at `rate` than at `league`, measured in odds.
+fn odds_ratio(rate: f64, league: f64) -> f64 {
+    if league <= 0.0 || league >= 1.0 || rate >= 1.0 {
+        return 1.0;
+    }
+    odds(rate) / odds(league)
+}
+
+/// How often a major league pitch at `count` is a ball, a strike, a foul and put in play. Hitters
+/// take more with three balls and protect the plate with two strikes.
+fn league_pitches(count: Count) -> (f64, f64, f64, f64) {
+    match (count.balls(), count.strikes()) {
+        (Balls::Zero, Strikes::Zero) => (0.40, 0.31, 0.16, 0.12),
+        (Balls::One, Strikes::Zero) => (0.38, 0.27, 0.19, 0.15),
+        (Balls::Two, Strikes::Zero) => (0.36, 0.25, 0.21, 0.17),
+        (Balls::Three, Strikes::Zero) => (0.45, 0.38, 0.10, 0.06),
+        (Balls::Zero, Strikes::One) => (0.40, 0.22, 0.20, 0.17),
+        (Balls::One, Strikes::One) => (0.38, 0.20, 0.22, 0.19),
+        (Balls::Two, Strikes::One) => (0.35, 0.19, 0.24, 0.21),
+        (Balls::Three, Strikes::One) => (0.37, 0.19, 0.23, 0.20),
+        (Balls::Zero, Strikes::Two) => (0.48, 0.16, 0.20, 0.15),
+        (Balls::One, Strikes::Two) => (0.42, 0.15, 0.23, 0.19),
+        (Balls::Two, Strikes::Two) => (0.36, 0.15, 0.27, 0.21),
+        (Balls::Three, Strikes::Two) => (0.30, 0.14, 0.31, 0.24),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseball::{
+        game::{Game, Team},
+        lineup::{Bats, Throws},
+        simulation::Simulator,
+    };
+
+    #[test]
+    fn test_log5() {
+        // A .300 hitter against a pitcher who allows .250 in a .260 league
+        assert!((log5(0.300, 0.250, 0.260) - 0.2891).abs() < 1e-4);
+        // Against an average pitcher the batter keeps his own rate
+        assert!((log5(0.300, 0.260, 0.260) - 0.300).abs() < 1e-9);
+        assert_eq!(log5(0.0, 0.250, 0.260), 0.0);
+        assert_eq!(log5(1.0, 0.250, 0.260), 1.0);
+    }
+
+    #[test]
+    fn test_plate_appearance_rates() {
+        let league = RateStats::league_average();
+        let model = Log5Model::new(league);
+        // Average players against each other play like the league
+        let average = model.plate_appearance(&league, &league);
+        assert!((average.strikeout_rate() - league.strikeout_rate()).abs() < 1e-9);
+        assert!((average.home_run_rate() - league.home_run_rate()).abs() < 1e-9);
+        assert!((average.in_play_out_rate() - league.in_play_out_rate()).abs() < 1e-9);
+
+        let strikeout_pitcher = league.with_strikeout_rate(0.35);
+        let contact_hitter = league.with_strikeout_rate(0.10);
+        let matchup = model.plate_appearance(&contact_hitter, &strikeout_pitcher);
+        assert!(matchup.strikeout_rate() > 0.10 && matchup.strikeout_rate() < 0.35);
+        assert!(matchup.in_play_rate() > league.in_play_rate());
+
+        // Rates that cannot all happen are scaled back to fit one plate appearance
+        let impossible = league.with_single_rate(0.9).with_home_run_rate(0.9);
+        let matchup = model.plate_appearance(&impossible, &impossible);
+        assert!(matchup.in_play_out_rate() == 0.0);
+        assert!(matchup.single_rate() + matchup.home_run_rate() <= 1.0);
+    }
+
+    #[test]
+    fn test_pitches_depend_on_the_count() {
+        let league = RateStats::league_average();
+        let model = Log5Model::new(league);
+        let at = |balls, strikes| model.distribution(&league, &league, Count::new(balls, strikes));
+
+        let three_oh = at(Balls::Three, Strikes::Zero);
+        let full = at(Balls::Three, Strikes::Two);
+        assert!(three_oh.probability(SimulatedPitch::Ball) > full.probability(SimulatedPitch::Ball));
+        assert!(full.probability(SimulatedPitch::Foul) > three_oh.probability(SimulatedPitch::Foul));
+
+        // A strikeout pitcher gets more strikes at any count
+        let ace = league.with_strikeout_rate(0.35);
+        let count = Count::new(Balls::One, Strikes::Two);
+        let against_ace = model.distribution(&league, &ace, count);
+        let against_league = model.distribution(&league, &league, count);
+        assert!(against_ace.probability(SimulatedPitch::Strike) > against_league.probability(SimulatedPitch::Strike));
+    }
+
+    #[test]
+    fn test_players_use_their_own_stats() {
+        let slugger = Player::new("Slugger", 4, Bats::Left, Throws::Right);
+        let league = RateStats::league_average();
+        let model = MatchupPitchModel::log5(league).with_batter(slugger.clone(), league.with_home_run_rate(0.10));
+
+        let count = Count::default();
+        let other = Player::new("Other", 5, Bats::Right, Throws::Right);
+        assert!(
+            model
+                .distribution(&slugger, None, count)
+                .probability(SimulatedPitch::HomeRun)
+                > model
+                    .distribution(&other, None, count)
+                    .probability(SimulatedPitch::HomeRun)
+        );
+    }
+
+    #[test]
+    fn test_simulate_with_matchups() {
+        let report = Simulator::new(Game::new(), MatchupPitchModel::log5(RateStats::league_average()))
+            .with_games(200)
+            .with_seed(11)
+            .run();
+        assert_eq!(report.games(), 200);
+        for team in [Team::Away, Team::Home] {
+            let runs = report.average_runs(team);
+            assert!(runs > 2.0 && runs < 8.0, "{} runs per game", runs);
+        }
+    }
+}
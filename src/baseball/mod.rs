@@ -1,14 +1,37 @@
 mod baserunners;
+mod box_score;
 mod core;
+mod earned_runs;
+mod events;
 mod field;
 mod game;
 mod inning;
+mod line_score;
 mod lineup;
+mod matchup;
+mod movement;
+mod pitching;
 mod plate_appearance;
+mod retrosheet;
+mod rules;
+mod running;
+mod simulation;
+mod substitution;
 
 pub use core::Runs;
 
-pub use baserunners::{Base, BaseOutcome, BaserunnerState, HomeOutcome, PlayOutcome};
-pub use game::{Game, GameResult};
-pub use lineup::{BattingPosition, PlayerPosition};
-pub use plate_appearance::PitchOutcome;
+pub use baserunners::{Base, BaseOutcome, BaserunnerState, HomeOutcome, PlayError, PlayKind, PlayOutcome};
+pub use box_score::{BattingLine, BoxScore, InningsPitched, PitchingLine, TeamBoxScore};
+pub use events::{GameEvent, GameLog, PitchEvent, PlacedRunner};
+pub use game::{Game, GameResult, RejectedPlay, Team};
+pub use line_score::{LineScore, TeamLine};
+pub use lineup::{Bats, BattingPosition, Lineup, LineupError, LineupSlot, Player, PlayerPosition, Roster, Throws};
+pub use matchup::{Log5Model, MatchupModel, MatchupPitchModel, RateStats, log5};
+pub use movement::{Advancement, Destination, RunnerMovement};
+pub use pitching::{Decision, LeadChange, PitchingDecisions, PitchingStint};
+pub use plate_appearance::{Balls, Count, PitchOutcome, Strikes};
+pub use retrosheet::{Appearance, Play, Record, RetrosheetError, RetrosheetGame};
+pub use rules::{GameRules, MercyRule};
+pub use running::{RunningKind, RunningPlay};
+pub use simulation::{PitchDistribution, PitchModel, PlayerPitchModel, SimulatedPitch, SimulationReport, Simulator};
+pub use substitution::{LineupChange, Substitution, SubstitutionError, SubstitutionKind};
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use crate::baseball::{baserunners::Base, lineup::BattingPosition};
+
+/// What let a runner move up on a play.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Advancement {
+    /// The batted ball itself, including being forced along by the batter-runner. Also used for
+    /// runners put out on the play.
+    BattedBall,
+    /// A misplay by a fielder (Rule 9.12).
+    Error,
+    /// A throw to another base that let the runner take an extra one.
+    Throw,
+    StolenBase,
+    /// A pitch the catcher could not hold with ordinary effort (Rule 9.13(a)).
+    WildPitch,
+    /// A pitch the catcher should have held (Rule 9.13(b)).
+    PassedBall,
+    /// Awarded a base because the pitcher balked (Rule 6.02(a)).
+    Balk,
+}
+
+/// Where a runner's part in a play ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Destination {
+    /// Safe at a base. Safe at home means the runner scored.
+    Safe(Base),
+    /// Forced out at a base, including the batter-runner thrown out at first (Rule 5.09(b)(6)).
+    ForceOut(Base),
+    /// Put out any other way: tagged, doubled off, or a batter whose fly ball is caught, who is out
+    /// at home plate.
+    Out(Base),
+}
+
+impl Destination {
+    pub fn base(self) -> Base {
+        match self {
+            Destination::Safe(base) | Destination::ForceOut(base) | Destination::Out(base) => base,
+        }
+    }
+
+    pub fn is_out(self) -> bool {
+        !matches!(self, Destination::Safe(_))
+    }
+}
+
+/// One runner's part in a play: where they started, where they ended up and what let them move.
+/// The batter starts from home plate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunnerMovement {
+    runner: BattingPosition,
+    from: Base,
+    to: Destination,
+    advancement: Advancement,
+}
+
+impl Display for RunnerMovement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to {
+            Destination::Safe(Base::Home) => write!(f, "{} scores from {}", self.runner, self.from),
+            Destination::Safe(base) => write!(f, "{} from {} to {}", self.runner, self.from, base),
+            Destination::ForceOut(base) => write!(f, "{} forced out at {}", self.runner, base),
+            Destination::Out(base) => write!(f, "{} out at {}", self.runner, base),
+        }
+    }
+}
+
+impl RunnerMovement {
+    pub fn new(runner: BattingPosition, from: Base, to: Destination) -> Self {
+        RunnerMovement {
+            runner,
+            from,
+            to,
+            advancement: Advancement::BattedBall,
+        }
+    }
+
+    pub fn with_advancement(mut self, advancement: Advancement) -> Self {
+        self.advancement = advancement;
+        self
+    }
+
+    pub fn runner(&self) -> BattingPosition {
+        self.runner
+    }
+
+    pub fn from(&self) -> Base {
+        self.from
+    }
+
+    pub fn to(&self) -> Destination {
+        self.to
+    }
+
+    pub fn advancement(&self) -> Advancement {
+        self.advancement
+    }
+
+    pub fn is_batter(&self) -> bool {
+        self.from == Base::Home
+    }
+
+    pub fn scored(&self) -> bool {
+        self.to == Destination::Safe(Base::Home)
+    }
+
+    pub fn is_out(&self) -> bool {
+        self.to.is_out()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runner_movement() {
+        let scored = RunnerMovement::new(BattingPosition::Third, Base::Second, Destination::Safe(Base::Home))
+            .with_advancement(Advancement::Error);
+        assert!(scored.scored());
+        assert!(!scored.is_out());
+        assert!(!scored.is_batter());
+        assert_eq!(scored.advancement(), Advancement::Error);
+        assert_eq!(scored.to_string(), "Third scores from second");
+
+        let batter_out = RunnerMovement::new(BattingPosition::Fourth, Base::Home, Destination::ForceOut(Base::First));
+        assert!(batter_out.is_batter());
+        assert!(batter_out.is_out());
+        assert_eq!(batter_out.to().base(), Base::First);
+        assert_eq!(batter_out.advancement(), Advancement::BattedBall);
+    }
+}
@@ -0,0 +1,482 @@
+use std::fmt::Display;
+
+use crate::{
+    Runs,
+    baseball::{
+        events::PitchEvent,
+        game::{GameWinner, InningNumber, Team},
+        inning::{InningHalf, Outs},
+        lineup::Player,
+        plate_appearance::PitchOutcome,
+    },
+};
+
+/// A pitcher's time on the mound, from entering the game until being replaced.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PitchingStint {
+    pitcher: Player,
+    inning: InningNumber,
+    half: InningHalf,
+    pitches: u16,
+    batters_faced: u8,
+    outs: u16,
+    finished_half_inning: bool,
+    lead_when_entered: Option<Runs>,
+    tying_run_due: bool,
+    lead: Runs,
+}
+
+impl PitchingStint {
+    pub fn new(pitcher: Player, inning: InningNumber, half: InningHalf) -> Self {
+        PitchingStint {
+            pitcher,
+            inning,
+            half,
+            pitches: 0,
+            batters_faced: 0,
+            outs: 0,
+            finished_half_inning: false,
+            lead_when_entered: None,
+            tying_run_due: false,
+            lead: 0,
+        }
+    }
+
+    pub fn pitcher(&self) -> &Player {
+        &self.pitcher
+    }
+
+    pub fn inning(&self) -> InningNumber {
+        self.inning
+    }
+
+    pub fn half(&self) -> InningHalf {
+        self.half
+    }
+
+    /// Running plays between pitches do not count.
+    pub fn pitches(&self) -> u16 {
+        self.pitches
+    }
+
+    /// Batters whose plate appearance ended against this pitcher.
+    pub fn batters_faced(&self) -> u8 {
+        self.batters_faced
+    }
+
+    pub fn outs(&self) -> u16 {
+        self.outs
+    }
+
+    /// Whether the pitcher has faced three batters or pitched until the side was retired (Rule 5.10(g)).
+    pub fn can_be_replaced(&self) -> bool {
+        self.batters_faced >= 3 || self.finished_half_inning
+    }
+
+    /// `None` until the pitcher has thrown a pitch.
+    pub fn lead_when_entered(&self) -> Option<Runs> {
+        self.lead_when_entered
+    }
+
+    pub fn lead(&self) -> Runs {
+        self.lead
+    }
+
+    /// Whether the pitcher entered with a lead of three runs or fewer, or with the tying run on
+    /// base, at bat or on deck (Rule 9.19(d)).
+    pub fn entered_in_save_situation(&self) -> bool {
+        self.lead_when_entered
+            .is_some_and(|lead| lead > 0 && (lead <= 3 || self.tying_run_due))
+    }
+
+    /// An inning with a lead of three or fewer, any outs with the tying run due, or three innings.
+    fn earned_save(&self) -> bool {
+        let Some(lead) = self.lead_when_entered.filter(|lead| *lead > 0) else {
+            return false;
+        };
+        self.outs >= 1 && ((lead <= 3 && self.outs >= 3) || self.tying_run_due || self.outs >= 9)
+    }
+
+    /// Records a pitch thrown with the pitcher's team ahead by `lead` runs, zero if they were not.
+    pub(crate) fn record(&mut self, pitch: &PitchEvent, lead: Runs) {
+        if self.lead_when_entered.is_none() {
+            self.lead_when_entered = Some(lead);
+            self.tying_run_due = lead > 0 && lead <= pitch.baserunners_before().runner_count() + 2;
+        }
+        self.lead = lead.saturating_sub(pitch.runs_scored());
+        if !matches!(pitch.pitch(), PitchOutcome::Running(_)) {
+            self.pitches += 1;
+        }
+        if pitch.ends_plate_appearance() {
+            self.batters_faced = self.batters_faced.saturating_add(1);
+        }
+        self.outs += u16::from(pitch.outs_after().as_number() - pitch.outs_before().as_number());
+        if pitch.outs_after() == Outs::Three {
+            self.finished_half_inning = true;
+        }
+    }
+}
+
+/// A team going ahead, with the pitchers in line for the win and the loss should the lead hold
+/// for the rest of the game (Rule 9.17).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeadChange {
+    team: Team,
+    inning: InningNumber,
+    half: InningHalf,
+    winning_pitcher: Option<Player>,
+    losing_pitcher: Option<Player>,
+}
+
+impl LeadChange {
+    pub fn new(
+        team: Team,
+        inning: InningNumber,
+        half: InningHalf,
+        winning_pitcher: Option<Player>,
+        losing_pitcher: Option<Player>,
+    ) -> Self {
+        LeadChange {
+            team,
+            inning,
+            half,
+            winning_pitcher,
+            losing_pitcher,
+        }
+    }
+
+    pub fn team(&self) -> Team {
+        self.team
+    }
+
+    pub fn inning(&self) -> InningNumber {
+        self.inning
+    }
+
+    pub fn half(&self) -> InningHalf {
+        self.half
+    }
+
+    /// The leading team's pitcher when they went ahead.
+    pub fn winning_pitcher(&self) -> Option<&Player> {
+        self.winning_pitcher.as_ref()
+    }
+
+    /// The pitcher who let the go-ahead run reach base.
+    pub fn losing_pitcher(&self) -> Option<&Player> {
+        self.losing_pitcher.as_ref()
+    }
+}
+
+/// An official scorer's decision credited to a pitcher.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Decision {
+    Win,
+    Loss,
+    Save,
+    /// Not an official statistic: a reliever who entered in a save situation, got an out and left
+    /// with the lead intact.
+    Hold,
+}
+
+impl Display for Decision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Decision::Win => write!(f, "W"),
+            Decision::Loss => write!(f, "L"),
+            Decision::Save => write!(f, "S"),
+            Decision::Hold => write!(f, "H"),
+        }
+    }
+}
+
+/// The pitchers credited with the win, loss and save in a finished game, and any holds.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PitchingDecisions {
+    winner: Option<Team>,
+    winning_pitcher: Option<Player>,
+    losing_pitcher: Option<Player>,
+    save: Option<Player>,
+    holds: Vec<(Team, Player)>,
+}
+
+impl PitchingDecisions {
+    /// A starter needs five innings for the win, four in a five-inning game, or it goes to the
+    /// reliever who pitched the most (Rule 9.17(b)).
+    pub fn new(
+        winner: Option<GameWinner>,
+        away: &[PitchingStint],
+        home: &[PitchingStint],
+        lead_changes: &[LeadChange],
+    ) -> Self {
+        let pitchers = |team: Team| match team {
+            Team::Away => away,
+            Team::Home => home,
+        };
+        let mut decisions = PitchingDecisions::default();
+
+        for team in [Team::Away, Team::Home] {
+            let stints = pitchers(team);
+            let setup = stints.get(1..stints.len().saturating_sub(1)).unwrap_or_default();
+            decisions.holds.extend(
+                setup
+                    .iter()
+                    .filter(|stint| stint.entered_in_save_situation() && stint.outs >= 1 && stint.lead > 0)
+                    .map(|stint| (team, stint.pitcher.clone())),
+            );
+        }
+
+        let winner = match winner {
+            Some(GameWinner::Away) => Team::Away,
+            Some(GameWinner::Home) => Team::Home,
+            None => return decisions,
+        };
+        decisions.winner = Some(winner);
+        let Some(go_ahead) = lead_changes.iter().rev().find(|lead_change| lead_change.team == winner) else {
+            return decisions;
+        };
+
+        let stints = pitchers(winner);
+        decisions.winning_pitcher = go_ahead.winning_pitcher.clone();
+        if let Some(starter) = stints.first()
+            && go_ahead.winning_pitcher.as_ref() == Some(&starter.pitcher)
+        {
+            let defensive_outs: u16 = stints.iter().map(PitchingStint::outs).sum();
+            let required_outs = if defensive_outs <= 15 { 12 } else { 15 };
+            let most_effective = stints[1..]
+                .iter()
+                .rev()
+                .max_by_key(|stint| stint.outs)
+                .map(|stint| stint.pitcher.clone());
+            if starter.outs < required_outs && most_effective.is_some() {
+                decisions.winning_pitcher = most_effective;
+            }
+        }
+        decisions.losing_pitcher = go_ahead.losing_pitcher.clone();
+
+        // The pitcher who finishes the game gets the save, if they are not the winning pitcher
+        if let Some(finisher) = stints.get(1..).and_then(<[PitchingStint]>::last)
+            && decisions.winning_pitcher.as_ref() != Some(&finisher.pitcher)
+            && finisher.earned_save()
+        {
+            decisions.save = Some(finisher.pitcher.clone());
+        }
+        decisions
+            .holds
+            .retain(|(team, pitcher)| *team != winner || decisions.winning_pitcher.as_ref() != Some(pitcher));
+        decisions
+    }
+
+    pub fn winning_pitcher(&self) -> Option<&Player> {
+        self.winning_pitcher.as_ref()
+    }
+
+    pub fn losing_pitcher(&self) -> Option<&Player> {
+        self.losing_pitcher.as_ref()
+    }
+
+    pub fn save(&self) -> Option<&Player> {
+        self.save.as_ref()
+    }
+
+    pub fn holds(&self, team: Team) -> impl Iterator<Item = &Player> {
+        self.holds
+            .iter()
+            .filter(move |(hold_team, _)| *hold_team == team)
+            .map(|(_, pitcher)| pitcher)
+    }
+
+    /// The decision credited to `pitcher` of `team`, if any.
+    pub fn decision(&self, team: Team, pitcher: &Player) -> Option<Decision> {
+        let won = self.winner == Some(team);
+        if won && self.winning_pitcher.as_ref() == Some(pitcher) {
+            Some(Decision::Win)
+        } else if !won && self.losing_pitcher.as_ref() == Some(pitcher) {
+            Some(Decision::Loss)
+        } else if won && self.save.as_ref() == Some(pitcher) {
+            Some(Decision::Save)
+        } else if self.holds(team).any(|hold| hold == pitcher) {
+            Some(Decision::Hold)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseball::{
+        baserunners::PlayOutcome,
+        inning::{HalfInning, HalfInningResult},
+        lineup::{Bats, BattingPosition, Throws},
+    };
+
+    fn record(stint: &mut PitchingStint, half_inning: HalfInning, pitch: PitchOutcome) -> HalfInningResult {
+        let result = half_inning.advance(pitch);
+        let batter = Player::new("Batter", 1, Bats::Right, Throws::Right);
+        let event = PitchEvent::new(InningNumber::First, batter, None, pitch, &half_inning, &result);
+        stint.record(&event, 0);
+        result
+    }
+
+    fn pitcher(name: &str, number: u8) -> Player {
+        Player::new(name, number, Bats::Right, Throws::Right)
+    }
+
+    /// A finished stint that entered with `lead_when_entered` and got `outs` outs.
+    fn stint(pitcher: Player, outs: u16, lead_when_entered: Runs, lead: Runs) -> PitchingStint {
+        let mut stint = PitchingStint::new(pitcher, InningNumber::First, InningHalf::Top);
+        stint.outs = outs;
+        stint.lead_when_entered = Some(lead_when_entered);
+        stint.lead = lead;
+        stint
+    }
+
+    fn lead_change(team: Team, winning_pitcher: &Player, losing_pitcher: &Player) -> LeadChange {
+        LeadChange::new(
+            team,
+            InningNumber::Third,
+            InningHalf::Top,
+            Some(winning_pitcher.clone()),
+            Some(losing_pitcher.clone()),
+        )
+    }
+
+    #[test]
+    fn test_win_loss_save_and_hold() {
+        let starter = pitcher("Starter", 30);
+        let setup = pitcher("Setup", 31);
+        let closer = pitcher("Closer", 32);
+        let loser = pitcher("Loser", 30);
+        let away = [
+            stint(starter.clone(), 21, 0, 2),
+            stint(setup.clone(), 3, 2, 2),
+            stint(closer.clone(), 3, 2, 2),
+        ];
+        let home = [stint(loser.clone(), 24, 0, 0)];
+        let lead_changes = [lead_change(Team::Away, &starter, &loser)];
+
+        let decisions = PitchingDecisions::new(Some(GameWinner::Away), &away, &home, &lead_changes);
+        assert_eq!(decisions.winning_pitcher(), Some(&starter));
+        assert_eq!(decisions.losing_pitcher(), Some(&loser));
+        assert_eq!(decisions.save(), Some(&closer));
+        assert_eq!(decisions.holds(Team::Away).collect::<Vec<_>>(), vec![&setup]);
+        assert_eq!(decisions.decision(Team::Away, &starter), Some(Decision::Win));
+        assert_eq!(decisions.decision(Team::Away, &setup), Some(Decision::Hold));
+        assert_eq!(decisions.decision(Team::Home, &loser), Some(Decision::Loss));
+        assert_eq!(decisions.decision(Team::Home, &setup), None);
+
+        // No decisions in a tie, but a hold still counts
+        let decisions = PitchingDecisions::new(None, &away, &home, &lead_changes);
+        assert_eq!(decisions.winning_pitcher(), None);
+        assert_eq!(decisions.save(), None);
+        assert_eq!(decisions.decision(Team::Away, &setup), Some(Decision::Hold));
+    }
+
+    #[test]
+    fn test_starter_needs_five_innings_for_the_win() {
+        let starter = pitcher("Starter", 30);
+        let long_man = pitcher("Long Man", 31);
+        let closer = pitcher("Closer", 32);
+        let loser = pitcher("Loser", 40);
+        let home = [stint(loser.clone(), 24, 0, 0)];
+        let lead_changes = [lead_change(Team::Away, &starter, &loser)];
+
+        let away = [
+            stint(starter.clone(), 12, 0, 5),
+            stint(long_man.clone(), 12, 5, 5),
+            stint(closer.clone(), 3, 5, 5),
+        ];
+        let decisions = PitchingDecisions::new(Some(GameWinner::Away), &away, &home, &lead_changes);
+        assert_eq!(decisions.winning_pitcher(), Some(&long_man));
+        // A five-run lead needs three innings for a save
+        assert_eq!(decisions.save(), None);
+        assert_eq!(decisions.holds(Team::Away).count(), 0);
+
+        // Four innings is enough in a game of five
+        let away = [stint(starter.clone(), 12, 0, 5), stint(closer.clone(), 3, 5, 5)];
+        let decisions = PitchingDecisions::new(Some(GameWinner::Away), &away, &home, &lead_changes);
+        assert_eq!(decisions.winning_pitcher(), Some(&starter));
+    }
+
+    #[test]
+    fn test_save_situations() {
+        let starter = pitcher("Starter", 30);
+        let closer = pitcher("Closer", 32);
+        let loser = pitcher("Loser", 40);
+        let home = [stint(loser.clone(), 24, 0, 0)];
+        let lead_changes = [lead_change(Team::Away, &starter, &loser)];
+        let save = |closer: PitchingStint| {
+            let away = [stint(starter.clone(), 24, 0, 4), closer];
+            PitchingDecisions::new(Some(GameWinner::Away), &away, &home, &lead_changes)
+                .save()
+                .cloned()
+        };
+
+        assert_eq!(save(stint(closer.clone(), 1, 3, 3)), None);
+        assert_eq!(save(stint(closer.clone(), 3, 3, 3)), Some(closer.clone()));
+        assert_eq!(save(stint(closer.clone(), 3, 4, 4)), None);
+
+        // The tying run on deck with the bases loaded
+        let mut tying_run_due = stint(closer.clone(), 1, 6, 6);
+        tying_run_due.tying_run_due = true;
+        assert_eq!(save(tying_run_due), Some(closer.clone()));
+
+        assert_eq!(save(stint(closer.clone(), 9, 8, 8)), Some(closer.clone()));
+    }
+
+    #[test]
+    fn test_three_batter_minimum() {
+        let pitcher = Player::new("Reliever", 40, Bats::Left, Throws::Left);
+        let mut stint = PitchingStint::new(pitcher, InningNumber::Seventh, InningHalf::Top);
+        assert!(!stint.can_be_replaced());
+
+        let mut half_inning = HalfInning::new(InningHalf::Top, BattingPosition::First);
+        for _ in 0..2 {
+            let groundout = PlayOutcome::groundout();
+            half_inning = record(&mut stint, half_inning, PitchOutcome::Ball)
+                .half_inning()
+                .expect("unexpected inning end");
+            half_inning = record(&mut stint, half_inning, PitchOutcome::InPlay(groundout))
+                .half_inning()
+                .expect("unexpected inning end");
+        }
+        assert_eq!(stint.pitches(), 4);
+        assert_eq!(stint.batters_faced(), 2);
+        assert_eq!(stint.outs(), 2);
+        assert!(!stint.can_be_replaced());
+
+        let mut result = HalfInningResult::InProgress(half_inning);
+        for _ in 0..4 {
+            let half_inning = result.half_inning().expect("unexpected inning end");
+            result = record(&mut stint, half_inning, PitchOutcome::Ball);
+        }
+        assert_eq!(stint.batters_faced(), 3);
+        assert!(stint.can_be_replaced());
+    }
+
+    #[test]
+    fn test_finishing_the_half_inning_meets_the_minimum() {
+        let pitcher = Player::new("Closer", 50, Bats::Right, Throws::Right);
+        let mut stint = PitchingStint::new(pitcher, InningNumber::Eighth, InningHalf::Bottom);
+        let half_inning = HalfInning::new(InningHalf::Bottom, BattingPosition::Fourth)
+            .advance(PitchOutcome::Strike)
+            .advance(PitchOutcome::Strike)
+            .advance(PitchOutcome::Strike)
+            .advance(PitchOutcome::Strike)
+            .advance(PitchOutcome::Strike)
+            .advance(PitchOutcome::Strike)
+            .half_inning()
+            .expect("Two out");
+
+        let popup = PlayOutcome::flyout();
+        record(&mut stint, half_inning, PitchOutcome::InPlay(popup));
+        assert_eq!(stint.batters_faced(), 1);
+        assert!(stint.can_be_replaced());
+    }
+}
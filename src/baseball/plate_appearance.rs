@@ -1,8 +1,9 @@
 use std::fmt::Display;
 
-use crate::baseball::baserunners::PlayOutcome;
+use crate::baseball::{baserunners::PlayOutcome, running::RunningPlay};
 
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Balls {
     #[default]
     Zero,
@@ -34,6 +35,7 @@ impl std::fmt::Display for Balls {
 }
 
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Strikes {
     #[default]
     Zero,
@@ -62,6 +64,7 @@ impl std::fmt::Display for Strikes {
 }
 
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Count {
     balls: Balls,
     strikes: Strikes,
@@ -89,7 +92,9 @@ impl Count {
     pub fn advance(self, outcome: PitchOutcome) -> CountAdvance {
         match outcome {
             PitchOutcome::Ball => self.advance_ball(),
-            PitchOutcome::Strike => self.advance_strike(),
+            PitchOutcome::Strike | PitchOutcome::FoulTip | PitchOutcome::FoulBunt | PitchOutcome::StrikeoutPlay(_) => {
+                self.advance_strike()
+            }
             PitchOutcome::Foul => self.advance_foul(),
             _ => CountAdvance::in_progress(self),
         }
@@ -122,6 +127,7 @@ impl Count {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CountAdvance {
     InProgress(Count),
     Strikeout,
@@ -149,13 +155,27 @@ impl CountAdvance {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PitchOutcome {
     Ball,
     Strike,
     Foul,
+    /// A foul tip held by the catcher, which is a strike whatever the count (Rule 5.09(a)(2)).
+    FoulTip,
+    /// A bunt rolled foul, which is a strike whatever the count (Rule 5.09(a)(4)).
+    FoulBunt,
+    /// A foul fly caught before it touches the ground: the batter is out (Rule 5.09(a)(1)) and the
+    /// runners may tag up as `PlayOutcome` describes.
+    CaughtFoul(PlayOutcome),
     InPlay(PlayOutcome),
     HomeRun,
     HitByPitch,
+    CatcherInterference,
+    /// Runners moving while the batter is still at the plate. The count and the batter are unchanged.
+    Running(RunningPlay),
+    /// Strike three with the runners and the batter moving as `PlayOutcome` describes: a third
+    /// strike the catcher drops, or a runner thrown out on the same pitch. Counts as a strike.
+    StrikeoutPlay(PlayOutcome),
 }
 
 impl Display for PitchOutcome {
@@ -164,14 +184,21 @@ impl Display for PitchOutcome {
             PitchOutcome::Ball => write!(f, "Ball"),
             PitchOutcome::Strike => write!(f, "Strike"),
             PitchOutcome::Foul => write!(f, "Foul"),
+            PitchOutcome::FoulTip => write!(f, "FoulTip"),
+            PitchOutcome::FoulBunt => write!(f, "FoulBunt"),
+            PitchOutcome::CaughtFoul(play_outcome) => write!(f, "CaughtFoul({})", play_outcome),
             PitchOutcome::InPlay(play_outcome) => write!(f, "InPlay({})", play_outcome),
             PitchOutcome::HomeRun => write!(f, "HomeRun"),
             PitchOutcome::HitByPitch => write!(f, "HitByPitch"),
+            PitchOutcome::CatcherInterference => write!(f, "CatcherInterference"),
+            PitchOutcome::Running(running_play) => write!(f, "Running({})", running_play),
+            PitchOutcome::StrikeoutPlay(play_outcome) => write!(f, "StrikeoutPlay({})", play_outcome),
         }
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlateAppearance {
     count: Count,
 }
@@ -187,7 +214,12 @@ impl PlateAppearance {
 
     pub fn advance(self, outcome: PitchOutcome) -> PlateAppearanceResult {
         match outcome {
-            PitchOutcome::Ball | PitchOutcome::Strike | PitchOutcome::Foul => {
+            PitchOutcome::Ball
+            | PitchOutcome::Strike
+            | PitchOutcome::Foul
+            | PitchOutcome::FoulTip
+            | PitchOutcome::FoulBunt
+            | PitchOutcome::StrikeoutPlay(_) => {
                 let count_advance = self.count.advance(outcome);
 
                 match count_advance {
@@ -198,9 +230,11 @@ impl PlateAppearance {
                     CountAdvance::Walk => PlateAppearanceResult::Walk,
                 }
             }
-            PitchOutcome::InPlay(outcome) => PlateAppearanceResult::InPlay(outcome),
+            PitchOutcome::InPlay(outcome) | PitchOutcome::CaughtFoul(outcome) => PlateAppearanceResult::InPlay(outcome),
             PitchOutcome::HomeRun => PlateAppearanceResult::HomeRun,
             PitchOutcome::HitByPitch => PlateAppearanceResult::HitByPitch,
+            PitchOutcome::CatcherInterference => PlateAppearanceResult::CatcherInterference,
+            PitchOutcome::Running(_) => PlateAppearanceResult::InProgress(self),
         }
     }
 
@@ -210,12 +244,14 @@ impl PlateAppearance {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlateAppearanceResult {
     InProgress(PlateAppearance),
     InPlay(PlayOutcome),
     Walk,
     Strikeout,
     HitByPitch,
+    CatcherInterference,
     HomeRun,
 }
 
@@ -238,10 +274,20 @@ impl PlateAppearanceResult {
             PlateAppearanceResult::Strikeout => true,
             PlateAppearanceResult::InPlay(_) => true,
             PlateAppearanceResult::HitByPitch => true,
+            PlateAppearanceResult::CatcherInterference => true,
             PlateAppearanceResult::HomeRun => true,
         }
     }
 
+    /// Whether the batter is credited with a base hit.
+    pub fn is_hit(self) -> bool {
+        match self {
+            PlateAppearanceResult::InPlay(outcome) => outcome.is_hit(),
+            PlateAppearanceResult::HomeRun => true,
+            _ => false,
+        }
+    }
+
     pub fn plate_appearance(self) -> Option<PlateAppearance> {
         match self {
             PlateAppearanceResult::InProgress(pa) => Some(pa),
@@ -328,6 +374,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_foul_tip_is_a_strike() {
+        let pa = PlateAppearance::with_count(Count::new(Balls::One, Strikes::One));
+        let PlateAppearanceResult::InProgress(pa) = pa.advance(PitchOutcome::FoulTip) else {
+            panic!("Expected in-progress plate appearance");
+        };
+        assert_eq!(pa.count(), Count::new(Balls::One, Strikes::Two));
+
+        // Unlike other fouls, a foul tip on two strikes is strike three
+        assert_eq!(pa.advance(PitchOutcome::FoulTip), PlateAppearanceResult::Strikeout);
+    }
+
+    #[test]
+    fn test_foul_bunt_with_two_strikes() {
+        let pa = PlateAppearance::with_count(Count::new(Balls::Zero, Strikes::Two));
+        assert_eq!(pa.advance(PitchOutcome::Foul), PlateAppearanceResult::InProgress(pa));
+        assert_eq!(pa.advance(PitchOutcome::FoulBunt), PlateAppearanceResult::Strikeout);
+        assert_eq!(
+            Count::default().advance(PitchOutcome::FoulBunt),
+            CountAdvance::InProgress(Count::new(Balls::Zero, Strikes::One))
+        );
+    }
+
+    #[test]
+    fn test_caught_foul() {
+        // The batter is out on any count, even with fewer than two strikes
+        let flyout = PlayOutcome::flyout();
+        let pa = PlateAppearance::with_count(Count::new(Balls::Three, Strikes::Zero));
+        let result = pa.advance(PitchOutcome::CaughtFoul(flyout));
+
+        assert!(result.is_complete());
+        assert_eq!(result, PlateAppearanceResult::InPlay(flyout));
+        assert!(!result.is_hit());
+    }
+
     #[test]
     fn test_walk() {
         let count = Count::new(Balls::Three, Strikes::One);
@@ -357,6 +438,16 @@ mod tests {
         assert!(matches!(pa, PlateAppearanceResult::HitByPitch));
     }
 
+    #[test]
+    fn test_catcher_interference() {
+        let count = Count::new(Balls::One, Strikes::Two);
+        let pa = PlateAppearance::with_count(count);
+        let pa = pa.advance(PitchOutcome::CatcherInterference);
+
+        assert!(pa.is_complete());
+        assert!(matches!(pa, PlateAppearanceResult::CatcherInterference));
+    }
+
     #[test]
     fn test_single() {
         let pa = PlateAppearance::new();
@@ -0,0 +1,2288 @@
+use std::fmt::Display;
+
+use crate::baseball::{
+    baserunners::{Base, BaserunnerState, PlayKind, PlayOutcome},
+    events::{GameEvent, PitchEvent},
+    game::{Game, GameResult, GameSummary, Team},
+    inning::{InningHalf, Outs},
+    lineup::{Bats, BattingPosition, Lineup, LineupError, LineupSlot, Player, PlayerPosition, Throws},
+    movement::{Advancement, Destination, RunnerMovement},
+    plate_appearance::{Balls, Count, PitchOutcome, PlateAppearanceResult, Strikes},
+    rules::GameRules,
+    running::{RunningKind, RunningPlay},
+    substitution::{LineupChange, SubstitutionError, SubstitutionKind},
+};
+
+/// Fielding position code Retrosheet uses for a pinch hitter.
+const PINCH_HITTER: u8 = 11;
+/// Fielding position code Retrosheet uses for a pinch runner.
+const PINCH_RUNNER: u8 = 12;
+/// Written for an error when the fielder who made it is not known.
+const UNKNOWN_FIELDER: char = '?';
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RetrosheetError {
+    /// A line of the event file is malformed.
+    Parse {
+        line: usize,
+        message: String,
+    },
+    /// A valid Retrosheet event the engine cannot replay yet, such as a stolen base.
+    UnsupportedEvent(String),
+    /// An event that contradicts the game situation, such as a runner advancing from an empty base.
+    InvalidEvent(String),
+    UnknownPlayer(String),
+    /// A team's starters do not fill all nine spots in the batting order.
+    IncompleteLineup(Team),
+    /// A play or substitution that is not for the team, inning or batter the game expects next.
+    OutOfOrder(String),
+    InvalidLineup(LineupError),
+    Substitution(SubstitutionError),
+}
+
+impl Display for RetrosheetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetrosheetError::Parse { line, message } => write!(f, "Line {}: {}", line, message),
+            RetrosheetError::UnsupportedEvent(event) => write!(f, "Event `{}` cannot be replayed", event),
+            RetrosheetError::InvalidEvent(event) => write!(f, "Event `{}` does not fit the game situation", event),
+            RetrosheetError::UnknownPlayer(player_id) => write!(f, "Player `{}` never entered the game", player_id),
+            RetrosheetError::IncompleteLineup(team) => write!(f, "{:?} team does not have nine starters", team),
+            RetrosheetError::OutOfOrder(message) => write!(f, "Out of order: {}", message),
+            RetrosheetError::InvalidLineup(error) => write!(f, "Invalid starting lineup: {}", error),
+            RetrosheetError::Substitution(error) => write!(f, "Invalid substitution: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for RetrosheetError {}
+
+impl From<LineupError> for RetrosheetError {
+    fn from(error: LineupError) -> Self {
+        RetrosheetError::InvalidLineup(error)
+    }
+}
+
+impl From<SubstitutionError> for RetrosheetError {
+    fn from(error: SubstitutionError) -> Self {
+        RetrosheetError::Substitution(error)
+    }
+}
+
+/// A `start` or `sub` record: a player taking a spot in the batting order and on the field.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    player_id: String,
+    name: String,
+    team: Team,
+    batting_position: Option<BattingPosition>,
+    fielding_position: u8,
+}
+
+impl Display for Appearance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},\"{}\",{},{},{}",
+            self.player_id,
+            self.name,
+            team_code(self.team),
+            self.batting_position.map(BattingPosition::as_number).unwrap_or(0),
+            self.fielding_position
+        )
+    }
+}
+
+impl Appearance {
+    pub fn new(
+        player_id: impl Into<String>,
+        name: impl Into<String>,
+        team: Team,
+        batting_position: Option<BattingPosition>,
+        fielding_position: u8,
+    ) -> Self {
+        Appearance {
+            player_id: player_id.into(),
+            name: name.into(),
+            team,
+            batting_position,
+            fielding_position,
+        }
+    }
+
+    pub fn player_id(&self) -> &str {
+        &self.player_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn team(&self) -> Team {
+        self.team
+    }
+
+    /// `None` for a pitcher who does not bat because of a designated hitter.
+    pub fn batting_position(&self) -> Option<BattingPosition> {
+        self.batting_position
+    }
+
+    /// Retrosheet's position code: 1-9 for the fielders, 10 DH, 11 pinch hitter, 12 pinch runner.
+    pub fn fielding_position(&self) -> u8 {
+        self.fielding_position
+    }
+
+    /// The defensive position taken, or `None` for a pinch hitter or pinch runner.
+    pub fn position(&self) -> Option<PlayerPosition> {
+        match self.fielding_position {
+            1 => Some(PlayerPosition::Pitcher),
+            2 => Some(PlayerPosition::Catcher),
+            3 => Some(PlayerPosition::FirstBase),
+            4 => Some(PlayerPosition::SecondBase),
+            5 => Some(PlayerPosition::ThirdBase),
+            6 => Some(PlayerPosition::Shortstop),
+            7 => Some(PlayerPosition::LeftField),
+            8 => Some(PlayerPosition::CenterField),
+            9 => Some(PlayerPosition::RightField),
+            10 => Some(PlayerPosition::DesignatedHitter),
+            _ => None,
+        }
+    }
+}
+
+/// A `play` record: how one plate appearance ended, with the pitches that led up to it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Play {
+    inning: u8,
+    team: Team,
+    player_id: String,
+    count: Option<Count>,
+    pitches: String,
+    event: String,
+}
+
+impl Display for Play {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let count = match self.count {
+            Some(count) => format!("{}{}", count.balls().as_number(), count.strikes().as_number()),
+            None => "??".to_string(),
+        };
+        write!(
+            f,
+            "play,{},{},{},{},{},{}",
+            self.inning,
+            team_code(self.team),
+            self.player_id,
+            count,
+            self.pitches,
+            self.event
+        )
+    }
+}
+
+impl Play {
+    pub fn new(
+        inning: u8,
+        team: Team,
+        player_id: impl Into<String>,
+        count: Option<Count>,
+        pitches: impl Into<String>,
+        event: impl Into<String>,
+    ) -> Self {
+        Play {
+            inning,
+            team,
+            player_id: player_id.into(),
+            count,
+            pitches: pitches.into(),
+            event: event.into(),
+        }
+    }
+
+    pub fn inning(&self) -> u8 {
+        self.inning
+    }
+
+    pub fn team(&self) -> Team {
+        self.team
+    }
+
+    pub fn player_id(&self) -> &str {
+        &self.player_id
+    }
+
+    /// The count when the play happened, if it was recorded.
+    pub fn count(&self) -> Option<Count> {
+        self.count
+    }
+
+    pub fn pitches(&self) -> &str {
+        &self.pitches
+    }
+
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+}
+
+/// The records of a game after its starting lineups, in file order.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Record {
+    Play(Play),
+    Substitution(Appearance),
+    /// A `radj` record: a runner placed on base to start an extra inning.
+    RunnerAdjustment {
+        player_id: String,
+        base: Base,
+    },
+    Comment(String),
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Record::Play(play) => write!(f, "{}", play),
+            Record::Substitution(appearance) => write!(f, "sub,{}", appearance),
+            Record::RunnerAdjustment { player_id, base } => write!(f, "radj,{},{}", player_id, base_name(*base)),
+            Record::Comment(comment) => write!(f, "com,\"{}\"", comment),
+        }
+    }
+}
+
+/// One game from a Retrosheet event file (`.EVN`/`.EVA`), as described at
+/// <https://www.retrosheet.org/eventfile.htm>.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetrosheetGame {
+    id: String,
+    info: Vec<(String, String)>,
+    starters: Vec<Appearance>,
+    records: Vec<Record>,
+}
+
+impl Display for RetrosheetGame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "id,{}", self.id)?;
+        writeln!(f, "version,2")?;
+        for (key, value) in &self.info {
+            writeln!(f, "info,{},{}", key, value)?;
+        }
+        for appearance in &self.starters {
+            writeln!(f, "start,{}", appearance)?;
+        }
+        for record in &self.records {
+            writeln!(f, "{}", record)?;
+        }
+        Ok(())
+    }
+}
+
+impl RetrosheetGame {
+    pub fn new(id: impl Into<String>) -> Self {
+        RetrosheetGame {
+            id: id.into(),
+            info: Vec::new(),
+            starters: Vec::new(),
+            records: Vec::new(),
+        }
+    }
+
+    pub fn with_info(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.info.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn with_starter(mut self, appearance: Appearance) -> Self {
+        self.starters.push(appearance);
+        self
+    }
+
+    pub fn with_record(mut self, record: Record) -> Self {
+        self.records.push(record);
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The value of an `info` record, such as `visteam` or `date`.
+    pub fn info(&self, key: &str) -> Option<&str> {
+        self.info
+            .iter()
+            .find(|(info_key, _)| info_key == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn starters(&self) -> &[Appearance] {
+        &self.starters
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Reads every game in an event file.
+    pub fn read_event_file(text: &str) -> Result<Vec<RetrosheetGame>, RetrosheetError> {
+        let mut games: Vec<RetrosheetGame> = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let parse_error = |message: String| RetrosheetError::Parse {
+                line: index + 1,
+                message,
+            };
+            let fields = split_fields(line);
+
+            if fields[0] == "id" {
+                let id = fields
+                    .get(1)
+                    .ok_or_else(|| parse_error("missing game id".to_string()))?;
+                games.push(RetrosheetGame::new(id.as_str()));
+                continue;
+            }
+
+            let game = games
+                .last_mut()
+                .ok_or_else(|| parse_error("record before the first game id".to_string()))?;
+            match fields[0].as_str() {
+                // Versions, earned run data and handedness adjustments do not affect the replay
+                "version" | "data" | "badj" | "padj" | "presadj" => {}
+                "info" => match &fields[1..] {
+                    [key, value @ ..] => game.info.push((key.clone(), value.join(","))),
+                    [] => return Err(parse_error("missing info key".to_string())),
+                },
+                "start" => game.starters.push(parse_appearance(&fields).map_err(parse_error)?),
+                "sub" => game
+                    .records
+                    .push(Record::Substitution(parse_appearance(&fields).map_err(parse_error)?)),
+                "play" => game
+                    .records
+                    .push(Record::Play(parse_play(&fields).map_err(parse_error)?)),
+                "radj" => game
+                    .records
+                    .push(parse_runner_adjustment(&fields).map_err(parse_error)?),
+                "com" => game.records.push(Record::Comment(fields[1..].join(","))),
+                other => return Err(parse_error(format!("unsupported record type `{}`", other))),
+            }
+        }
+
+        Ok(games)
+    }
+
+    /// Writes a game played in the engine as an event file. Retrosheet player ids are made up from
+    /// each player's name, team and number, and plays are described by where every runner ended up.
+    /// The engine does not track which fielders handled the ball, so no putouts, assists or errors
+    /// are credited: outs on balls in play are written as Retrosheet's unknown play `99` with their
+    /// trajectory, such as `99/G`, errors as `E?`, and caught stealing and pickoffs without fielders.
+    pub fn from_summary(id: impl Into<String>, summary: &GameSummary) -> Self {
+        let mut game = RetrosheetGame::new(id);
+
+        for team in [Team::Away, Team::Home] {
+            for (batting_position, slot) in summary.starting_lineup(team).iter() {
+                game.starters.push(Appearance::new(
+                    player_id(team, slot.player()),
+                    slot.player().name(),
+                    team,
+                    Some(batting_position),
+                    position_code(slot.position()),
+                ));
+            }
+        }
+
+        let mut pitches = String::new();
+        for event in summary.events() {
+            match event {
+                GameEvent::Substitution(substitution) => {
+                    let fielding_position = match substitution.kind() {
+                        SubstitutionKind::PinchHitter => PINCH_HITTER,
+                        SubstitutionKind::PinchRunner => PINCH_RUNNER,
+                        SubstitutionKind::Defensive | SubstitutionKind::DoubleSwitch => {
+                            position_code(substitution.position())
+                        }
+                    };
+                    game.records.push(Record::Substitution(Appearance::new(
+                        player_id(substitution.team(), substitution.incoming()),
+                        substitution.incoming().name(),
+                        substitution.team(),
+                        substitution.batting_position(),
+                        fielding_position,
+                    )));
+                }
+                GameEvent::PlacedRunner(placed_runner) => {
+                    game.records.push(Record::RunnerAdjustment {
+                        player_id: player_id(batting_team(placed_runner.half()), placed_runner.runner()),
+                        base: Base::Second,
+                    });
+                }
+                GameEvent::Pitch(pitch) => {
+                    pitches.push_str(pitch_code(pitch.pitch()));
+                    if let PitchOutcome::Running(play) = pitch.pitch() {
+                        // The plate appearance goes on, and its next record repeats the pitches so far
+                        let team = batting_team(pitch.half());
+                        game.records.push(Record::Play(Play::new(
+                            pitch.inning().as_number(),
+                            team,
+                            player_id(team, pitch.batter()),
+                            Some(pitch.count_before()),
+                            pitches.clone(),
+                            running_code(play),
+                        )));
+                        if pitch.outs_after() == Outs::Three {
+                            pitches.clear();
+                        }
+                    } else if pitch.ends_plate_appearance() {
+                        let team = batting_team(pitch.half());
+                        game.records.push(Record::Play(Play::new(
+                            pitch.inning().as_number(),
+                            team,
+                            player_id(team, pitch.batter()),
+                            Some(pitch.count_before()),
+                            std::mem::take(&mut pitches),
+                            event_code(pitch),
+                        )));
+                    }
+                }
+            }
+        }
+
+        game
+    }
+
+    /// Plays the game through the engine, pitch by pitch. Players get numbers in the order they
+    /// appear for their team, and a game whose records run out early is returned in progress.
+    /// Extra innings start with a runner on second if the file places one anywhere. Pitching changes
+    /// are taken as recorded, since the three-batter minimum is newer than most event files.
+    pub fn replay(&self) -> Result<GameResult, RetrosheetError> {
+        let players = Players::new(self);
+        let away_lineup = self.starting_lineup(Team::Away, &players)?;
+        let home_lineup = self.starting_lineup(Team::Home, &players)?;
+        let extra_inning_runner = self
+            .records
+            .iter()
+            .any(|record| matches!(record, Record::RunnerAdjustment { .. }));
+        let rules = GameRules::new()
+            .with_extra_inning_runner(extra_inning_runner)
+            .with_three_batter_minimum(false);
+
+        let mut game = Game::with_lineups(away_lineup, home_lineup).with_rules(rules);
+        for appearance in &self.starters {
+            if appearance.batting_position.is_none() && appearance.position() == Some(PlayerPosition::Pitcher) {
+                game = game.with_starting_pitcher(appearance.team, players.get(&appearance.player_id)?.clone());
+            }
+        }
+        let mut result = GameResult::InProgress(game);
+        // Consecutive defensive changes are made together, so the lineup only has to be valid after all of them
+        let mut realignment: Option<(Team, Vec<LineupChange>)> = None;
+
+        for record in &self.records {
+            match record {
+                Record::Comment(_) => {}
+                Record::RunnerAdjustment { player_id, base } => {
+                    realign(&mut result, &mut realignment)?;
+                    let player = players.get(player_id)?;
+                    let game = in_progress(&mut result)?;
+                    if *base != Base::Second || game.runner_on(Base::Second) != Some(player) {
+                        return Err(RetrosheetError::OutOfOrder(format!(
+                            "{} is not the runner placed on second in the {}",
+                            player_id,
+                            game.inning_description()
+                        )));
+                    }
+                }
+                Record::Play(play) => {
+                    realign(&mut result, &mut realignment)?;
+                    if play.event != "NP" {
+                        result = replay_play(result, play, &players)?;
+                    }
+                }
+                Record::Substitution(appearance) => {
+                    let player = players.get(&appearance.player_id)?.clone();
+                    // A pitcher who does not bat has no spot in the engine's lineup
+                    let Some(batting_position) = appearance.batting_position else {
+                        if appearance.position() == Some(PlayerPosition::Pitcher) {
+                            in_progress(&mut result)?.change_pitcher(appearance.team, player)?;
+                        }
+                        continue;
+                    };
+
+                    match appearance.fielding_position {
+                        PINCH_HITTER => {
+                            realign(&mut result, &mut realignment)?;
+                            let game = in_progress(&mut result)?;
+                            if appearance.team != game.batting_team()
+                                || batting_position != game.current_half_inning().current_batter()
+                            {
+                                return Err(RetrosheetError::OutOfOrder(format!(
+                                    "{} is not batting for the player due up",
+                                    appearance.player_id
+                                )));
+                            }
+                            game.pinch_hit(player)?;
+                        }
+                        PINCH_RUNNER => {
+                            realign(&mut result, &mut realignment)?;
+                            let game = in_progress(&mut result)?;
+                            let base = game
+                                .current_half_inning()
+                                .baserunners()
+                                .base_of(batting_position)
+                                .filter(|_| appearance.team == game.batting_team())
+                                .ok_or_else(|| {
+                                    RetrosheetError::OutOfOrder(format!(
+                                        "{} is running for a player who is not on base",
+                                        appearance.player_id
+                                    ))
+                                })?;
+                            game.pinch_run(base, player)?;
+                        }
+                        _ => {
+                            let position = appearance
+                                .position()
+                                .ok_or_else(|| RetrosheetError::InvalidEvent(format!("sub,{}", appearance)))?;
+                            if realignment.as_ref().is_some_and(|(team, _)| *team != appearance.team) {
+                                realign(&mut result, &mut realignment)?;
+                            }
+                            realignment
+                                .get_or_insert_with(|| (appearance.team, Vec::new()))
+                                .1
+                                .push(LineupChange::new(batting_position, player, position));
+                        }
+                    }
+                }
+            }
+        }
+
+        if !result.is_complete() {
+            realign(&mut result, &mut realignment)?;
+        }
+        Ok(result)
+    }
+
+    fn starting_lineup(&self, team: Team, players: &Players) -> Result<Lineup, RetrosheetError> {
+        let mut slots: [Option<LineupSlot>; 9] = Default::default();
+        for appearance in self.starters.iter().filter(|appearance| appearance.team == team) {
+            let Some(batting_position) = appearance.batting_position else {
+                continue;
+            };
+            let position = appearance.position().ok_or(RetrosheetError::IncompleteLineup(team))?;
+            let player = players.get(&appearance.player_id)?.clone();
+            slots[usize::from(batting_position.as_number() - 1)] = Some(LineupSlot::new(player, position));
+        }
+
+        let slots: Vec<LineupSlot> = slots
+            .into_iter()
+            .collect::<Option<_>>()
+            .ok_or(RetrosheetError::IncompleteLineup(team))?;
+        let slots: [LineupSlot; 9] = slots.try_into().expect("batting order has nine slots");
+        Ok(Lineup::new(slots)?)
+    }
+}
+
+/// The engine's player for every Retrosheet id in a game.
+struct Players {
+    players: Vec<(String, Player)>,
+}
+
+impl Players {
+    fn new(game: &RetrosheetGame) -> Self {
+        let mut players: Vec<(String, Team, Player)> = Vec::new();
+        let substitutes = game.records.iter().filter_map(|record| match record {
+            Record::Substitution(appearance) => Some(appearance),
+            _ => None,
+        });
+
+        for appearance in game.starters.iter().chain(substitutes) {
+            if players
+                .iter()
+                .any(|(player_id, _, _)| *player_id == appearance.player_id)
+            {
+                continue;
+            }
+            let teammates = players.iter().filter(|(_, team, _)| *team == appearance.team).count();
+            let number = u8::try_from(teammates + 1).unwrap_or(u8::MAX);
+            let player = Player::new(appearance.name.as_str(), number, Bats::Right, Throws::Right);
+            players.push((appearance.player_id.clone(), appearance.team, player));
+        }
+
+        Players {
+            players: players
+                .into_iter()
+                .map(|(player_id, _, player)| (player_id, player))
+                .collect(),
+        }
+    }
+
+    fn get(&self, player_id: &str) -> Result<&Player, RetrosheetError> {
+        self.players
+            .iter()
+            .find(|(id, _)| id == player_id)
+            .map(|(_, player)| player)
+            .ok_or_else(|| RetrosheetError::UnknownPlayer(player_id.to_string()))
+    }
+}
+
+/// How a plate appearance ends. Strikeouts and walks end on the last counted pitch rather than a
+/// pitch of their own, so they are told apart from the other endings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Terminal {
+    Strikeout,
+    Walk,
+    Pitch(PitchOutcome),
+}
+
+/// A runner who can appear in the advance part of an event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Runner {
+    Batter,
+    On(Base),
+}
+
+/// One runner movement such as `1-3` or `2XH`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Advance {
+    runner: Runner,
+    to: Base,
+    is_out: bool,
+    on_error: bool,
+}
+
+fn replay_play(result: GameResult, play: &Play, players: &Players) -> Result<GameResult, RetrosheetError> {
+    let GameResult::InProgress(game) = &result else {
+        return Err(RetrosheetError::OutOfOrder(format!(
+            "`{}` comes after the game ended",
+            play
+        )));
+    };
+
+    let batter = players.get(&play.player_id)?;
+    if play.inning != game.current_inning().as_number()
+        || play.team != game.batting_team()
+        || batter != game.current_batter()
+    {
+        return Err(RetrosheetError::OutOfOrder(format!(
+            "expected {} to bat in the {}, found `{}`",
+            game.current_batter(),
+            game.inning_description(),
+            play
+        )));
+    }
+
+    let half_inning = game.current_half_inning();
+    let terminal = parse_event(&play.event, half_inning.baserunners(), half_inning.current_batter())?;
+    let pitches = pitch_sequence(&play.pitches);
+    let thrown = pitches_thrown(game);
+    let strikeout_play = matches!(terminal, Terminal::Pitch(PitchOutcome::StrikeoutPlay(_)));
+
+    let mut result = result;
+    for (index, pitch) in pitches.iter().enumerate().skip(thrown) {
+        // A strikeout play is its own third strike, which the file writes as the last pitch
+        if strikeout_play && index + 1 == pitches.len() && has_two_strikes(&result) {
+            break;
+        }
+        result = result.advance(*pitch);
+        if let Some(ended) = plate_appearance_ended(&result) {
+            let expected = match terminal {
+                Terminal::Strikeout => Some(PlateAppearanceResult::Strikeout),
+                Terminal::Walk => Some(PlateAppearanceResult::Walk),
+                Terminal::Pitch(_) => None,
+            };
+            if index + 1 == pitches.len() && expected == Some(ended) {
+                return Ok(result);
+            }
+            return Err(RetrosheetError::InvalidEvent(play.to_string()));
+        }
+    }
+
+    match terminal {
+        Terminal::Strikeout => finish_count(result, PitchOutcome::Strike),
+        Terminal::Walk => finish_count(result, PitchOutcome::Ball),
+        Terminal::Pitch(pitch) => {
+            if strikeout_play {
+                result = two_strikes(result);
+            }
+            match result {
+                GameResult::InProgress(game) => game
+                    .try_advance(pitch)
+                    .map_err(|_| RetrosheetError::InvalidEvent(play.to_string())),
+                _ => Err(RetrosheetError::InvalidEvent(play.to_string())),
+            }
+        }
+    }
+}
+
+/// Throws strikes until the batter has two, filling in pitches a file left out before a strikeout play.
+fn two_strikes(mut result: GameResult) -> GameResult {
+    while matches!(result, GameResult::InProgress(_)) && !has_two_strikes(&result) {
+        result = result.advance(PitchOutcome::Strike);
+    }
+    result
+}
+
+fn has_two_strikes(result: &GameResult) -> bool {
+    match result {
+        GameResult::InProgress(game) => {
+            game.current_half_inning().current_plate_appearance().count().strikes() == Strikes::Two
+        }
+        _ => false,
+    }
+}
+
+/// Pitches already thrown to the batter at the plate. A file repeats them in each record of a
+/// plate appearance interrupted by a running play.
+fn pitches_thrown(game: &Game) -> usize {
+    let half_inning = game.current_half_inning();
+    game.log()
+        .pitches()
+        .rev()
+        .take_while(|pitch| {
+            !pitch.ends_plate_appearance()
+                && pitch.inning() == game.current_inning()
+                && pitch.half() == half_inning.half()
+        })
+        .filter(|pitch| !matches!(pitch.pitch(), PitchOutcome::Running(_)))
+        .count()
+}
+
+/// Repeats `pitch` until the plate appearance ends, filling in pitches a file left out.
+fn finish_count(mut result: GameResult, pitch: PitchOutcome) -> Result<GameResult, RetrosheetError> {
+    loop {
+        result = result.advance(pitch);
+        if plate_appearance_ended(&result).is_some() {
+            return Ok(result);
+        }
+    }
+}
+
+/// How the plate appearance ended, if the last pitch thrown ended one.
+fn plate_appearance_ended(result: &GameResult) -> Option<PlateAppearanceResult> {
+    let log = match result {
+        GameResult::InProgress(game) | GameResult::Suspended(game) => game.log(),
+        GameResult::Complete(summary) | GameResult::Called(summary) | GameResult::Tied(summary) => summary.log(),
+    };
+    log.pitches()
+        .last()
+        .filter(|pitch| pitch.ends_plate_appearance())
+        .map(PitchEvent::result)
+}
+
+fn in_progress(result: &mut GameResult) -> Result<&mut Game, RetrosheetError> {
+    match result {
+        GameResult::InProgress(game) => Ok(game),
+        _ => Err(RetrosheetError::OutOfOrder(
+            "substitution after the game ended".to_string(),
+        )),
+    }
+}
+
+fn realign(
+    result: &mut GameResult,
+    realignment: &mut Option<(Team, Vec<LineupChange>)>,
+) -> Result<(), RetrosheetError> {
+    if let Some((team, changes)) = realignment.take() {
+        in_progress(result)?.realign(team, &changes)?;
+    }
+    Ok(())
+}
+
+/// The pitches that count toward the ball-strike count. Pickoff throws, markers and the pitch
+/// that ends the plate appearance are left out.
+fn pitch_sequence(pitches: &str) -> Vec<PitchOutcome> {
+    pitches
+        .chars()
+        .filter_map(|code| match code {
+            'B' | 'I' | 'P' | 'V' => Some(PitchOutcome::Ball),
+            'C' | 'K' | 'M' | 'Q' | 'S' => Some(PitchOutcome::Strike),
+            'F' | 'R' => Some(PitchOutcome::Foul),
+            'T' => Some(PitchOutcome::FoulTip),
+            'L' | 'O' => Some(PitchOutcome::FoulBunt),
+            _ => None,
+        })
+        .collect()
+}
+
+fn parse_event(event: &str, runners: BaserunnerState, batter: BattingPosition) -> Result<Terminal, RetrosheetError> {
+    let unsupported = || RetrosheetError::UnsupportedEvent(event.to_string());
+    let invalid = || RetrosheetError::InvalidEvent(event.to_string());
+
+    let (description, advances) = match event.split_once('.') {
+        Some((description, advances)) => (description, parse_advances(advances).ok_or_else(invalid)?),
+        None => (event, Vec::new()),
+    };
+    let mut parts = description.split('/');
+    let basic = parts.next().unwrap_or_default();
+    let modifiers: Vec<&str> = parts.collect();
+
+    // A strikeout can share its pitch with a dropped third strike or a running play, as in `K+WP`
+    // or `K+CS2(26)`
+    if let Some(rest) = basic.strip_prefix('K') {
+        let (fielders, extra) = rest.split_once('+').unwrap_or((rest, ""));
+        if is_fielders(fielders) {
+            if extra.is_empty() {
+                // A batter who reaches on a dropped third strike has an advance such as `B-1` and
+                // says what let them run
+                return if advances.is_empty() {
+                    Ok(Terminal::Strikeout)
+                } else {
+                    Err(unsupported())
+                };
+            }
+            let (advancement, running) = strikeout_extra(extra).ok_or_else(unsupported)?;
+            let play = strikeout_play(advancement, &running, &advances, runners, batter).ok_or_else(invalid)?;
+            return Ok(Terminal::Pitch(PitchOutcome::StrikeoutPlay(play)));
+        }
+    }
+
+    // Any other event with a `+` combines a plate appearance with a running play
+    if basic.contains('+') {
+        return Err(unsupported());
+    }
+
+    if let Some((kind, _)) = basic.split(';').next().and_then(running_part) {
+        let play = running_play(kind, basic, &advances, runners).ok_or_else(invalid)?;
+        return Ok(Terminal::Pitch(PitchOutcome::Running(play)));
+    }
+
+    let forced = |after: BaserunnerState| {
+        if forced_advances_match(&advances, runners, after, batter) {
+            Ok(())
+        } else {
+            Err(unsupported())
+        }
+    };
+
+    match basic {
+        "W" | "IW" | "I" => {
+            forced(runners.walk(batter).0)?;
+            Ok(Terminal::Walk)
+        }
+        "HP" => {
+            forced(runners.hit_by_pitch(batter).0)?;
+            Ok(Terminal::Pitch(PitchOutcome::HitByPitch))
+        }
+        "C" => {
+            forced(runners.catcher_interference(batter).0)?;
+            Ok(Terminal::Pitch(PitchOutcome::CatcherInterference))
+        }
+        _ if basic
+            .strip_prefix("HR")
+            .or(basic.strip_prefix('H'))
+            .is_some_and(is_fielders) =>
+        {
+            forced(BaserunnerState::empty())?;
+            Ok(Terminal::Pitch(PitchOutcome::HomeRun))
+        }
+        _ => {
+            // Where the batter ends up unless an advance says otherwise, and runners put out by the fielders
+            let hit = |prefix: &str| basic.strip_prefix(prefix).is_some_and(is_fielders);
+            let advancement = match hit("E") {
+                true => Advancement::Error,
+                false => Advancement::BattedBall,
+            };
+            let (kind, batter_to, fielded_outs) = if hit("S") {
+                (PlayKind::Single, Some(Base::First), Vec::new())
+            } else if hit("D") || basic == "DGR" {
+                (PlayKind::Double, Some(Base::Second), Vec::new())
+            } else if hit("T") {
+                (PlayKind::Triple, Some(Base::Third), Vec::new())
+            } else if hit("FC") {
+                (PlayKind::FieldersChoice, Some(Base::First), Vec::new())
+            } else if hit("E") {
+                (PlayKind::ReachedOnError, Some(Base::First), Vec::new())
+            } else if basic.starts_with(|c: char| c.is_ascii_digit()) {
+                let (batter_out, fielded_outs) = fielded_outs(basic).ok_or_else(unsupported)?;
+                (
+                    batted_ball_kind(&modifiers),
+                    (!batter_out).then_some(Base::First),
+                    fielded_outs,
+                )
+            } else {
+                return Err(unsupported());
+            };
+
+            let batter_to = batter_to.map(|base| (base, advancement));
+            let play = place_runners(kind, batter_to, &fielded_outs, &advances, runners, batter).ok_or_else(invalid)?;
+            match modifiers.iter().any(|modifier| is_foul(modifier)) {
+                true => Ok(Terminal::Pitch(PitchOutcome::CaughtFoul(play))),
+                false => Ok(Terminal::Pitch(PitchOutcome::InPlay(play))),
+            }
+        }
+    }
+}
+
+/// Moves the runners for a batted ball, or `None` if the advances do not fit the runners on base.
+/// `batter_to` is where the batter ends up and how, unless an advance says otherwise.
+fn place_runners(
+    kind: PlayKind,
+    batter_to: Option<(Base, Advancement)>,
+    fielded_outs: &[Base],
+    advances: &[Advance],
+    runners: BaserunnerState,
+    batter: BattingPosition,
+) -> Option<PlayOutcome> {
+    // Runners are doubled off the base they left on a caught ball, and otherwise put out at the next
+    let caught = matches!(kind, PlayKind::Flyout | PlayKind::SacrificeFly);
+    let mut movements = Vec::new();
+    for base in fielded_outs {
+        let runner = runners.runner_on(*base)?;
+        let to = match (caught, is_forced(runners, *base)) {
+            (true, _) => Destination::Out(*base),
+            (false, true) => Destination::ForceOut(base.next()),
+            (false, false) => Destination::Out(base.next()),
+        };
+        movements.push(RunnerMovement::new(runner, *base, to));
+    }
+
+    let play_advancement = batter_to.map_or(Advancement::BattedBall, |(_, advancement)| advancement);
+    let mut batter_movement = match batter_to {
+        Some((base, advancement)) => {
+            RunnerMovement::new(batter, Base::Home, Destination::Safe(base)).with_advancement(advancement)
+        }
+        None if caught => RunnerMovement::new(batter, Base::Home, Destination::Out(Base::Home)),
+        None => RunnerMovement::new(batter, Base::Home, Destination::ForceOut(Base::First)),
+    };
+    for advance in advances {
+        let (runner, from) = match advance.runner {
+            Runner::Batter => (batter, Base::Home),
+            Runner::On(base) => (runners.runner_on(base)?, base),
+        };
+        let to = match advance.is_out {
+            true => Destination::Out(advance.to),
+            false => Destination::Safe(advance.to),
+        };
+        let advancement = match advance.on_error {
+            true => Advancement::Error,
+            false => play_advancement,
+        };
+        let movement = RunnerMovement::new(runner, from, to).with_advancement(advancement);
+        match advance.runner {
+            Runner::Batter => batter_movement = movement,
+            Runner::On(_) => movements.push(movement),
+        }
+    }
+    movements.push(batter_movement);
+
+    if movements.iter().filter(|movement| movement.is_out()).count() > 3 {
+        return None;
+    }
+    PlayOutcome::from_movements(runners, &movements)
+        .ok()
+        .map(|play| play.with_kind(kind))
+}
+
+/// Reads what happened along with a strikeout: `WP`, `PB` or an error such as `E2` that let the
+/// batter run, or running plays such as `SB2;SB3` or `CS2(26)`, with the runners they move.
+fn strikeout_extra(extra: &str) -> Option<(Advancement, Vec<(Base, Destination)>)> {
+    match extra {
+        "WP" => return Some((Advancement::WildPitch, Vec::new())),
+        "PB" => return Some((Advancement::PassedBall, Vec::new())),
+        _ => {}
+    }
+    if extra
+        .strip_prefix('E')
+        .is_some_and(|fielder| fielder.len() == 1 && is_fielders(fielder))
+    {
+        return Some((Advancement::Error, Vec::new()));
+    }
+
+    let mut advancement = None;
+    let mut running = Vec::new();
+    for part in extra.split(';') {
+        let (kind, movement) = running_part(part)?;
+        advancement.get_or_insert(kind.advancement());
+        running.push(movement?);
+    }
+    Some((advancement?, running))
+}
+
+/// Moves the runners for a strikeout that shares its pitch with something else, or `None` if the
+/// play does not fit the runners on base. The batter is out unless an advance such as `B-1` puts
+/// them on base, which only a pitch the catcher missed or an error allows.
+fn strikeout_play(
+    advancement: Advancement,
+    running: &[(Base, Destination)],
+    advances: &[Advance],
+    runners: BaserunnerState,
+    batter: BattingPosition,
+) -> Option<PlayOutcome> {
+    let mut movements = Vec::new();
+    for (from, to) in running {
+        let runner = runners.runner_on(*from)?;
+        movements.push(RunnerMovement::new(runner, *from, *to).with_advancement(advancement));
+    }
+
+    let mut batter_movement = RunnerMovement::new(batter, Base::Home, Destination::Out(Base::Home));
+    for advance in advances {
+        let (runner, from) = match advance.runner {
+            Runner::Batter => (batter, Base::Home),
+            Runner::On(base) => (runners.runner_on(base)?, base),
+        };
+        let to = match advance.is_out {
+            true => Destination::Out(advance.to),
+            false => Destination::Safe(advance.to),
+        };
+        let advancement = match advance.on_error {
+            true => Advancement::Error,
+            false => advancement,
+        };
+        let movement = RunnerMovement::new(runner, from, to).with_advancement(advancement);
+        match advance.runner {
+            Runner::Batter => batter_movement = movement,
+            Runner::On(_) => {
+                movements.retain(|movement| movement.from() != from);
+                movements.push(movement);
+            }
+        }
+    }
+    if !batter_movement.is_out()
+        && matches!(
+            batter_movement.advancement(),
+            Advancement::StolenBase | Advancement::Throw
+        )
+    {
+        return None;
+    }
+    movements.push(batter_movement);
+
+    PlayOutcome::from_movements(runners, &movements)
+        .ok()
+        .map(|play| play.with_kind(PlayKind::Strikeout))
+}
+
+/// Moves the runners for a running play such as `SB3;SB2` or `WP.3-H;1-2`, or `None` if the play
+/// does not fit the runners on base. The batter cannot move on a running play.
+fn running_play(kind: RunningKind, basic: &str, advances: &[Advance], runners: BaserunnerState) -> Option<RunningPlay> {
+    let mut movements = Vec::new();
+    for part in basic.split(';') {
+        if let (_, Some((from, to))) = running_part(part)? {
+            let runner = runners.runner_on(from)?;
+            movements.push(RunnerMovement::new(runner, from, to).with_advancement(kind.advancement()));
+        }
+    }
+
+    for advance in advances {
+        let Runner::On(from) = advance.runner else {
+            return None;
+        };
+        let runner = runners.runner_on(from)?;
+        let to = match advance.is_out {
+            true => Destination::Out(advance.to),
+            false => Destination::Safe(advance.to),
+        };
+        let advancement = match advance.on_error {
+            true => Advancement::Error,
+            false => kind.advancement(),
+        };
+        movements.retain(|movement| movement.from() != from);
+        movements.push(RunnerMovement::new(runner, from, to).with_advancement(advancement));
+    }
+
+    RunningPlay::from_movements(kind, runners, &movements).ok()
+}
+
+/// Reads one part of a running play: `WP`, `PB` or `BK`, which only move runners through advances,
+/// or `SB2`, `CS3(25)`, `POCSH(16)` or `PO1(13)`, which name the runner's base and where they end up.
+/// The fielders in parentheses are optional.
+/// A throw the fielders muff, written with an `E`, is not supported.
+fn running_part(part: &str) -> Option<(RunningKind, Option<(Base, Destination)>)> {
+    let previous = |base: Base| {
+        [Base::First, Base::Second, Base::Third]
+            .into_iter()
+            .find(|from| from.next() == base)
+    };
+    let fielded = |rest: &str| {
+        let mut chars = rest.chars();
+        let base = base_code(chars.next()?)?;
+        // The fielders who made the play may be left out
+        match chars.as_str() {
+            "" => Some(base),
+            fielders => is_fielders(fielders.strip_prefix('(')?.strip_suffix(')')?).then_some(base),
+        }
+    };
+
+    match part {
+        "WP" => return Some((RunningKind::WildPitch, None)),
+        "PB" => return Some((RunningKind::PassedBall, None)),
+        "BK" => return Some((RunningKind::Balk, None)),
+        _ => {}
+    }
+    if let Some(rest) = part.strip_prefix("SB") {
+        let base = base_code(rest.chars().next()?).filter(|_| rest.len() == 1)?;
+        return Some((
+            RunningKind::StolenBase,
+            Some((previous(base)?, Destination::Safe(base))),
+        ));
+    }
+    if let Some(rest) = part.strip_prefix("POCS").or(part.strip_prefix("CS")) {
+        let base = fielded(rest)?;
+        return Some((
+            RunningKind::CaughtStealing,
+            Some((previous(base)?, Destination::Out(base))),
+        ));
+    }
+    if let Some(rest) = part.strip_prefix("PO") {
+        let base = fielded(rest)?;
+        return Some((RunningKind::Pickoff, Some((base, Destination::Out(base)))));
+    }
+    None
+}
+
+/// Whether the runner on `base` has to move up when the batter becomes a runner.
+fn is_forced(runners: BaserunnerState, base: Base) -> bool {
+    match base {
+        Base::First => true,
+        Base::Second => runners.has_runner_on(Base::First),
+        Base::Third => runners.has_runner_on(Base::First) && runners.has_runner_on(Base::Second),
+        Base::Home => false,
+    }
+}
+
+/// Reads a fielding sequence such as `63`, `64(1)3` or `8(B)84(2)`. Each `(n)` is a runner put out;
+/// the batter is out when named with `(B)` or when fielders follow the last runner.
+fn fielded_outs(sequence: &str) -> Option<(bool, Vec<Base>)> {
+    let mut outs = Vec::new();
+    let mut batter_out = false;
+    let mut ends_with_runner = false;
+
+    let mut chars = sequence.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '0'..='9' => ends_with_runner = false,
+            '(' => {
+                match chars.next()? {
+                    'B' => batter_out = true,
+                    runner => outs.push(base_code(runner)?),
+                }
+                if chars.next()? != ')' {
+                    return None;
+                }
+                ends_with_runner = true;
+            }
+            _ => return None,
+        }
+    }
+
+    Some((batter_out || !ends_with_runner, outs))
+}
+
+/// The sacrifice or trajectory modifier of an out, if the file records one.
+/// Whether a modifier puts the ball in foul territory: `FL`, or a hit location ending in `F` such
+/// as `P2F`.
+fn is_foul(modifier: &str) -> bool {
+    modifier == "FL" || (modifier.ends_with('F') && modifier.contains(|c: char| c.is_ascii_digit()))
+}
+
+fn batted_ball_kind(modifiers: &[&str]) -> PlayKind {
+    if modifiers.contains(&"SF") {
+        return PlayKind::SacrificeFly;
+    }
+    if modifiers.contains(&"SH") {
+        return PlayKind::SacrificeBunt;
+    }
+
+    for modifier in modifiers {
+        let trajectory: String = modifier.chars().take_while(char::is_ascii_alphabetic).collect();
+        match trajectory.as_str() {
+            "G" | "GDP" | "GTP" | "BG" | "BGDP" => return PlayKind::Groundout,
+            "F" | "FDP" | "FTP" | "L" | "LDP" | "LTP" | "P" | "PDP" | "BP" | "BL" | "BPDP" => {
+                return PlayKind::Flyout;
+            }
+            _ => {}
+        }
+    }
+    PlayKind::Other
+}
+
+/// Reads the advances after the `.` of an event, such as `2-H;1-3` or `1X3(E5)`.
+fn parse_advances(advances: &str) -> Option<Vec<Advance>> {
+    advances
+        .split(';')
+        .map(|advance| {
+            let mut chars = advance.chars();
+            let runner = match chars.next()? {
+                'B' => Runner::Batter,
+                base => Runner::On(base_code(base)?),
+            };
+            let is_out = match chars.next()? {
+                '-' => false,
+                'X' => true,
+                _ => return None,
+            };
+            let to = base_code(chars.next()?)?;
+
+            // An out on the bases is cancelled by an error, written like `(E5)` or `(5E4)`
+            let annotations = chars.as_str();
+            let error = annotations
+                .as_bytes()
+                .windows(2)
+                .any(|pair| pair[0] == b'E' && is_fielders(&(pair[1] as char).to_string()));
+
+            Some(Advance {
+                runner,
+                to,
+                is_out: is_out && !error,
+                on_error: error,
+            })
+        })
+        .collect()
+}
+
+/// Whether every advance written for a walk, hit batter or home run matches where the engine puts the runners.
+fn forced_advances_match(
+    advances: &[Advance],
+    before: BaserunnerState,
+    after: BaserunnerState,
+    batter: BattingPosition,
+) -> bool {
+    advances.iter().all(|advance| {
+        let runner = match advance.runner {
+            Runner::Batter => Some(batter),
+            Runner::On(base) => before.runner_on(base),
+        };
+        match (runner, advance.to) {
+            (None, _) => false,
+            _ if advance.is_out => false,
+            (Some(runner), Base::Home) => after.base_of(runner).is_none(),
+            (Some(runner), base) => after.base_of(runner) == Some(base),
+        }
+    })
+}
+
+/// Whether `text` is a run of fielders, any of whom may be unknown.
+fn is_fielders(text: &str) -> bool {
+    text.chars().all(|c| c.is_ascii_digit() || c == UNKNOWN_FIELDER)
+}
+
+fn base_code(code: char) -> Option<Base> {
+    match code {
+        '1' => Some(Base::First),
+        '2' => Some(Base::Second),
+        '3' => Some(Base::Third),
+        'H' => Some(Base::Home),
+        _ => None,
+    }
+}
+
+fn base_name(base: Base) -> char {
+    match base {
+        Base::First => '1',
+        Base::Second => '2',
+        Base::Third => '3',
+        Base::Home => 'H',
+    }
+}
+
+fn team_code(team: Team) -> u8 {
+    match team {
+        Team::Away => 0,
+        Team::Home => 1,
+    }
+}
+
+fn batting_team(half: InningHalf) -> Team {
+    match half {
+        InningHalf::Top => Team::Away,
+        InningHalf::Bottom => Team::Home,
+    }
+}
+
+fn position_code(position: PlayerPosition) -> u8 {
+    match position {
+        PlayerPosition::Pitcher => 1,
+        PlayerPosition::Catcher => 2,
+        PlayerPosition::FirstBase => 3,
+        PlayerPosition::SecondBase => 4,
+        PlayerPosition::ThirdBase => 5,
+        PlayerPosition::Shortstop => 6,
+        PlayerPosition::LeftField => 7,
+        PlayerPosition::CenterField => 8,
+        PlayerPosition::RightField => 9,
+        PlayerPosition::DesignatedHitter => 10,
+    }
+}
+
+/// A Retrosheet-style id: four letters of the name, the team and the player's number, e.g. `batt0007`.
+fn player_id(team: Team, player: &Player) -> String {
+    let letters: String = player
+        .name()
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .chain(std::iter::repeat('x'))
+        .take(4)
+        .collect();
+    format!("{}{}{:03}", letters, team_code(team), player.number())
+}
+
+fn pitch_code(pitch: PitchOutcome) -> &'static str {
+    match pitch {
+        PitchOutcome::Ball => "B",
+        PitchOutcome::Strike | PitchOutcome::StrikeoutPlay(_) => "K",
+        PitchOutcome::Foul => "F",
+        PitchOutcome::FoulTip => "T",
+        PitchOutcome::FoulBunt => "L",
+        PitchOutcome::InPlay(_) | PitchOutcome::CaughtFoul(_) | PitchOutcome::HomeRun => "X",
+        PitchOutcome::HitByPitch => "H",
+        PitchOutcome::CatcherInterference | PitchOutcome::Running(_) => "",
+    }
+}
+
+/// Describes how a plate appearance ended, spelling out every runner who moved.
+fn event_code(pitch: &PitchEvent) -> String {
+    // The base the batter reaches without an explicit advance; `None` when the event puts them out
+    let (basic, batter_to) = match pitch.result() {
+        PlateAppearanceResult::Strikeout => match pitch.pitch() {
+            PitchOutcome::StrikeoutPlay(play) => (strikeout_code(pitch, play), None),
+            _ => ("K".to_string(), None),
+        },
+        PlateAppearanceResult::Walk => ("W".to_string(), Some(Base::First)),
+        PlateAppearanceResult::HitByPitch => ("HP".to_string(), Some(Base::First)),
+        PlateAppearanceResult::CatcherInterference => ("C".to_string(), Some(Base::First)),
+        PlateAppearanceResult::HomeRun => ("HR".to_string(), Some(Base::Home)),
+        PlateAppearanceResult::InPlay(play) => match (pitch.pitch(), in_play_code(pitch, play)) {
+            (PitchOutcome::CaughtFoul(_), (basic, batter_to)) => (format!("{}/FL", basic), batter_to),
+            (_, code) => code,
+        },
+        PlateAppearanceResult::InProgress(_) => ("NP".to_string(), None),
+    };
+
+    let before = pitch.baserunners_before();
+    let after = pitch.baserunners_after();
+    let scorers = pitch.scorers();
+    let destination = |runner: BattingPosition| match after.base_of(runner) {
+        Some(base) => Some(base),
+        None if scorers.contains(&runner) => Some(Base::Home),
+        None => None,
+    };
+
+    // Runners stealing on a strikeout are already described by the event
+    let stolen: Vec<BattingPosition> = match pitch.pitch() {
+        PitchOutcome::StrikeoutPlay(_) => pitch
+            .movements()
+            .into_iter()
+            .filter(|movement| steal_code(*movement).is_some())
+            .map(|movement| movement.runner())
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    // An extra base on a throwing error is marked on the advance, unless the whole play was an error
+    let marks_errors = matches!(
+        pitch.result(),
+        PlateAppearanceResult::InPlay(play) if play.kind() != PlayKind::ReachedOnError
+    );
+    let movements = pitch.movements();
+    let error = |runner: BattingPosition| {
+        let on_error = movements
+            .iter()
+            .any(|movement| movement.runner() == runner && movement.advancement() == Advancement::Error);
+        if marks_errors && on_error {
+            format!("(E{})", UNKNOWN_FIELDER)
+        } else {
+            String::new()
+        }
+    };
+
+    let mut advances = Vec::new();
+    for base in [Base::Third, Base::Second, Base::First] {
+        if let Some(runner) = before.runner_on(base).filter(|runner| !stolen.contains(runner)) {
+            match destination(runner) {
+                Some(to) if to == base => {}
+                Some(to) => advances.push(format!("{}-{}{}", base_name(base), base_name(to), error(runner))),
+                None => advances.push(format!("{}X{}", base_name(base), base_name(base.next()))),
+            }
+        }
+    }
+    match (destination(pitch.batting_position()), batter_to) {
+        (to, default) if to == default => {}
+        (Some(to), _) => advances.push(format!("B-{}{}", base_name(to), error(pitch.batting_position()))),
+        (None, default) => advances.push(format!("BX{}", base_name(default.unwrap_or(Base::First)))),
+    }
+
+    if advances.is_empty() {
+        basic
+    } else {
+        format!("{}.{}", basic, advances.join(";"))
+    }
+}
+
+/// Describes a running play, such as `SB2`, `CS3`, `PO1` or `WP.3-H;1-2`.
+fn running_code(play: RunningPlay) -> String {
+    let mut parts = Vec::new();
+    let mut advances = Vec::new();
+    for movement in play.movements() {
+        let from = movement.from();
+        let steal = match play.kind() {
+            RunningKind::StolenBase | RunningKind::CaughtStealing => steal_code(movement),
+            _ => None,
+        };
+        if let Some(steal) = steal {
+            parts.push(steal);
+            continue;
+        }
+        match (play.kind(), movement.to()) {
+            (RunningKind::Pickoff, Destination::Out(base)) if base == from => {
+                parts.push(format!("PO{}", base_name(base)));
+            }
+            (_, Destination::Safe(to)) => advances.push(format!("{}-{}", base_name(from), base_name(to))),
+            (_, to) => advances.push(format!("{}X{}", base_name(from), base_name(to.base()))),
+        }
+    }
+
+    let basic = match play.kind() {
+        RunningKind::WildPitch => "WP".to_string(),
+        RunningKind::PassedBall => "PB".to_string(),
+        RunningKind::Balk => "BK".to_string(),
+        _ => parts.join(";"),
+    };
+    if advances.is_empty() {
+        basic
+    } else {
+        format!("{}.{}", basic, advances.join(";"))
+    }
+}
+
+/// Describes a runner stealing the next base, such as `SB2` or `CS3`, or `None` for any other
+/// movement.
+fn steal_code(movement: RunnerMovement) -> Option<String> {
+    if movement.advancement() != Advancement::StolenBase || movement.to().base() != movement.from().next() {
+        return None;
+    }
+    match movement.to() {
+        Destination::Safe(to) => Some(format!("SB{}", base_name(to))),
+        to => Some(format!("CS{}", base_name(to.base()))),
+    }
+}
+
+/// Describes a strikeout that shares its pitch with something else: `K+WP`, `K+PB` or `K+E?` when
+/// the batter reaches first, or the runners stealing on the pitch, such as `K+CS2/DP`.
+fn strikeout_code(pitch: &PitchEvent, play: PlayOutcome) -> String {
+    let movements = pitch.movements();
+    let batter_safe = movements
+        .iter()
+        .find(|movement| movement.is_batter() && !movement.is_out());
+    let extra = match batter_safe.map(|movement| movement.advancement()) {
+        Some(Advancement::WildPitch) => "WP".to_string(),
+        Some(Advancement::PassedBall) => "PB".to_string(),
+        Some(_) => format!("E{}", UNKNOWN_FIELDER),
+        None => movements
+            .into_iter()
+            .filter_map(steal_code)
+            .collect::<Vec<_>>()
+            .join(";"),
+    };
+    let double_play = match play.outs() {
+        2 => "/DP",
+        3 => "/TP",
+        _ => "",
+    };
+    match extra.is_empty() {
+        true => format!("K{}", double_play),
+        false => format!("K+{}{}", extra, double_play),
+    }
+}
+
+fn in_play_code(pitch: &PitchEvent, play: PlayOutcome) -> (String, Option<Base>) {
+    let batter = pitch.batting_position();
+    let batter_out = pitch.baserunners_after().base_of(batter).is_none() && !pitch.scorers().contains(&batter);
+    let double_play = match play.outs() {
+        2 => "DP",
+        3 => "TP",
+        _ => "",
+    };
+
+    match play.kind() {
+        PlayKind::Single => ("S".to_string(), Some(Base::First)),
+        PlayKind::Double => ("D".to_string(), Some(Base::Second)),
+        PlayKind::Triple => ("T".to_string(), Some(Base::Third)),
+        PlayKind::HomeRun => ("HR".to_string(), Some(Base::Home)),
+        PlayKind::ReachedOnError => (format!("E{}", UNKNOWN_FIELDER), Some(Base::First)),
+        PlayKind::Groundout if batter_out => (format!("99/G{}", double_play), None),
+        PlayKind::Flyout if batter_out => (format!("99/F{}", double_play), None),
+        PlayKind::SacrificeFly if batter_out => ("99/SF".to_string(), None),
+        PlayKind::SacrificeBunt if batter_out => (format!("99/SH{}", double_play), None),
+        _ if batter_out => ("99".to_string(), None),
+        _ => ("FC".to_string(), Some(Base::First)),
+    }
+}
+
+/// Splits a record on commas, keeping commas inside quotes and dropping the quotes.
+fn split_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+fn parse_team(field: &str) -> Result<Team, String> {
+    match field {
+        "0" => Ok(Team::Away),
+        "1" => Ok(Team::Home),
+        _ => Err(format!("`{}` is not a team", field)),
+    }
+}
+
+/// `start` and `sub` records: `start,playerid,"name",team,batting position,fielding position`.
+fn parse_appearance(fields: &[String]) -> Result<Appearance, String> {
+    let [_, player_id, name, team, batting_position, fielding_position] = fields else {
+        return Err(format!("expected 6 fields, found {}", fields.len()));
+    };
+
+    let batting_position = match batting_position.parse::<usize>() {
+        Ok(0) => None,
+        Ok(spot @ 1..=9) => Some(BattingPosition::ALL[spot - 1]),
+        _ => return Err(format!("`{}` is not a batting position", batting_position)),
+    };
+    let fielding_position = match fielding_position.parse::<u8>() {
+        Ok(code @ 1..=PINCH_RUNNER) => code,
+        _ => return Err(format!("`{}` is not a fielding position", fielding_position)),
+    };
+
+    Ok(Appearance::new(
+        player_id.as_str(),
+        name.as_str(),
+        parse_team(team)?,
+        batting_position,
+        fielding_position,
+    ))
+}
+
+/// `radj` records: `radj,playerid,base`.
+fn parse_runner_adjustment(fields: &[String]) -> Result<Record, String> {
+    let [_, player_id, base] = fields else {
+        return Err(format!("expected 3 fields, found {}", fields.len()));
+    };
+    let base = match base.as_str() {
+        "1" => Base::First,
+        "2" => Base::Second,
+        "3" => Base::Third,
+        _ => return Err(format!("`{}` is not a base", base)),
+    };
+
+    Ok(Record::RunnerAdjustment {
+        player_id: player_id.clone(),
+        base,
+    })
+}
+
+/// `play` records: `play,inning,team,playerid,count,pitches,event`.
+fn parse_play(fields: &[String]) -> Result<Play, String> {
+    let [_, inning, team, player_id, count, pitches, event] = fields else {
+        return Err(format!("expected 7 fields, found {}", fields.len()));
+    };
+
+    let inning = match inning.parse::<u8>() {
+        Ok(inning) if inning > 0 => inning,
+        _ => return Err(format!("`{}` is not an inning", inning)),
+    };
+    let count = match count.as_bytes() {
+        [balls, strikes] => {
+            let balls = match balls {
+                b'0' => Some(Balls::Zero),
+                b'1' => Some(Balls::One),
+                b'2' => Some(Balls::Two),
+                b'3' => Some(Balls::Three),
+                _ => None,
+            };
+            let strikes = match strikes {
+                b'0' => Some(Strikes::Zero),
+                b'1' => Some(Strikes::One),
+                b'2' => Some(Strikes::Two),
+                _ => None,
+            };
+            balls.zip(strikes).map(|(balls, strikes)| Count::new(balls, strikes))
+        }
+        _ => None,
+    };
+
+    Ok(Play::new(
+        inning,
+        parse_team(team)?,
+        player_id.as_str(),
+        count,
+        pitches.as_str(),
+        event.as_str(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseball::{
+        baserunners::{BaseOutcome, HomeOutcome},
+        box_score::PitchingLine,
+        substitution::Substitution,
+    };
+
+    const SAMPLE: &str = r#"id,HOM202504010
+version,2
+info,visteam,AWY
+info,hometeam,HOM
+info,usedh,true
+start,awy01,"Away One",0,1,8
+start,awy02,"Away Two",0,2,6
+start,awy03,"Away Three",0,3,4
+start,awy04,"Away Four",0,4,3
+start,awy05,"Away Five",0,5,9
+start,awy06,"Away Six",0,6,5
+start,awy07,"Away Seven",0,7,7
+start,awy08,"Away Eight",0,8,2
+start,awy09,"Away Nine",0,9,1
+start,hom01,"Home One",1,1,8
+start,hom02,"Home Two",1,2,7
+start,hom03,"Home Three",1,3,9
+start,hom04,"Home Four",1,4,10
+start,hom05,"Home Five",1,5,3
+start,hom06,"Home Six",1,6,5
+start,hom07,"Home Seven",1,7,6
+start,hom08,"Home Eight",1,8,4
+start,hom09,"Home Nine",1,9,2
+start,homp1,"Home Pitcher",1,0,1
+play,1,0,awy01,32,BCBBFB,W
+play,1,0,awy02,11,BCX,S8/G.1-3
+play,1,0,awy03,00,X,64(1)3/GDP.3-H
+play,1,0,awy04,02,CSS,K
+play,1,1,hom01,10,BX,D7/L
+com,"Pinch runner, then a two-run homer"
+sub,hompr,"Home Runner",1,1,12
+play,1,1,hom02,00,X,HR/F7.2-H
+play,1,1,hom03,01,CX,8/F
+play,1,1,hom04,00,X,53/G
+play,1,1,hom05,21,BBCX,E6/G
+play,1,1,hom06,00,X,54(1)/FO/G
+sub,hompr,"Home Runner",1,1,8
+sub,hom02,"Home Two",1,2,9
+sub,hom03,"Home Three",1,3,7
+sub,homp2,"Home Reliever",1,0,1
+play,2,0,awy05,??,,NP
+play,2,0,awy05,??,,W
+play,2,0,awy06,12,BCFX,T9/L.1-H
+"#;
+
+    fn sample() -> RetrosheetGame {
+        let mut games = RetrosheetGame::read_event_file(SAMPLE).expect("Sample should parse");
+        assert_eq!(games.len(), 1);
+        games.remove(0)
+    }
+
+    fn in_play(event: &str, runners: BaserunnerState) -> PlayOutcome {
+        match parse_event(event, runners, BattingPosition::Fifth) {
+            Ok(Terminal::Pitch(PitchOutcome::InPlay(play))) => play,
+            other => panic!("{} should be a ball in play, got {:?}", event, other),
+        }
+    }
+
+    fn play_engine_game() -> GameSummary {
+        let mut result = GameResult::InProgress(Game::new());
+        let mut plate_appearances = 0;
+
+        loop {
+            let mut game = match result {
+                GameResult::InProgress(game) => game,
+                result => return result.summary().expect("Game should be over"),
+            };
+
+            if plate_appearances == 12 {
+                let pinch_hitter = Player::new("Pinch Hitter", 20, Bats::Left, Throws::Right);
+                game.pinch_hit(pinch_hitter)
+                    .expect("Bench player should be able to pinch hit");
+            }
+            if plate_appearances == 30 {
+                let reliever = Player::new("Reliever", 30, Bats::Right, Throws::Right);
+                let change = LineupChange::new(BattingPosition::Ninth, reliever, PlayerPosition::Pitcher);
+                let fielding_team = game.batting_team().opponent();
+                game.defensive_replacement(fielding_team, change)
+                    .expect("Reliever should be able to replace the pitcher");
+            }
+
+            let runners = game.current_half_inning().baserunners();
+            let batter = game.current_half_inning().current_batter();
+            let pitches = match plate_appearances % 10 {
+                0 => vec![
+                    PitchOutcome::Ball,
+                    PitchOutcome::Strike,
+                    PitchOutcome::InPlay(PlayOutcome::single(runners, batter)),
+                ],
+                // A wild pitch in the middle of a strikeout, ended by a foul tip
+                1 if !runners.is_empty() => vec![
+                    PitchOutcome::Strike,
+                    PitchOutcome::Running(RunningPlay::wild_pitch(runners).expect("Runners on base")),
+                    PitchOutcome::Foul,
+                    PitchOutcome::FoulTip,
+                ],
+                1 => vec![PitchOutcome::Strike, PitchOutcome::Foul, PitchOutcome::Strike],
+                // The runner on second steals third before the batter flies out
+                2 if runners.second().is_some() && runners.third().is_none() => {
+                    let steal = RunningPlay::stolen_base(runners, Base::Second).expect("Runner on second");
+                    let after = runners.set_second(None).set_third(runners.second());
+                    vec![
+                        PitchOutcome::Ball,
+                        PitchOutcome::Running(steal),
+                        PitchOutcome::InPlay(PlayOutcome::flyout_with(after, batter)),
+                    ]
+                }
+                2 => vec![
+                    PitchOutcome::Ball,
+                    PitchOutcome::CaughtFoul(PlayOutcome::flyout_with(runners, batter)),
+                ],
+                3 => vec![PitchOutcome::Ball; 4],
+                4 if runners.is_empty() => vec![PitchOutcome::InPlay(PlayOutcome::groundout())],
+                // Fielder's choice: the runner from first is forced out and the batter takes their place
+                4 if runners.first().is_some() => vec![PitchOutcome::InPlay(
+                    PlayOutcome::flyout_with(runners, batter)
+                        .with_first(BaseOutcome::Runner(batter))
+                        .with_kind(PlayKind::Other),
+                )],
+                4 => vec![PitchOutcome::Strike; 3],
+                5 => vec![
+                    PitchOutcome::Foul,
+                    PitchOutcome::InPlay(PlayOutcome::double(runners, batter)),
+                ],
+                6 => vec![PitchOutcome::HitByPitch],
+                // Line drive double play, doubling the runner off first
+                7 if runners.first().is_some() => vec![PitchOutcome::InPlay(
+                    PlayOutcome::flyout_with(runners, batter)
+                        .with_first(BaseOutcome::None)
+                        .with_home(HomeOutcome::new_with_outs(Outs::Two)),
+                )],
+                7 => vec![
+                    PitchOutcome::Ball,
+                    PitchOutcome::InPlay(PlayOutcome::triple(runners, batter)),
+                ],
+                // The batter runs on a third strike that gets past the catcher
+                9 if runners.first().is_none() => {
+                    let outs = game.current_half_inning().outs();
+                    let dropped = PlayOutcome::dropped_third_strike(runners, batter, outs, Advancement::PassedBall)
+                        .expect("First base is open");
+                    vec![
+                        PitchOutcome::Strike,
+                        PitchOutcome::Strike,
+                        PitchOutcome::StrikeoutPlay(dropped),
+                    ]
+                }
+                _ => vec![PitchOutcome::Ball, PitchOutcome::Ball, PitchOutcome::HomeRun],
+            };
+
+            result = pitches
+                .into_iter()
+                .fold(GameResult::InProgress(game), GameResult::advance);
+            plate_appearances += 1;
+        }
+    }
+
+    #[test]
+    fn test_read_event_file() {
+        let game = sample();
+
+        assert_eq!(game.id(), "HOM202504010");
+        assert_eq!(game.info("hometeam"), Some("HOM"));
+        assert_eq!(game.info("date"), None);
+        assert_eq!(game.starters().len(), 19);
+        assert_eq!(game.starters()[18].batting_position(), None);
+        assert_eq!(game.starters()[18].position(), Some(PlayerPosition::Pitcher));
+
+        let Record::Play(play) = &game.records()[1] else {
+            panic!("Second record should be a play");
+        };
+        assert_eq!(play.player_id(), "awy02");
+        assert_eq!(play.team(), Team::Away);
+        assert_eq!(play.count(), Some(Count::new(Balls::One, Strikes::One)));
+        assert_eq!(play.pitches(), "BCX");
+        assert_eq!(play.event(), "S8/G.1-3");
+
+        assert_eq!(
+            game.records()[5],
+            Record::Comment("Pinch runner, then a two-run homer".to_string())
+        );
+        let Record::Substitution(pinch_runner) = &game.records()[6] else {
+            panic!("Seventh record should be a substitution");
+        };
+        assert_eq!(pinch_runner.name(), "Home Runner");
+        assert_eq!(pinch_runner.batting_position(), Some(BattingPosition::First));
+        assert_eq!(pinch_runner.fielding_position(), PINCH_RUNNER);
+        assert_eq!(pinch_runner.position(), None);
+    }
+
+    #[test]
+    fn test_replay_event_file() {
+        let result = sample().replay().expect("Sample should replay");
+        let game = result.game().expect("Sample stops in the 2nd inning");
+
+        assert_eq!(game.current_inning().as_number(), 2);
+        assert_eq!(game.batting_team(), Team::Away);
+        assert_eq!(game.score().away(), 1);
+        assert_eq!(game.score().home(), 2);
+        assert_eq!(game.runner_on(Base::Third).map(Player::name), Some("Away Six"));
+
+        let line_score = game.line_score();
+        assert_eq!(line_score.away().runs_by_inning(), &[1, 1]);
+        assert_eq!(line_score.home().runs_by_inning(), &[2]);
+        assert_eq!(line_score.away().hits(), 2);
+        assert_eq!(line_score.home().hits(), 2);
+        assert_eq!(line_score.home().left_on_base(), 1);
+
+        let home = game.home_lineup();
+        assert_eq!(home.player(BattingPosition::First).name(), "Home Runner");
+        assert_eq!(home.position(BattingPosition::First), PlayerPosition::CenterField);
+        assert_eq!(home.position(BattingPosition::Second), PlayerPosition::RightField);
+        assert_eq!(home.position(BattingPosition::Third), PlayerPosition::LeftField);
+        assert_eq!(home.pitcher(), None);
+
+        let substitutions: Vec<&Substitution> = game.substitutions().collect();
+        assert_eq!(substitutions.len(), 2);
+        assert_eq!(substitutions[0].kind(), SubstitutionKind::PinchRunner);
+        assert_eq!(substitutions[1].incoming().name(), "Home Reliever");
+        assert_eq!(substitutions[1].outgoing().name(), "Home Pitcher");
+        assert_eq!(substitutions[1].batting_position(), None);
+
+        // The walk with no pitch data is filled in with four balls
+        let walk: Vec<PitchOutcome> = game.log().pitches().skip(24).take(4).map(PitchEvent::pitch).collect();
+        assert_eq!(walk, vec![PitchOutcome::Ball; 4]);
+    }
+
+    #[test]
+    fn test_fielded_outs() {
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::Third));
+        let second = BaserunnerState::new().set_second(Some(BattingPosition::Fourth));
+        let first_and_second = first.set_second(Some(BattingPosition::Fourth));
+
+        let double_play = in_play("64(1)3/GDP", first);
+        assert_eq!(double_play.outs(), 2);
+        assert_eq!(double_play.baserunners(), BaserunnerState::empty());
+        assert_eq!(double_play.kind(), PlayKind::Groundout);
+
+        let force_out = in_play("54(1)/FO/G", first);
+        assert_eq!(force_out.outs(), 1);
+        assert_eq!(force_out.first(), BaseOutcome::Runner(BattingPosition::Fifth));
+
+        let line_drive_double_play = in_play("8(B)84(2)/LDP", second);
+        assert_eq!(line_drive_double_play.outs(), 2);
+        assert_eq!(line_drive_double_play.kind(), PlayKind::Flyout);
+
+        let thrown_out_at_home = in_play("S9/L.2XH(92);1-3", first_and_second);
+        assert_eq!(thrown_out_at_home.outs(), 1);
+        assert_eq!(thrown_out_at_home.runs_scored(), 0);
+        assert_eq!(thrown_out_at_home.third(), BaseOutcome::Runner(BattingPosition::Third));
+        assert!(thrown_out_at_home.is_hit());
+
+        let fielders_choice = in_play("FC6.1X2(64)", first);
+        assert_eq!(fielders_choice.kind(), PlayKind::FieldersChoice);
+        assert_eq!(fielders_choice.first(), BaseOutcome::Runner(BattingPosition::Fifth));
+
+        let sacrifice_fly = in_play(
+            "8/SF/F.3-H",
+            BaserunnerState::new().set_third(Some(BattingPosition::Second)),
+        );
+        assert_eq!(sacrifice_fly.kind(), PlayKind::SacrificeFly);
+        assert_eq!(sacrifice_fly.runs_scored(), 1);
+
+        let sacrifice_bunt = in_play("13/SH.1-2", first);
+        assert_eq!(sacrifice_bunt.kind(), PlayKind::SacrificeBunt);
+        assert_eq!(sacrifice_bunt.second(), BaseOutcome::Runner(BattingPosition::Third));
+
+        let safe_on_error = in_play("D8.1XH(E2)", first);
+        assert_eq!(safe_on_error.outs(), 0);
+        assert_eq!(safe_on_error.runs_scored(), 1);
+        assert_eq!(safe_on_error.second(), BaseOutcome::Runner(BattingPosition::Fifth));
+        let scored_on_error = safe_on_error
+            .runner_movements(&first, BattingPosition::Fifth)
+            .into_iter()
+            .find(|movement| movement.scored());
+        assert_eq!(
+            scored_on_error.map(|movement| movement.advancement()),
+            Some(Advancement::Error)
+        );
+
+        let reached_on_error = in_play("E6.1-2", first);
+        assert_eq!(reached_on_error.kind(), PlayKind::ReachedOnError);
+        assert!(
+            reached_on_error
+                .runner_movements(&first, BattingPosition::Fifth)
+                .iter()
+                .all(|movement| movement.advancement() == Advancement::Error)
+        );
+        // An error by an unknown fielder
+        assert_eq!(in_play("E?.1-2", first), reached_on_error);
+    }
+
+    #[test]
+    fn test_running_plays() {
+        let first_and_third = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Third))
+            .set_third(Some(BattingPosition::Second));
+        let running = |event: &str| match parse_event(event, first_and_third, BattingPosition::Fifth) {
+            Ok(Terminal::Pitch(PitchOutcome::Running(play))) => play,
+            other => panic!("{} should be a running play, got {:?}", event, other),
+        };
+
+        let double_steal = running("SBH;SB2");
+        assert_eq!(double_steal.kind(), RunningKind::StolenBase);
+        assert_eq!(double_steal.runs_scored(), 1);
+        assert_eq!(
+            double_steal.apply(&first_and_third),
+            Ok((BaserunnerState::new().set_second(Some(BattingPosition::Third)), 1))
+        );
+
+        let caught_stealing = running("CS2(26)");
+        assert_eq!(caught_stealing.kind(), RunningKind::CaughtStealing);
+        assert_eq!(caught_stealing.outs(), 1);
+        assert_eq!(running("POCS2(136)"), caught_stealing);
+
+        let pickoff = running("PO1(13)");
+        assert_eq!(pickoff.kind(), RunningKind::Pickoff);
+        assert_eq!(pickoff.outs(), 1);
+
+        // Without the fielders, as exported games write them
+        assert_eq!(running("CS2"), caught_stealing);
+        assert_eq!(running("PO1"), pickoff);
+
+        let wild_pitch = running("WP.3-H;1-2");
+        assert_eq!(wild_pitch.kind(), RunningKind::WildPitch);
+        assert_eq!(wild_pitch.runs_scored(), 1);
+        assert_eq!(
+            wild_pitch
+                .movements()
+                .map(|movement| movement.advancement())
+                .collect::<Vec<_>>(),
+            vec![Advancement::WildPitch, Advancement::WildPitch]
+        );
+
+        // The runner takes an extra base on the catcher's throwing error
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::Third));
+        let Ok(Terminal::Pitch(PitchOutcome::Running(steal_and_error))) =
+            parse_event("SB2.1-3(E2)", first, BattingPosition::Fifth)
+        else {
+            panic!("SB2.1-3(E2) should be a running play");
+        };
+        assert_eq!(
+            steal_and_error.apply(&first),
+            Ok((BaserunnerState::new().set_third(Some(BattingPosition::Third)), 0))
+        );
+        assert_eq!(
+            steal_and_error
+                .movements()
+                .next()
+                .map(|movement| movement.advancement()),
+            Some(Advancement::Error)
+        );
+        assert_eq!(running_code(running("BK.3-H;1-2")), "BK.3-H;1-2");
+        assert_eq!(running_code(double_steal), "SBH;SB2");
+    }
+
+    #[test]
+    fn test_fouls() {
+        assert_eq!(
+            pitch_sequence("FTLO"),
+            vec![
+                PitchOutcome::Foul,
+                PitchOutcome::FoulTip,
+                PitchOutcome::FoulBunt,
+                PitchOutcome::FoulBunt
+            ]
+        );
+
+        let caught_foul = |event: &str, runners| match parse_event(event, runners, BattingPosition::Fifth) {
+            Ok(Terminal::Pitch(PitchOutcome::CaughtFoul(play))) => play,
+            other => panic!("{} should be a caught foul, got {:?}", event, other),
+        };
+        let popup = caught_foul("2/P2F", BaserunnerState::empty());
+        assert_eq!(popup.outs(), 1);
+        assert_eq!(popup.kind(), PlayKind::Flyout);
+
+        let sacrifice_fly = caught_foul(
+            "9/SF/FL.3-H",
+            BaserunnerState::new().set_third(Some(BattingPosition::Second)),
+        );
+        assert_eq!(sacrifice_fly.kind(), PlayKind::SacrificeFly);
+        assert_eq!(sacrifice_fly.runs_scored(), 1);
+    }
+
+    #[test]
+    fn test_strikeout_plays() {
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::Third));
+        let strikeout = |event: &str, runners| match parse_event(event, runners, BattingPosition::Fifth) {
+            Ok(Terminal::Pitch(PitchOutcome::StrikeoutPlay(play))) => play,
+            other => panic!("{} should be a strikeout play, got {:?}", event, other),
+        };
+
+        let wild_pitch = strikeout("K+WP.B-1", BaserunnerState::empty());
+        assert_eq!(wild_pitch.kind(), PlayKind::Strikeout);
+        assert_eq!(wild_pitch.outs(), 0);
+        assert_eq!(wild_pitch.baserunners().first(), Some(BattingPosition::Fifth));
+        assert_eq!(
+            strikeout("K+E2.B-1", BaserunnerState::empty())
+                .movements()
+                .last()
+                .map(|movement| movement.advancement()),
+            Some(Advancement::Error)
+        );
+        assert_eq!(
+            strikeout("K+E?.B-1", BaserunnerState::empty()),
+            strikeout("K+E2.B-1", BaserunnerState::empty())
+        );
+
+        let double_play = strikeout("K+CS2(26)/DP", first);
+        assert_eq!(double_play.outs(), 2);
+        assert!(double_play.baserunners().is_empty());
+
+        let steal = strikeout("K+SB2", first);
+        assert_eq!(steal.outs(), 1);
+        assert_eq!(steal.baserunners().second(), Some(BattingPosition::Third));
+    }
+
+    #[test]
+    fn test_replay_strikeout_plays() {
+        // Replays the sample up to `play`, which takes the place of `record`
+        let replay = |record: &str, play: &str| {
+            let (text, _) = SAMPLE.split_once(record).expect("Sample has the record");
+            let games = RetrosheetGame::read_event_file(&format!("{}{}\n", text, play)).expect("Sample should parse");
+            games[0].replay().expect("Sample should replay")
+        };
+
+        // Two out and the bases empty after the double play, so the batter can run
+        let result = replay("play,1,0,awy04,02,CSS,K", "play,1,0,awy04,02,CSS,K+WP.B-1");
+        let game = result.game_ref().expect("Sample ends mid-inning");
+        assert_eq!(game.runner_on(Base::First).map(Player::name), Some("Away Four"));
+        assert_eq!(game.current_half_inning().outs(), Outs::Two);
+        let last = game.log().pitches().last().expect("Strikeout should be logged");
+        assert_eq!(last.count_before(), Count::new(Balls::Zero, Strikes::Two));
+        assert!(matches!(last.pitch(), PitchOutcome::StrikeoutPlay(_)));
+
+        let result = replay("play,1,0,awy02,11,BCX,S8/G.1-3", "play,1,0,awy02,02,CSS,K+CS2(26)/DP");
+        let game = result.game_ref().expect("Sample ends mid-inning");
+        assert!(game.current_half_inning().baserunners().is_empty());
+        assert_eq!(game.current_half_inning().outs(), Outs::Two);
+    }
+
+    #[test]
+    fn test_replay_plate_appearance_interrupted_by_a_steal() {
+        let text = SAMPLE.replace(
+            "play,1,0,awy02,11,BCX,S8/G.1-3",
+            "play,1,0,awy02,10,B,SB2\nplay,1,0,awy02,11,BCX,S8/G.2-3",
+        );
+        let games = RetrosheetGame::read_event_file(&text).expect("Sample should parse");
+        let result = games[0].replay().expect("Sample should replay");
+        let game = result.game_ref().expect("Sample ends mid-game");
+
+        let steal = game
+            .log()
+            .pitches()
+            .find(|pitch| matches!(pitch.pitch(), PitchOutcome::Running(_)))
+            .expect("Steal should be logged");
+        assert_eq!(steal.count_before(), Count::new(Balls::One, Strikes::Zero));
+        assert_eq!(
+            game.log()
+                .pitches()
+                .filter(|pitch| pitch.half() == InningHalf::Top && pitch.batting_position() == BattingPosition::Second)
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_events_that_cannot_be_replayed() {
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::Third));
+        let event = |event: &str, runners| parse_event(event, runners, BattingPosition::Fifth);
+
+        assert_eq!(
+            event("CS2(2E4)", first),
+            Err(RetrosheetError::UnsupportedEvent("CS2(2E4)".to_string()))
+        );
+        assert_eq!(
+            event("DI.1-2", first),
+            Err(RetrosheetError::UnsupportedEvent("DI.1-2".to_string()))
+        );
+        assert_eq!(
+            event("K+OA.1-2", first),
+            Err(RetrosheetError::UnsupportedEvent("K+OA.1-2".to_string()))
+        );
+        // A dropped third strike says what let the batter run
+        assert_eq!(
+            event("K.B-1", BaserunnerState::empty()),
+            Err(RetrosheetError::UnsupportedEvent("K.B-1".to_string()))
+        );
+        // The runner on first has to move up for the batter to reach
+        assert_eq!(
+            event("K+WP.B-1", first),
+            Err(RetrosheetError::InvalidEvent("K+WP.B-1".to_string()))
+        );
+        // A walk only moves the runners it forces
+        assert_eq!(
+            event("W.1-3", first),
+            Err(RetrosheetError::UnsupportedEvent("W.1-3".to_string()))
+        );
+        assert_eq!(event("W.1-2", first), Ok(Terminal::Walk));
+        assert_eq!(
+            event("S8.2-H", first),
+            Err(RetrosheetError::InvalidEvent("S8.2-H".to_string()))
+        );
+        assert_eq!(
+            event("SB3", first),
+            Err(RetrosheetError::InvalidEvent("SB3".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_play_out_of_order() {
+        let text = SAMPLE.replace("play,1,0,awy02,11,BCX", "play,1,0,awy03,11,BCX");
+        let games = RetrosheetGame::read_event_file(&text).expect("Sample should parse");
+        assert!(matches!(games[0].replay(), Err(RetrosheetError::OutOfOrder(_))));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            RetrosheetGame::read_event_file("info,visteam,AWY"),
+            Err(RetrosheetError::Parse {
+                line: 1,
+                message: "record before the first game id".to_string()
+            })
+        );
+
+        let text = "id,HOM202504010\nplay,1,0,awy01,00,X\n";
+        assert!(matches!(
+            RetrosheetGame::read_event_file(text),
+            Err(RetrosheetError::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_extra_inning_runner_round_trip() {
+        let ground_out = PitchOutcome::InPlay(PlayOutcome::groundout());
+        let mut result = GameResult::InProgress(Game::new().with_rules(GameRules::major_league()));
+        while result
+            .game_ref()
+            .is_some_and(|game| !game.rules().is_extra_inning(game.current_inning().as_number()))
+        {
+            result = result.advance(ground_out);
+        }
+        result = result.advance(PitchOutcome::HomeRun);
+        while !result.is_complete() {
+            result = result.advance(PitchOutcome::Strike);
+        }
+        let summary = result.summary().expect("Game should be complete");
+
+        let exported = RetrosheetGame::from_summary("HOM202504030", &summary);
+        let radj: Vec<&Record> = exported
+            .records()
+            .iter()
+            .filter(|record| matches!(record, Record::RunnerAdjustment { .. }))
+            .collect();
+        assert_eq!(radj.len(), 2);
+        let text = exported.to_string();
+        assert!(text.contains("radj,batt0009,2\n"));
+        assert!(text.contains(",X,99/G\n"));
+
+        let games = RetrosheetGame::read_event_file(&text).expect("Exported file should parse");
+        assert_eq!(games, vec![exported]);
+        let replayed = games[0]
+            .replay()
+            .expect("Exported file should replay")
+            .summary()
+            .expect("Replayed game should be complete");
+        assert_eq!(replayed.final_score(), summary.final_score());
+        assert_eq!(replayed.box_score(), summary.box_score());
+
+        // The placed runner must be the batter due up ahead of the leadoff hitter
+        let text = text.replacen("radj,batt0009,2", "radj,batt0001,2", 1);
+        let games = RetrosheetGame::read_event_file(&text).expect("Edited file should parse");
+        assert!(matches!(games[0].replay(), Err(RetrosheetError::OutOfOrder(_))));
+    }
+
+    #[test]
+    fn test_export_errors() {
+        let mut result = GameResult::InProgress(Game::new());
+        for throwing_error in [false, true] {
+            let game = result.game_ref().expect("Game should continue");
+            let runners = game.current_half_inning().baserunners();
+            let batter = game.current_half_inning().current_batter();
+            let play = match throwing_error {
+                false => PlayOutcome::reached_on_error(runners, batter),
+                true => PlayOutcome::single(runners, batter)
+                    .with_throwing_error(runners, batter, BattingPosition::First)
+                    .expect("Runner on second"),
+            };
+            result = result.advance(PitchOutcome::InPlay(play));
+        }
+        result = result.advance(PitchOutcome::HomeRun);
+        while !result.is_complete() {
+            result = result.advance(PitchOutcome::Strike);
+        }
+        let summary = result.summary().expect("Game should be complete");
+        assert_eq!(summary.line_score().home().errors(), 2);
+        let home_pitcher = summary.box_score().home().pitcher(9).cloned();
+        assert_eq!(home_pitcher.as_ref().map(PitchingLine::runs), Some(3));
+        // The batter who reached on the error scores unearned
+        assert_eq!(home_pitcher.as_ref().map(PitchingLine::earned_runs), Some(2));
+
+        let exported = RetrosheetGame::from_summary("HOM202504040", &summary);
+        let text = exported.to_string();
+        assert!(text.contains(",X,E?\n"));
+        assert!(text.contains(",X,S.1-3(E?)\n"));
+
+        let replayed = RetrosheetGame::read_event_file(&text).expect("Exported file should parse")[0]
+            .replay()
+            .expect("Exported file should replay")
+            .summary()
+            .expect("Replayed game should be complete");
+        assert_eq!(replayed.line_score(), summary.line_score());
+        assert_eq!(replayed.box_score(), summary.box_score());
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let summary = play_engine_game();
+        let exported = RetrosheetGame::from_summary("HOM202504020", &summary)
+            .with_info("visteam", "AWY")
+            .with_info("hometeam", "HOM");
+
+        let text = exported.to_string();
+        assert!(text.starts_with("id,HOM202504020\nversion,2\ninfo,visteam,AWY\n"));
+        assert!(text.contains("sub,pinc0020,\"Pinch Hitter\",0,6,11\n"));
+        assert!(text.contains(",WP."));
+        assert!(text.contains(",SB3\n"));
+        assert!(text.contains(",K+PB.B-1"));
+        assert!(text.contains(",KFT,K\n"));
+        assert!(text.contains("/FL"));
+
+        let games = RetrosheetGame::read_event_file(&text).expect("Exported file should parse");
+        assert_eq!(games, vec![exported]);
+
+        let replayed = games[0]
+            .replay()
+            .expect("Exported file should replay")
+            .summary()
+            .expect("Replayed game should be complete");
+        assert_eq!(replayed.final_score(), summary.final_score());
+        assert_eq!(replayed.line_score(), summary.line_score());
+
+        let batting = |summary: &GameSummary, team| {
+            summary
+                .box_score()
+                .team(team)
+                .batting()
+                .iter()
+                .map(|line| (line.player().name().to_string(), line.hits(), line.runs_batted_in()))
+                .collect::<Vec<_>>()
+        };
+        for team in [Team::Away, Team::Home] {
+            assert_eq!(batting(&replayed, team), batting(&summary, team));
+        }
+    }
+}
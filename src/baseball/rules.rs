@@ -0,0 +1,219 @@
+use crate::Runs;
+
+/// Ends a game early once one team leads by `run_differential` runs and at least `innings` innings
+/// have been played (or the home team leads after the top half of that inning).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MercyRule {
+    run_differential: Runs,
+    innings: u8,
+}
+
+impl MercyRule {
+    pub fn new(run_differential: Runs, innings: u8) -> Self {
+        MercyRule {
+            run_differential,
+            innings,
+        }
+    }
+
+    pub fn run_differential(&self) -> Runs {
+        self.run_differential
+    }
+
+    pub fn innings(&self) -> u8 {
+        self.innings
+    }
+
+    fn applies(&self, inning: u8, lead: Runs) -> bool {
+        inning >= self.innings && lead >= self.run_differential
+    }
+}
+
+/// How long a game lasts and the ways it can end other than after regulation (Rule 7.01).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRules {
+    regulation_innings: u8,
+    official_innings: u8,
+    extra_inning_runner: bool,
+    mercy_rule: Option<MercyRule>,
+    max_innings: Option<u8>,
+    suspend_called_ties: bool,
+    three_batter_minimum: bool,
+}
+
+impl GameRules {
+    /// Nine innings with unlimited extra innings that start with the bases empty, and pitchers held
+    /// to the three-batter minimum.
+    pub fn new() -> Self {
+        GameRules {
+            regulation_innings: 9,
+            official_innings: 5,
+            extra_inning_runner: false,
+            mercy_rule: None,
+            max_innings: None,
+            suspend_called_ties: false,
+            three_batter_minimum: true,
+        }
+    }
+
+    /// Nine innings, with a runner placed on second base to start each extra inning and called ties
+    /// suspended rather than ending the game.
+    pub fn major_league() -> Self {
+        Self::new().with_extra_inning_runner(true).with_suspended_ties(true)
+    }
+
+    /// Seven innings, as played in each game of a doubleheader.
+    pub fn doubleheader() -> Self {
+        Self::major_league().with_regulation_innings(7)
+    }
+
+    /// Six innings, ended early by a ten-run lead after four and official once four are played.
+    /// Pitchers may be replaced at any time.
+    pub fn little_league() -> Self {
+        Self::new()
+            .with_regulation_innings(6)
+            .with_official_innings(4)
+            .with_mercy_rule(MercyRule::new(10, 4))
+            .with_three_batter_minimum(false)
+    }
+
+    pub fn with_regulation_innings(mut self, innings: u8) -> Self {
+        self.regulation_innings = innings;
+        self
+    }
+
+    /// Innings after which a called game counts, or one fewer if the home team is ahead.
+    pub fn with_official_innings(mut self, innings: u8) -> Self {
+        self.official_innings = innings;
+        self
+    }
+
+    pub fn with_extra_inning_runner(mut self, extra_inning_runner: bool) -> Self {
+        self.extra_inning_runner = extra_inning_runner;
+        self
+    }
+
+    pub fn with_mercy_rule(mut self, mercy_rule: MercyRule) -> Self {
+        self.mercy_rule = Some(mercy_rule);
+        self
+    }
+
+    /// Stops a game still tied after `innings` innings, leaving it a tie.
+    pub fn with_max_innings(mut self, innings: u8) -> Self {
+        self.max_innings = Some(innings);
+        self
+    }
+
+    /// Whether a regulation game called with the score tied is suspended instead of ending in a tie.
+    pub fn with_suspended_ties(mut self, suspend_called_ties: bool) -> Self {
+        self.suspend_called_ties = suspend_called_ties;
+        self
+    }
+
+    /// Whether a pitcher must face three batters or finish the half inning before being replaced
+    /// (Rule 5.10(g)).
+    pub fn with_three_batter_minimum(mut self, three_batter_minimum: bool) -> Self {
+        self.three_batter_minimum = three_batter_minimum;
+        self
+    }
+
+    pub fn regulation_innings(&self) -> u8 {
+        self.regulation_innings
+    }
+
+    pub fn official_innings(&self) -> u8 {
+        self.official_innings
+    }
+
+    pub fn extra_inning_runner(&self) -> bool {
+        self.extra_inning_runner
+    }
+
+    pub fn mercy_rule(&self) -> Option<MercyRule> {
+        self.mercy_rule
+    }
+
+    pub fn max_innings(&self) -> Option<u8> {
+        self.max_innings
+    }
+
+    pub fn suspend_called_ties(&self) -> bool {
+        self.suspend_called_ties
+    }
+
+    pub fn three_batter_minimum(&self) -> bool {
+        self.three_batter_minimum
+    }
+
+    /// Whether `inning` is played only because the game was tied after regulation.
+    pub fn is_extra_inning(&self, inning: u8) -> bool {
+        inning > self.regulation_innings
+    }
+
+    /// Whether a game that has reached `inning` with one team ahead by `lead` is over.
+    /// Only the home team can take a lead that ends the game before it has finished batting.
+    pub(crate) fn is_decided(&self, inning: u8, lead: Runs) -> bool {
+        lead > 0
+            && (inning >= self.regulation_innings
+                || self
+                    .mercy_rule
+                    .is_some_and(|mercy_rule| mercy_rule.applies(inning, lead)))
+    }
+
+    /// Whether a game tied after `inning` complete innings stops there.
+    pub(crate) fn ends_in_tie(&self, inning: u8) -> bool {
+        self.max_innings.is_some_and(|max_innings| inning >= max_innings)
+    }
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_rules() {
+        let rules = GameRules::default();
+        assert_eq!(rules.regulation_innings(), 9);
+        assert!(!rules.extra_inning_runner());
+        assert_eq!(rules.mercy_rule(), None);
+        assert_eq!(rules.max_innings(), None);
+        assert_eq!(rules.official_innings(), 5);
+        assert!(!rules.suspend_called_ties());
+        assert!(rules.three_batter_minimum());
+        assert!(GameRules::major_league().three_batter_minimum());
+        assert!(!GameRules::little_league().three_batter_minimum());
+        assert!(rules.is_extra_inning(10));
+        assert!(!rules.is_extra_inning(9));
+    }
+
+    #[test]
+    fn test_game_is_decided() {
+        let rules = GameRules::new();
+        assert!(!rules.is_decided(8, 20));
+        assert!(rules.is_decided(9, 1));
+        assert!(!rules.is_decided(12, 0));
+
+        let rules = GameRules::little_league();
+        assert!(!rules.is_decided(3, 15));
+        assert!(!rules.is_decided(4, 9));
+        assert!(rules.is_decided(4, 10));
+        assert!(rules.is_decided(6, 1));
+    }
+
+    #[test]
+    fn test_ends_in_tie() {
+        assert!(!GameRules::new().ends_in_tie(20));
+
+        let rules = GameRules::new().with_max_innings(12);
+        assert!(!rules.ends_in_tie(11));
+        assert!(rules.ends_in_tie(12));
+    }
+}
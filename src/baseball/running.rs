@@ -0,0 +1,325 @@
+use std::fmt::Display;
+
+use crate::{
+    Runs,
+    baseball::{
+        baserunners::{Base, BaserunnerState, PlayError, start_number, walk_off_movements},
+        inning::Outs,
+        lineup::BattingPosition,
+        movement::{Advancement, Destination, RunnerMovement},
+    },
+};
+
+/// What sent the runners on a play that does not involve the batter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RunningKind {
+    StolenBase,
+    CaughtStealing,
+    Pickoff,
+    WildPitch,
+    PassedBall,
+    Balk,
+}
+
+impl Display for RunningKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunningKind::StolenBase => write!(f, "Stolen base"),
+            RunningKind::CaughtStealing => write!(f, "Caught stealing"),
+            RunningKind::Pickoff => write!(f, "Pickoff"),
+            RunningKind::WildPitch => write!(f, "Wild pitch"),
+            RunningKind::PassedBall => write!(f, "Passed ball"),
+            RunningKind::Balk => write!(f, "Balk"),
+        }
+    }
+}
+
+impl RunningKind {
+    /// What lets a runner move up on this kind of play.
+    pub fn advancement(self) -> Advancement {
+        match self {
+            RunningKind::StolenBase | RunningKind::CaughtStealing => Advancement::StolenBase,
+            RunningKind::Pickoff => Advancement::Throw,
+            RunningKind::WildPitch => Advancement::WildPitch,
+            RunningKind::PassedBall => Advancement::PassedBall,
+            RunningKind::Balk => Advancement::Balk,
+        }
+    }
+}
+
+/// Runners moving while the batter is still at the plate, between pitches or on a pitch the batter
+/// did not put in play. The count is left as it was.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunningPlay {
+    kind: RunningKind,
+    movements: [Option<RunnerMovement>; 3],
+}
+
+impl Display for RunningPlay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        for (index, movement) in self.movements().enumerate() {
+            let separator = if index == 0 { ": " } else { ", " };
+            write!(f, "{}{}", separator, movement)?;
+        }
+        Ok(())
+    }
+}
+
+impl RunningPlay {
+    /// A running play described runner by runner. Runners without a movement hold their base, and
+    /// the batter cannot move at all.
+    pub fn from_movements(
+        kind: RunningKind,
+        baserunners: BaserunnerState,
+        movements: &[RunnerMovement],
+    ) -> Result<Self, PlayError> {
+        if baserunners.is_empty() {
+            return Err(PlayError::BasesEmpty);
+        }
+        if let Some(batter) = movements.iter().find(|movement| movement.is_batter()) {
+            return Err(PlayError::UnknownRunner(batter.runner()));
+        }
+        baserunners.apply(movements)?;
+
+        let mut sorted = movements.to_vec();
+        sorted.sort_by_key(|movement| std::cmp::Reverse(start_number(movement.from())));
+        let mut recorded = [None; 3];
+        for (slot, movement) in recorded.iter_mut().zip(sorted) {
+            *slot = Some(movement);
+        }
+        Ok(RunningPlay {
+            kind,
+            movements: recorded,
+        })
+    }
+
+    /// The runner on `from` steals the next base.
+    pub fn stolen_base(baserunners: BaserunnerState, from: Base) -> Result<Self, PlayError> {
+        Self::single_runner(
+            RunningKind::StolenBase,
+            baserunners,
+            from,
+            Destination::Safe(from.next()),
+        )
+    }
+
+    /// The runner on `from` is tagged out trying to steal the next base.
+    pub fn caught_stealing(baserunners: BaserunnerState, from: Base) -> Result<Self, PlayError> {
+        Self::single_runner(
+            RunningKind::CaughtStealing,
+            baserunners,
+            from,
+            Destination::Out(from.next()),
+        )
+    }
+
+    /// The runner on `from` is picked off by a throw from the pitcher or catcher.
+    pub fn pickoff(baserunners: BaserunnerState, from: Base) -> Result<Self, PlayError> {
+        Self::single_runner(RunningKind::Pickoff, baserunners, from, Destination::Out(from))
+    }
+
+    /// Every runner moves up a base on a wild pitch.
+    pub fn wild_pitch(baserunners: BaserunnerState) -> Result<Self, PlayError> {
+        Self::every_runner(RunningKind::WildPitch, baserunners)
+    }
+
+    /// Every runner moves up a base on a passed ball.
+    pub fn passed_ball(baserunners: BaserunnerState) -> Result<Self, PlayError> {
+        Self::every_runner(RunningKind::PassedBall, baserunners)
+    }
+
+    /// Every runner is awarded a base on a balk (Rule 6.02(a)).
+    pub fn balk(baserunners: BaserunnerState) -> Result<Self, PlayError> {
+        Self::every_runner(RunningKind::Balk, baserunners)
+    }
+
+    fn single_runner(
+        kind: RunningKind,
+        baserunners: BaserunnerState,
+        from: Base,
+        to: Destination,
+    ) -> Result<Self, PlayError> {
+        let runner = baserunners.runner_on(from).ok_or(PlayError::NoRunnerOn(from))?;
+        let movement = RunnerMovement::new(runner, from, to).with_advancement(kind.advancement());
+        Self::from_movements(kind, baserunners, &[movement])
+    }
+
+    fn every_runner(kind: RunningKind, baserunners: BaserunnerState) -> Result<Self, PlayError> {
+        let movements: Vec<RunnerMovement> = [Base::Third, Base::Second, Base::First]
+            .into_iter()
+            .filter_map(|base| {
+                let runner = baserunners.runner_on(base)?;
+                Some(
+                    RunnerMovement::new(runner, base, Destination::Safe(base.next()))
+                        .with_advancement(kind.advancement()),
+                )
+            })
+            .collect();
+        Self::from_movements(kind, baserunners, &movements)
+    }
+
+    pub fn kind(self) -> RunningKind {
+        self.kind
+    }
+
+    /// Each runner who moved, lead runner first.
+    pub fn movements(self) -> impl Iterator<Item = RunnerMovement> {
+        self.movements.into_iter().flatten()
+    }
+
+    pub fn outs(self) -> u8 {
+        self.movements().filter(|movement| movement.is_out()).count() as u8
+    }
+
+    pub fn runs_scored(self) -> Runs {
+        self.movements().filter(|movement| movement.scored()).count() as Runs
+    }
+
+    /// Moves `baserunners` as the play describes, returning the runners left on base and the runs
+    /// scored, or why the play does not fit them.
+    pub fn apply(self, baserunners: &BaserunnerState) -> Result<(BaserunnerState, Runs), PlayError> {
+        let movements: Vec<RunnerMovement> = self.movements().collect();
+        baserunners.apply(&movements)
+    }
+
+    /// The play as scored when it ends the game after `runs_needed` runs, with `outs` already
+    /// recorded before it (Rule 7.01(g)). Trailing runners move up no more bases than the winning
+    /// run did, and an out made after the game ended does not stand.
+    pub fn walk_off(self, outs: Outs, runs_needed: Runs) -> RunningPlay {
+        let movements: Vec<RunnerMovement> = self.movements().collect();
+        let outs_to_go = 3usize.saturating_sub(outs.as_number() as usize).max(1);
+        let ended_outs: Vec<BattingPosition> = movements
+            .iter()
+            .filter(|movement| movement.is_out())
+            .skip(outs_to_go - 1)
+            .map(|movement| movement.runner())
+            .collect();
+        let runs = self.runs_scored();
+        if runs_needed == 0 || runs < runs_needed || (runs == runs_needed && ended_outs.is_empty()) {
+            return self;
+        }
+
+        let Some(winning_run) = movements
+            .iter()
+            .filter(|movement| movement.scored())
+            .nth(runs_needed as usize - 1)
+        else {
+            return self;
+        };
+        let credited_bases = 4 - start_number(winning_run.from());
+
+        let mut recorded = [None; 3];
+        for (slot, movement) in
+            recorded
+                .iter_mut()
+                .zip(walk_off_movements(&movements, runs_needed, credited_bases, &ended_outs))
+        {
+            *slot = Some(movement);
+        }
+        RunningPlay {
+            kind: self.kind,
+            movements: recorded,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseball::lineup::BattingPosition;
+
+    #[test]
+    fn test_stolen_base() {
+        let first = BaserunnerState::new().set_first(Some(BattingPosition::Fourth));
+        let steal = RunningPlay::stolen_base(first, Base::First).expect("Runner on first");
+        assert_eq!(steal.kind(), RunningKind::StolenBase);
+        assert_eq!(steal.outs(), 0);
+        assert_eq!(
+            steal.apply(&first),
+            Ok((BaserunnerState::new().set_second(Some(BattingPosition::Fourth)), 0))
+        );
+        assert_eq!(
+            steal.movements().next().map(|movement| movement.advancement()),
+            Some(Advancement::StolenBase)
+        );
+
+        assert_eq!(
+            RunningPlay::stolen_base(first, Base::Second),
+            Err(PlayError::NoRunnerOn(Base::Second))
+        );
+        let first_and_second = first.set_second(Some(BattingPosition::Third));
+        assert_eq!(
+            RunningPlay::stolen_base(first_and_second, Base::First),
+            Err(PlayError::BaseOccupied(Base::Second))
+        );
+    }
+
+    #[test]
+    fn test_runners_put_out() {
+        let first_and_third = BaserunnerState::new()
+            .set_first(Some(BattingPosition::Fourth))
+            .set_third(Some(BattingPosition::Second));
+
+        let caught_stealing = RunningPlay::caught_stealing(first_and_third, Base::First).expect("Runner on first");
+        assert_eq!(caught_stealing.outs(), 1);
+        assert_eq!(
+            caught_stealing.apply(&first_and_third),
+            Ok((BaserunnerState::new().set_third(Some(BattingPosition::Second)), 0))
+        );
+
+        let pickoff = RunningPlay::pickoff(first_and_third, Base::Third).expect("Runner on third");
+        assert_eq!(pickoff.outs(), 1);
+        assert_eq!(pickoff.runs_scored(), 0);
+        assert_eq!(pickoff.to_string(), "Pickoff: Second out at third");
+    }
+
+    #[test]
+    fn test_every_runner_moves_up() {
+        let second_and_third = BaserunnerState::new()
+            .set_second(Some(BattingPosition::Fourth))
+            .set_third(Some(BattingPosition::Second));
+
+        let balk = RunningPlay::balk(second_and_third).expect("Runners on base");
+        assert_eq!(balk.runs_scored(), 1);
+        assert_eq!(
+            balk.apply(&second_and_third),
+            Ok((BaserunnerState::new().set_third(Some(BattingPosition::Fourth)), 1))
+        );
+        assert_eq!(
+            RunningPlay::wild_pitch(BaserunnerState::empty()),
+            Err(PlayError::BasesEmpty)
+        );
+    }
+
+    #[test]
+    fn test_walk_off_running_play() {
+        // Both runners come home on a wild pitch, but the game ends with the first
+        let second_and_third = BaserunnerState::new()
+            .set_second(Some(BattingPosition::Fourth))
+            .set_third(Some(BattingPosition::Second));
+        let wild_pitch = RunningPlay::from_movements(
+            RunningKind::WildPitch,
+            second_and_third,
+            &[
+                RunnerMovement::new(BattingPosition::Fourth, Base::Second, Destination::Safe(Base::Home)),
+                RunnerMovement::new(BattingPosition::Second, Base::Third, Destination::Safe(Base::Home)),
+            ],
+        )
+        .expect("Runners on second and third");
+        assert_eq!(wild_pitch.runs_scored(), 2);
+        assert_eq!(
+            wild_pitch.movements().next().map(|movement| movement.runner()),
+            Some(BattingPosition::Second)
+        );
+
+        let walk_off = wild_pitch.walk_off(Outs::Zero, 1);
+        assert_eq!(walk_off.runs_scored(), 1);
+        assert_eq!(
+            walk_off.apply(&second_and_third),
+            Ok((BaserunnerState::new().set_third(Some(BattingPosition::Fourth)), 1))
+        );
+    }
+}
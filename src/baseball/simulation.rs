@@ -0,0 +1,591 @@
+use std::fmt::Display;
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{
+    Runs,
+    baseball::{
+        baserunners::PlayOutcome,
+        game::{Game, GameResult, GameSummary, GameWinner, Team},
+        lineup::Player,
+        plate_appearance::{Count, PitchOutcome},
+    },
+};
+
+/// A game still going after this many pitches is called, so a model that can never retire the side
+/// cannot hang the simulator.
+const MAX_PITCHES: usize = 5_000;
+
+/// What a simulated pitch does, before it is turned into a `PitchOutcome` for the runners on base.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SimulatedPitch {
+    Ball,
+    /// A called or swinging strike.
+    Strike,
+    Foul,
+    HitByPitch,
+    /// The batter is thrown out at first and forced runners move up.
+    Groundout,
+    /// The batter flies out and the runners hold.
+    Flyout,
+    Single,
+    Double,
+    Triple,
+    HomeRun,
+    ReachedOnError,
+}
+
+impl SimulatedPitch {
+    pub const ALL: [SimulatedPitch; 11] = [
+        SimulatedPitch::Ball,
+        SimulatedPitch::Strike,
+        SimulatedPitch::Foul,
+        SimulatedPitch::HitByPitch,
+        SimulatedPitch::Groundout,
+        SimulatedPitch::Flyout,
+        SimulatedPitch::Single,
+        SimulatedPitch::Double,
+        SimulatedPitch::Triple,
+        SimulatedPitch::HomeRun,
+        SimulatedPitch::ReachedOnError,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The pitch as thrown to the batter due up in `game`.
+    pub fn outcome(self, game: &Game) -> PitchOutcome {
+        let half_inning = game.current_half_inning();
+        let baserunners = half_inning.baserunners();
+        let batter = half_inning.current_batter();
+        match self {
+            SimulatedPitch::Ball => PitchOutcome::Ball,
+            SimulatedPitch::Strike => PitchOutcome::Strike,
+            SimulatedPitch::Foul => PitchOutcome::Foul,
+            SimulatedPitch::HitByPitch => PitchOutcome::HitByPitch,
+            SimulatedPitch::Groundout => PitchOutcome::InPlay(PlayOutcome::groundout_with(baserunners, batter)),
+            SimulatedPitch::Flyout => PitchOutcome::InPlay(PlayOutcome::flyout_with(baserunners, batter)),
+            SimulatedPitch::Single => PitchOutcome::InPlay(PlayOutcome::single(baserunners, batter)),
+            SimulatedPitch::Double => PitchOutcome::InPlay(PlayOutcome::double(baserunners, batter)),
+            SimulatedPitch::Triple => PitchOutcome::InPlay(PlayOutcome::triple(baserunners, batter)),
+            SimulatedPitch::HomeRun => PitchOutcome::HomeRun,
+            SimulatedPitch::ReachedOnError => PitchOutcome::InPlay(PlayOutcome::reached_on_error(baserunners, batter)),
+        }
+    }
+}
+
+/// Relative weights for what the next pitch does. The weights need not add up to one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PitchDistribution {
+    weights: [f64; SimulatedPitch::ALL.len()],
+}
+
+impl PitchDistribution {
+    /// A distribution with every weight at zero, to be filled in with `with_weight`.
+    pub fn new() -> Self {
+        PitchDistribution {
+            weights: [0.0; SimulatedPitch::ALL.len()],
+        }
+    }
+
+    /// Roughly how often each pitch ends each way in a recent major league season.
+    pub fn league_average() -> Self {
+        Self::new()
+            .with_weight(SimulatedPitch::Ball, 0.360)
+            .with_weight(SimulatedPitch::Strike, 0.250)
+            .with_weight(SimulatedPitch::Foul, 0.180)
+            .with_weight(SimulatedPitch::HitByPitch, 0.004)
+            .with_weight(SimulatedPitch::Groundout, 0.054)
+            .with_weight(SimulatedPitch::Flyout, 0.065)
+            .with_weight(SimulatedPitch::Single, 0.027)
+            .with_weight(SimulatedPitch::Double, 0.008)
+            .with_weight(SimulatedPitch::Triple, 0.001)
+            .with_weight(SimulatedPitch::HomeRun, 0.006)
+            .with_weight(SimulatedPitch::ReachedOnError, 0.002)
+    }
+
+    /// Sets the weight of `pitch`. Negative weights are treated as zero.
+    pub fn with_weight(mut self, pitch: SimulatedPitch, weight: f64) -> Self {
+        self.weights[pitch.index()] = weight.max(0.0);
+        self
+    }
+
+    pub fn weight(&self, pitch: SimulatedPitch) -> f64 {
+        self.weights[pitch.index()]
+    }
+
+    /// The chance of `pitch`, between zero and one.
+    pub fn probability(&self, pitch: SimulatedPitch) -> f64 {
+        let total = self.total();
+        if total > 0.0 { self.weight(pitch) / total } else { 0.0 }
+    }
+
+    /// Averages two distributions pitch by pitch, each normalized first.
+    pub fn blend(&self, other: &PitchDistribution) -> PitchDistribution {
+        SimulatedPitch::ALL.into_iter().fold(Self::new(), |blended, pitch| {
+            blended.with_weight(pitch, (self.probability(pitch) + other.probability(pitch)) / 2.0)
+        })
+    }
+
+    /// Picks a pitch, `roll` being uniform in `[0, 1)`. An empty distribution always throws a ball.
+    pub fn sample(&self, roll: f64) -> SimulatedPitch {
+        let mut remaining = roll * self.total();
+        for pitch in SimulatedPitch::ALL {
+            let weight = self.weight(pitch);
+            if remaining < weight {
+                return pitch;
+            }
+            remaining -= weight;
+        }
+        SimulatedPitch::ALL
+            .into_iter()
+            .rev()
+            .find(|pitch| self.weight(*pitch) > 0.0)
+            .unwrap_or(SimulatedPitch::Ball)
+    }
+
+    fn total(&self) -> f64 {
+        self.weights.iter().sum()
+    }
+}
+
+impl Default for PitchDistribution {
+    fn default() -> Self {
+        Self::league_average()
+    }
+}
+
+/// Decides how likely each pitch is when `batter` faces `pitcher` with the count at `count`.
+/// Shared between the simulator's threads, so it must be `Sync`.
+pub trait PitchModel: Sync {
+    fn distribution(&self, batter: &Player, pitcher: Option<&Player>, count: Count) -> PitchDistribution;
+}
+
+/// A model that ignores the count and gives each batter and pitcher their own distribution. When
+/// both players have one, the two are averaged; a player without one uses the default.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerPitchModel {
+    default: PitchDistribution,
+    batters: Vec<(Player, PitchDistribution)>,
+    pitchers: Vec<(Player, PitchDistribution)>,
+}
+
+impl PlayerPitchModel {
+    /// Every player pitches and hits like `default`.
+    pub fn new(default: PitchDistribution) -> Self {
+        PlayerPitchModel {
+            default,
+            batters: Vec::new(),
+            pitchers: Vec::new(),
+        }
+    }
+
+    pub fn with_batter(mut self, batter: Player, distribution: PitchDistribution) -> Self {
+        self.batters.retain(|(player, _)| *player != batter);
+        self.batters.push((batter, distribution));
+        self
+    }
+
+    pub fn with_pitcher(mut self, pitcher: Player, distribution: PitchDistribution) -> Self {
+        self.pitchers.retain(|(player, _)| *player != pitcher);
+        self.pitchers.push((pitcher, distribution));
+        self
+    }
+
+    fn find<'a>(players: &'a [(Player, PitchDistribution)], player: &Player) -> Option<&'a PitchDistribution> {
+        players
+            .iter()
+            .find(|(candidate, _)| candidate == player)
+            .map(|(_, distribution)| distribution)
+    }
+}
+
+impl PitchModel for PlayerPitchModel {
+    fn distribution(&self, batter: &Player, pitcher: Option<&Player>, _: Count) -> PitchDistribution {
+        let batting = Self::find(&self.batters, batter);
+        let pitching = pitcher.and_then(|pitcher| Self::find(&self.pitchers, pitcher));
+        match (batting, pitching) {
+            (Some(batting), Some(pitching)) => batting.blend(pitching),
+            (Some(distribution), None) | (None, Some(distribution)) => *distribution,
+            (None, None) => self.default,
+        }
+    }
+}
+
+/// Plays many games from the same starting point, drawing each pitch from a `PitchModel`.
+/// Game `n` is always seeded with `seed + n`, so the report does not depend on the thread count.
+pub struct Simulator<M> {
+    game: Game,
+    model: M,
+    games: u32,
+    threads: usize,
+    seed: u64,
+}
+
+impl<M: PitchModel> Simulator<M> {
+    /// Plays 1,000 games from `game`, usually a new game with its lineups and rules set, on every
+    /// available core.
+    pub fn new(game: Game, model: M) -> Self {
+        Simulator {
+            game,
+            model,
+            games: 1_000,
+            threads: std::thread::available_parallelism().map_or(1, usize::from),
+            seed: 0,
+        }
+    }
+
+    pub fn with_games(mut self, games: u32) -> Self {
+        self.games = games;
+        self
+    }
+
+    /// Spreads the games over `threads` threads, at least one.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn games(&self) -> u32 {
+        self.games
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Plays every game and tallies the results.
+    pub fn run(&self) -> SimulationReport {
+        let threads = self.threads.min(self.games.max(1) as usize) as u32;
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|thread| {
+                    scope.spawn(move || {
+                        let mut report = SimulationReport::new();
+                        for number in (thread..self.games).step_by(threads as usize) {
+                            report.record(self.play(number).as_ref());
+                        }
+                        report
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("simulation thread panicked"))
+                .fold(SimulationReport::new(), SimulationReport::merge)
+        })
+    }
+
+    /// Plays game `number` to the end, or `None` if it had to be suspended.
+    pub fn play(&self, number: u32) -> Option<GameSummary> {
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(number as u64));
+        let mut result = GameResult::InProgress(self.game.clone());
+        for _ in 0..MAX_PITCHES {
+            let Some(game) = result.game_ref() else {
+                break;
+            };
+            let batting_team = game.batting_team();
+            let distribution = self.model.distribution(
+                game.current_batter(),
+                game.pitcher(batting_team.opponent()),
+                game.current_half_inning().current_plate_appearance().count(),
+            );
+            let outcome = distribution.sample(rng.r#gen()).outcome(game);
+            result = result.advance(outcome);
+        }
+        result.call().summary()
+    }
+}
+
+/// How a batch of simulated games turned out.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationReport {
+    games: u32,
+    away_wins: u32,
+    home_wins: u32,
+    ties: u32,
+    suspended: u32,
+    /// Games by the number of runs each team scored, indexed by runs.
+    away_runs: Vec<u32>,
+    home_runs: Vec<u32>,
+    /// Games by the number of innings played, indexed by innings.
+    innings: Vec<u32>,
+}
+
+impl Display for SimulationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Games: {}  Away: {}-{}  Home: {}-{}  Ties: {}  Suspended: {}",
+            self.games, self.away_wins, self.home_wins, self.home_wins, self.away_wins, self.ties, self.suspended
+        )?;
+        writeln!(
+            f,
+            "Win %: Away {:.3}  Home {:.3}",
+            self.win_percentage(Team::Away),
+            self.win_percentage(Team::Home)
+        )?;
+        writeln!(
+            f,
+            "Runs per game: Away {:.2}  Home {:.2}",
+            self.average_runs(Team::Away),
+            self.average_runs(Team::Home)
+        )?;
+        writeln!(f, "Runs   Away   Home")?;
+        for runs in 0..self.away_runs.len().max(self.home_runs.len()) {
+            let away = self.away_runs.get(runs).copied().unwrap_or(0);
+            let home = self.home_runs.get(runs).copied().unwrap_or(0);
+            writeln!(f, "{:>4} {:>6} {:>6}", runs, away, home)?;
+        }
+        write!(f, "Innings  Games")?;
+        for (innings, games) in self.innings.iter().enumerate().filter(|(_, games)| **games > 0) {
+            write!(f, "\n{:>7} {:>6}", innings, games)?;
+        }
+        Ok(())
+    }
+}
+
+impl SimulationReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one game, `None` meaning it was suspended before it could finish.
+    pub fn record(&mut self, summary: Option<&GameSummary>) {
+        self.games += 1;
+        let Some(summary) = summary else {
+            self.suspended += 1;
+            return;
+        };
+        match summary.winner() {
+            Some(GameWinner::Away) => self.away_wins += 1,
+            Some(GameWinner::Home) => self.home_wins += 1,
+            None => self.ties += 1,
+        }
+        let score = summary.final_score();
+        tally(&mut self.away_runs, score.away() as usize);
+        tally(&mut self.home_runs, score.home() as usize);
+        tally(&mut self.innings, summary.innings_played().as_number() as usize);
+    }
+
+    /// Combines the games of two reports.
+    pub fn merge(mut self, other: SimulationReport) -> SimulationReport {
+        self.games += other.games;
+        self.away_wins += other.away_wins;
+        self.home_wins += other.home_wins;
+        self.ties += other.ties;
+        self.suspended += other.suspended;
+        combine(&mut self.away_runs, &other.away_runs);
+        combine(&mut self.home_runs, &other.home_runs);
+        combine(&mut self.innings, &other.innings);
+        self
+    }
+
+    pub fn games(&self) -> u32 {
+        self.games
+    }
+
+    pub fn wins(&self, team: Team) -> u32 {
+        match team {
+            Team::Away => self.away_wins,
+            Team::Home => self.home_wins,
+        }
+    }
+
+    pub fn ties(&self) -> u32 {
+        self.ties
+    }
+
+    pub fn suspended(&self) -> u32 {
+        self.suspended
+    }
+
+    /// Wins over games won or lost, ties and suspended games left out.
+    pub fn win_percentage(&self, team: Team) -> f64 {
+        let decided = self.away_wins + self.home_wins;
+        if decided == 0 {
+            return 0.0;
+        }
+        self.wins(team) as f64 / decided as f64
+    }
+
+    /// Games by the number of runs `team` scored: entry `n` counts the games with `n` runs.
+    pub fn runs_distribution(&self, team: Team) -> &[u32] {
+        match team {
+            Team::Away => &self.away_runs,
+            Team::Home => &self.home_runs,
+        }
+    }
+
+    /// Runs per finished game.
+    pub fn average_runs(&self, team: Team) -> f64 {
+        let distribution = self.runs_distribution(team);
+        let games: u32 = distribution.iter().sum();
+        if games == 0 {
+            return 0.0;
+        }
+        let runs: u64 = distribution
+            .iter()
+            .enumerate()
+            .map(|(runs, games)| runs as u64 * *games as u64)
+            .sum();
+        runs as f64 / games as f64
+    }
+
+    /// Games by the number of innings played: entry `n` counts the games that lasted `n` innings.
+    pub fn innings_distribution(&self) -> &[u32] {
+        &self.innings
+    }
+
+    /// Finished games that went past `regulation_innings`.
+    pub fn extra_inning_games(&self, regulation_innings: u8) -> u32 {
+        self.innings.iter().skip(regulation_innings as usize + 1).sum()
+    }
+
+    /// The most runs `team` scored in any game.
+    pub fn max_runs(&self, team: Team) -> Option<Runs> {
+        let distribution = self.runs_distribution(team);
+        distribution
+            .iter()
+            .rposition(|games| *games > 0)
+            .map(|runs| runs as Runs)
+    }
+}
+
+fn tally(distribution: &mut Vec<u32>, index: usize) {
+    if distribution.len() <= index {
+        distribution.resize(index + 1, 0);
+    }
+    distribution[index] += 1;
+}
+
+fn combine(distribution: &mut Vec<u32>, other: &[u32]) {
+    if distribution.len() < other.len() {
+        distribution.resize(other.len(), 0);
+    }
+    for (total, games) in distribution.iter_mut().zip(other) {
+        *total += games;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseball::{
+        lineup::{Bats, Throws},
+        rules::GameRules,
+    };
+
+    #[test]
+    fn test_sample_distribution() {
+        let distribution = PitchDistribution::new()
+            .with_weight(SimulatedPitch::Ball, 3.0)
+            .with_weight(SimulatedPitch::Strike, 1.0);
+        assert_eq!(distribution.probability(SimulatedPitch::Ball), 0.75);
+        assert_eq!(distribution.sample(0.0), SimulatedPitch::Ball);
+        assert_eq!(distribution.sample(0.74), SimulatedPitch::Ball);
+        assert_eq!(distribution.sample(0.76), SimulatedPitch::Strike);
+        assert_eq!(distribution.sample(0.999_999), SimulatedPitch::Strike);
+        assert_eq!(PitchDistribution::new().sample(0.5), SimulatedPitch::Ball);
+    }
+
+    #[test]
+    fn test_player_pitch_model() {
+        let slugger = Player::new("Slugger", 4, Bats::Left, Throws::Right);
+        let ace = Player::new("Ace", 9, Bats::Right, Throws::Right);
+        let home_runs = PitchDistribution::new().with_weight(SimulatedPitch::HomeRun, 1.0);
+        let strikes = PitchDistribution::new().with_weight(SimulatedPitch::Strike, 1.0);
+        let model = PlayerPitchModel::new(PitchDistribution::league_average())
+            .with_batter(slugger.clone(), home_runs)
+            .with_pitcher(ace.clone(), strikes);
+
+        let count = Count::default();
+        assert_eq!(model.distribution(&slugger, None, count), home_runs);
+        assert_eq!(
+            model
+                .distribution(&slugger, Some(&ace), count)
+                .probability(SimulatedPitch::HomeRun),
+            0.5
+        );
+        let other = Player::new("Other", 5, Bats::Right, Throws::Right);
+        assert_eq!(model.distribution(&other, Some(&ace), count), strikes);
+        assert_eq!(
+            model.distribution(&other, None, count),
+            PitchDistribution::league_average()
+        );
+    }
+
+    #[test]
+    fn test_every_pitch_a_strike() {
+        let model = PlayerPitchModel::new(PitchDistribution::new().with_weight(SimulatedPitch::Strike, 1.0));
+        let game = Game::new().with_rules(GameRules::new().with_max_innings(9));
+        let summary = Simulator::new(game, model).play(0).expect("the game ends in a tie");
+        assert_eq!(summary.winner(), None);
+        assert_eq!(summary.innings_played().as_number(), 9);
+        assert_eq!(summary.pitchers(Team::Home)[0].pitches(), 81);
+    }
+
+    #[test]
+    fn test_game_that_cannot_end_is_suspended() {
+        let model = PlayerPitchModel::new(PitchDistribution::new().with_weight(SimulatedPitch::Foul, 1.0));
+        let report = Simulator::new(Game::new(), model).with_games(2).run();
+        assert_eq!(report.games(), 2);
+        assert_eq!(report.suspended(), 2);
+        assert_eq!(report.win_percentage(Team::Home), 0.0);
+    }
+
+    #[test]
+    fn test_simulation_report() {
+        let simulator = Simulator::new(Game::new(), PlayerPitchModel::default())
+            .with_games(200)
+            .with_seed(7);
+        let report = simulator.with_threads(4).run();
+        assert_eq!(report.games(), 200);
+        assert_eq!(
+            report.wins(Team::Away) + report.wins(Team::Home) + report.ties() + report.suspended(),
+            200
+        );
+        assert_eq!(report.runs_distribution(Team::Away).iter().sum::<u32>(), 200);
+        assert_eq!(report.innings_distribution().iter().sum::<u32>(), 200);
+        assert!(report.innings_distribution().iter().take(9).all(|games| *games == 0));
+        let away = report.win_percentage(Team::Away);
+        assert!((away + report.win_percentage(Team::Home) - 1.0).abs() < 1e-9);
+        assert!(report.average_runs(Team::Away) > 1.0 && report.average_runs(Team::Away) < 10.0);
+
+        // The same seed gives the same games however they are split up
+        let single_threaded = Simulator::new(Game::new(), PlayerPitchModel::default())
+            .with_games(200)
+            .with_seed(7)
+            .with_threads(1)
+            .run();
+        assert_eq!(single_threaded, report);
+    }
+
+    #[test]
+    fn test_simulation_follows_the_rules() {
+        let game = Game::new().with_rules(GameRules::new().with_regulation_innings(7).with_max_innings(8));
+        let report = Simulator::new(game, PlayerPitchModel::default())
+            .with_games(100)
+            .with_threads(2)
+            .run();
+        let innings = report.innings_distribution();
+        assert!(innings.len() <= 9);
+        assert!(innings.iter().take(7).all(|games| *games == 0));
+        assert_eq!(report.extra_inning_games(7), innings.get(8).copied().unwrap_or(0));
+        assert!(report.ties() <= innings.get(8).copied().unwrap_or(0));
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt::Display;
+
+use crate::baseball::{
+    baserunners::Base,
+    game::{InningNumber, Team},
+    inning::InningHalf,
+    lineup::{BattingPosition, LineupError, Player, PlayerPosition},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SubstitutionKind {
+    PinchHitter,
+    PinchRunner,
+    Defensive,
+    DoubleSwitch,
+}
+
+impl Display for SubstitutionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubstitutionKind::PinchHitter => write!(f, "Pinch hitter"),
+            SubstitutionKind::PinchRunner => write!(f, "Pinch runner"),
+            SubstitutionKind::Defensive => write!(f, "Defensive replacement"),
+            SubstitutionKind::DoubleSwitch => write!(f, "Double switch"),
+        }
+    }
+}
+
+/// A player entering a batting order slot at a defensive position.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineupChange {
+    batting_position: BattingPosition,
+    player: Player,
+    position: PlayerPosition,
+}
+
+impl LineupChange {
+    pub fn new(batting_position: BattingPosition, player: Player, position: PlayerPosition) -> Self {
+        LineupChange {
+            batting_position,
+            player,
+            position,
+        }
+    }
+
+    pub fn batting_position(&self) -> BattingPosition {
+        self.batting_position
+    }
+
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn position(&self) -> PlayerPosition {
+        self.position
+    }
+}
+
+/// One entry in a game's substitution history.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Substitution {
+    kind: SubstitutionKind,
+    team: Team,
+    inning: InningNumber,
+    half: InningHalf,
+    outgoing: Player,
+    incoming: Player,
+    batting_position: Option<BattingPosition>,
+    position: PlayerPosition,
+}
+
+impl Display for Substitution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} replaces {}", self.kind, self.incoming, self.outgoing)?;
+        if let Some(batting_position) = self.batting_position {
+            write!(f, " batting {}", batting_position.as_number())?;
+        }
+        write!(f, " ({})", self.position.abbreviation())
+    }
+}
+
+impl Substitution {
+    pub fn new(
+        kind: SubstitutionKind,
+        team: Team,
+        inning: InningNumber,
+        half: InningHalf,
+        outgoing: Player,
+        change: LineupChange,
+    ) -> Self {
+        Substitution {
+            kind,
+            team,
+            inning,
+            half,
+            outgoing,
+            incoming: change.player,
+            batting_position: Some(change.batting_position),
+            position: change.position,
+        }
+    }
+
+    /// A new pitcher for a team using a designated hitter, who takes no spot in the batting order.
+    pub fn pitching_change(
+        team: Team,
+        inning: InningNumber,
+        half: InningHalf,
+        outgoing: Player,
+        incoming: Player,
+    ) -> Self {
+        Substitution {
+            kind: SubstitutionKind::Defensive,
+            team,
+            inning,
+            half,
+            outgoing,
+            incoming,
+            batting_position: None,
+            position: PlayerPosition::Pitcher,
+        }
+    }
+
+    pub fn kind(&self) -> SubstitutionKind {
+        self.kind
+    }
+
+    pub fn team(&self) -> Team {
+        self.team
+    }
+
+    pub fn inning(&self) -> InningNumber {
+        self.inning
+    }
+
+    pub fn half(&self) -> InningHalf {
+        self.half
+    }
+
+    /// The incoming player's spot in the batting order, or `None` for a pitcher who does not bat.
+    pub fn batting_position(&self) -> Option<BattingPosition> {
+        self.batting_position
+    }
+
+    pub fn outgoing(&self) -> &Player {
+        &self.outgoing
+    }
+
+    pub fn incoming(&self) -> &Player {
+        &self.incoming
+    }
+
+    pub fn position(&self) -> PlayerPosition {
+        self.position
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SubstitutionError {
+    Reentry(u8),
+    AlreadyInLineup(u8),
+    NoRunnerOn(Base),
+    InvalidLineup(LineupError),
+    /// Batters the pitcher has faced.
+    ThreeBatterMinimum(u8),
+}
+
+impl Display for SubstitutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubstitutionError::Reentry(number) => {
+                write!(f, "Player #{} has been removed and may not re-enter", number)
+            }
+            SubstitutionError::AlreadyInLineup(number) => write!(f, "Player #{} is already in the lineup", number),
+            SubstitutionError::NoRunnerOn(base) => write!(f, "There is no runner on {:?}", base),
+            SubstitutionError::InvalidLineup(error) => write!(f, "Invalid lineup: {}", error),
+            SubstitutionError::ThreeBatterMinimum(batters_faced) => write!(
+                f,
+                "The pitcher has faced {} of the three batters required before being replaced",
+                batters_faced
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SubstitutionError {}
+
+impl From<LineupError> for SubstitutionError {
+    fn from(error: LineupError) -> Self {
+        SubstitutionError::InvalidLineup(error)
+    }
+}
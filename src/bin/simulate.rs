@@ -0,0 +1,70 @@
+//! Plays simulated games without a window and prints how they turned out. Every player hits and
+//! pitches like a league-average major leaguer.
+//!
+//! `simulate [--games N] [--threads N] [--seed N] [--rules standard|major-league|doubleheader|little-league]`
+
+use std::process::ExitCode;
+
+use baseball::{Game, GameRules, MatchupPitchModel, Simulator};
+
+const USAGE: &str =
+    "Usage: simulate [--games N] [--threads N] [--seed N] [--rules standard|major-league|doubleheader|little-league]";
+
+fn main() -> ExitCode {
+    let simulator = match simulator(std::env::args().skip(1)) {
+        Ok(simulator) => simulator,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!(
+        "Simulating {} games on {} threads with seed {}",
+        simulator.games(),
+        simulator.threads(),
+        simulator.seed()
+    );
+    println!("{}", simulator.run());
+    ExitCode::SUCCESS
+}
+
+fn simulator(mut args: impl Iterator<Item = String>) -> Result<Simulator<MatchupPitchModel>, String> {
+    let mut rules = GameRules::new();
+    let mut games = 1_000;
+    let mut threads = None;
+    let mut seed = 0;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--games" => games = number(&value()?)?,
+            "--threads" => threads = Some(number(&value()?)?),
+            "--seed" => seed = number(&value()?)?,
+            "--rules" => rules = named_rules(&value()?)?,
+            "--help" | "-h" => return Err("Plays simulated games and reports the results".to_string()),
+            _ => return Err(format!("Unknown argument {}", arg)),
+        }
+    }
+
+    let simulator = Simulator::new(Game::new().with_rules(rules), MatchupPitchModel::default())
+        .with_games(games)
+        .with_seed(seed);
+    Ok(match threads {
+        Some(threads) => simulator.with_threads(threads),
+        None => simulator,
+    })
+}
+
+fn number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("{} is not a number", value))
+}
+
+fn named_rules(name: &str) -> Result<GameRules, String> {
+    match name {
+        "standard" => Ok(GameRules::new()),
+        "major-league" => Ok(GameRules::major_league()),
+        "doubleheader" => Ok(GameRules::doubleheader()),
+        "little-league" => Ok(GameRules::little_league()),
+        _ => Err(format!("Unknown rules {}", name)),
+    }
+}
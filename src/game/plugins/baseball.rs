@@ -1,8 +1,11 @@
-use std::f32::consts::PI;
+use std::f32::consts::{FRAC_PI_4, PI};
 
 use bevy::prelude::*;
 
-use crate::baseball::*;
+use crate::{
+    baseball::*,
+    game::plugins::batted_ball::{fielding_spot, resolve_batted_balls},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct BaseballPlugin;
@@ -18,6 +21,9 @@ impl Plugin for BaseballPlugin {
                     update_game_display,
                     handle_fielding,
                     animate_players,
+                    resolve_batted_balls
+                        .after(update_ball_physics)
+                        .before(check_game_events),
                     check_game_events,
                 ),
             )
@@ -42,6 +48,24 @@ pub struct BallState {
     pub velocity: Vec3,
     pub is_in_play: bool,
     pub hit_type: Option<HitType>,
+    /// Angle off the center field line a batted ball was hit at, in radians, positive toward
+    /// right field.
+    pub spray: f32,
+}
+
+impl BallState {
+    /// Sends the ball off the bat as `hit` toward `spray`.
+    pub fn hit(&mut self, hit: HitType, spray: f32) {
+        let speed = match hit {
+            HitType::Grounder => 60.0,
+            HitType::Fly => 240.0,
+            HitType::LineDrive => 500.0,
+            HitType::NoDoubt => 800.0,
+        };
+        self.hit_type = Some(hit);
+        self.spray = spray;
+        self.velocity = Vec3::new(speed * spray.sin(), speed * spray.cos(), 0.0);
+    }
 }
 
 #[derive(Resource, Default)]
@@ -57,7 +81,8 @@ pub enum SwingOutcome {
 }
 
 // TODO: SEE IF I NEED THIS?
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HitType {
     Grounder,
     Fly,
@@ -72,6 +97,8 @@ pub struct Ball;
 #[derive(Component)]
 pub struct Player {
     pub position: PlayerPosition,
+    /// Where the player sets up before each pitch.
+    pub station: Vec3,
     pub target_position: Vec3,
 }
 
@@ -87,6 +114,9 @@ pub struct InningText;
 #[derive(Component)]
 pub struct CountText;
 
+#[derive(Component)]
+pub struct BatterText;
+
 #[derive(Component)]
 pub struct InstructionText;
 
@@ -156,20 +186,7 @@ pub fn setup_field(
     ));
 
     // Create players
-    let player_positions = [
-        (0.0, -60.0, PlayerPosition::Pitcher),
-        (0.0, -170.0, PlayerPosition::Catcher),
-        (90.0, -60.0, PlayerPosition::FirstBase),
-        (45.0, 0.0, PlayerPosition::SecondBase),
-        (-45.0, 0.0, PlayerPosition::Shortstop),
-        (-90.0, -60.0, PlayerPosition::ThirdBase),
-        (-120.0, 60.0, PlayerPosition::LeftField),
-        (0.0, 120.0, PlayerPosition::CenterField),
-        (120.0, 60.0, PlayerPosition::RightField),
-        (15.0, -150.0, PlayerPosition::DesignatedHitter),
-    ];
-
-    for (x, y, pos) in player_positions.iter() {
+    for (x, y, pos) in PLAYER_POSITIONS.iter() {
         let color = match pos {
             PlayerPosition::DesignatedHitter => Color::srgb(0.8, 0.2, 0.2),
             _ => Color::srgb(0.2, 0.2, 0.8),
@@ -181,25 +198,41 @@ pub fn setup_field(
             Transform::from_xyz(*x, *y, 5.0),
             Player {
                 position: *pos,
+                station: Vec3::new(*x, *y, 5.0),
                 target_position: Vec3::new(*x, *y, 5.0),
             },
         ));
     }
 }
 
+/// Where each fielder, and the batter, sets up on the field.
+pub const PLAYER_POSITIONS: [(f32, f32, PlayerPosition); 10] = [
+    (0.0, -60.0, PlayerPosition::Pitcher),
+    (0.0, -170.0, PlayerPosition::Catcher),
+    (90.0, -60.0, PlayerPosition::FirstBase),
+    (45.0, 0.0, PlayerPosition::SecondBase),
+    (-45.0, 0.0, PlayerPosition::Shortstop),
+    (-90.0, -60.0, PlayerPosition::ThirdBase),
+    (-120.0, 60.0, PlayerPosition::LeftField),
+    (0.0, 120.0, PlayerPosition::CenterField),
+    (120.0, 60.0, PlayerPosition::RightField),
+    (15.0, -150.0, PlayerPosition::DesignatedHitter),
+];
+
 pub fn setup_ui(mut commands: Commands) {
-    // Score display
+    // Line score across the top of the screen
     commands.spawn((
-        Text::new("Score: Away 0 - Home 0"),
+        Text::new(LineScore::new().to_string()),
         TextFont {
-            font_size: 24.0,
+            font_size: 18.0,
             ..default()
         },
         TextColor(Color::WHITE),
+        TextLayout::new_with_justify(JustifyText::Center),
         Node {
             position_type: PositionType::Absolute,
             top: Val::Px(10.0),
-            left: Val::Px(10.0),
+            width: Val::Percent(100.0),
             ..default()
         },
         ScoreText,
@@ -215,7 +248,7 @@ pub fn setup_ui(mut commands: Commands) {
         TextColor(Color::WHITE),
         Node {
             position_type: PositionType::Absolute,
-            top: Val::Px(40.0),
+            top: Val::Px(90.0),
             left: Val::Px(10.0),
             ..default()
         },
@@ -232,16 +265,38 @@ pub fn setup_ui(mut commands: Commands) {
         TextColor(Color::WHITE),
         Node {
             position_type: PositionType::Absolute,
-            top: Val::Px(70.0),
+            top: Val::Px(120.0),
             left: Val::Px(10.0),
             ..default()
         },
         CountText,
     ));
 
+    // Batter and baserunner display
+    commands.spawn((
+        Text::new("At bat: -"),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(150.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+        BatterText,
+    ));
+
     // Instructions
+    let instructions = if cfg!(feature = "serde") {
+        "A to pitch | Hold SPACE to charge swing | ENTER to swing | F5 save | F9 load"
+    } else {
+        "A to pitch | Hold SPACE to charge swing | ENTER to swing"
+    };
     commands.spawn((
-        Text::new("A to pitch | Hold SPACE to charge swing | ENTER to swing"),
+        Text::new(instructions),
         TextFont {
             font_size: 16.0,
             ..default()
@@ -301,15 +356,8 @@ pub fn handle_input(
                 );
                 match outcome {
                     SwingOutcome::Hit(hit) => {
-                        ball_state.hit_type = Some(hit);
-                        let velo = match hit {
-                            HitType::Grounder => Vec3::new(1.0, 60.0, 0.0),
-                            HitType::Fly => Vec3::new(0.0, 240.0, 0.0),
-                            HitType::LineDrive => Vec3::new(0.0, 500.0, -1.0),
-                            HitType::NoDoubt => Vec3::new(0.0, 800.0, -1.0),
-                        };
-
-                        ball_state.velocity = velo;
+                        let spray = calculate_spray_angle(&ball_state);
+                        ball_state.hit(hit, spray);
                     }
                     SwingOutcome::PitchOutcome(po) => {
                         game_data.current_pitch_outcome = Some(po);
@@ -360,27 +408,59 @@ pub fn update_ball_physics(
             }
 
             // Check if ball lands (for hits)
-            if let Some(ref hit_type) = ball_state.hit_type {
-                if ball_state.position.y <= -150.0
-                    || (matches!(hit_type, HitType::Grounder) && ball_state.position.y <= -100.0)
-                {
-                    ball_state.is_in_play = false;
-                }
+            if let Some(ref hit_type) = ball_state.hit_type
+                && (ball_state.position.y <= -150.0
+                    || (matches!(hit_type, HitType::Grounder) && ball_state.position.y <= -100.0))
+            {
+                ball_state.is_in_play = false;
             }
         }
     }
 }
 
 pub fn update_game_display(
-    mut score_query: Query<&mut Text, (With<ScoreText>, Without<InningText>, Without<CountText>)>,
-    mut inning_query: Query<&mut Text, (With<InningText>, Without<ScoreText>, Without<CountText>)>,
-    mut count_query: Query<&mut Text, (With<CountText>, Without<ScoreText>, Without<InningText>)>,
+    mut score_query: Query<
+        &mut Text,
+        (
+            With<ScoreText>,
+            Without<InningText>,
+            Without<CountText>,
+            Without<BatterText>,
+        ),
+    >,
+    mut inning_query: Query<
+        &mut Text,
+        (
+            With<InningText>,
+            Without<ScoreText>,
+            Without<CountText>,
+            Without<BatterText>,
+        ),
+    >,
+    mut count_query: Query<
+        &mut Text,
+        (
+            With<CountText>,
+            Without<ScoreText>,
+            Without<InningText>,
+            Without<BatterText>,
+        ),
+    >,
+    mut batter_query: Query<
+        &mut Text,
+        (
+            With<BatterText>,
+            Without<ScoreText>,
+            Without<InningText>,
+            Without<CountText>,
+        ),
+    >,
     game_data: Res<GameData>,
 ) {
     if let Some(GameResult::InProgress(game)) = &game_data.game_result {
-        // Update score
+        // Update line score
         if let Ok(mut score_text) = score_query.single_mut() {
-            **score_text = format!("Score: Away {} - Home {}", game.score().away(), game.score().home());
+            **score_text = game.line_score().to_string();
         }
 
         // Update inning
@@ -399,34 +479,49 @@ pub fn update_game_display(
                 half_inning.outs().as_number()
             );
         }
+
+        // Update batter and baserunners
+        if let Ok(mut batter_text) = batter_query.single_mut() {
+            let runners = [(Base::First, "1B"), (Base::Second, "2B"), (Base::Third, "3B")]
+                .into_iter()
+                .filter_map(|(base, label)| game.runner_on(base).map(|runner| format!("{}: {}", label, runner)))
+                .collect::<Vec<_>>();
+
+            **batter_text = if runners.is_empty() {
+                format!("At bat: {}", game.current_batter())
+            } else {
+                format!("At bat: {} | On base: {}", game.current_batter(), runners.join(", "))
+            };
+        }
+    } else if let Some(summary) = game_data.game_result.as_ref().and_then(GameResult::summary_ref)
+        && let Ok(mut score_text) = score_query.single_mut()
+    {
+        **score_text = summary.line_score().to_string();
     }
 }
 
-pub fn handle_fielding(mut player_query: Query<(&mut Player, &mut Transform)>, ball_state: Res<BallState>) {
-    for (mut player, _transform) in player_query.iter_mut() {
-        if ball_state.is_in_play && ball_state.hit_type.is_some() {
-            // Move fielders toward the ball
-            let ball_pos = ball_state.position;
-            let player_pos = player.target_position;
-
-            match player.position {
-                PlayerPosition::LeftField | PlayerPosition::CenterField | PlayerPosition::RightField => {
-                    if matches!(ball_state.hit_type, Some(HitType::Fly)) {
-                        let direction = (ball_pos - player_pos).normalize();
-                        player.target_position = player_pos + direction * 100.0;
-                    }
-                }
-                PlayerPosition::FirstBase
-                | PlayerPosition::SecondBase
-                | PlayerPosition::ThirdBase
-                | PlayerPosition::Shortstop => {
-                    if matches!(ball_state.hit_type, Some(HitType::Grounder) | Some(HitType::LineDrive)) {
-                        let direction = (ball_pos - player_pos).normalize();
-                        player.target_position = player_pos + direction * 50.0;
-                    }
-                }
-                _ => {}
+pub fn handle_fielding(mut player_query: Query<&mut Player>, ball_state: Res<BallState>) {
+    for mut player in player_query.iter_mut() {
+        let Some(hit_type) = ball_state.hit_type.filter(|_| ball_state.is_in_play) else {
+            // Back to position for the next pitch
+            player.target_position = player.station;
+            continue;
+        };
+
+        // Run to where the ball will be fielded
+        let chases = match player.position {
+            PlayerPosition::LeftField | PlayerPosition::CenterField | PlayerPosition::RightField => {
+                matches!(hit_type, HitType::Fly)
             }
+            PlayerPosition::FirstBase
+            | PlayerPosition::SecondBase
+            | PlayerPosition::ThirdBase
+            | PlayerPosition::Shortstop => matches!(hit_type, HitType::Grounder | HitType::LineDrive),
+            _ => false,
+        };
+        if chases {
+            let spot = fielding_spot(hit_type, ball_state.spray);
+            player.target_position = spot.truncate().extend(player.station.z);
         }
     }
 }
@@ -446,19 +541,20 @@ pub fn animate_players(mut player_query: Query<(&Player, &mut Transform)>, time:
 }
 
 pub fn check_game_events(mut game_data: ResMut<GameData>, mut ball_state: ResMut<BallState>) {
-    if let Some(outcome) = game_data.current_pitch_outcome.take() {
-        if let Some(GameResult::InProgress(game)) = game_data.game_result.take() {
-            let new_result = game.advance(outcome);
-            info!("New result: {}", new_result);
-            game_data.game_result = Some(new_result);
-
-            // Reset for next pitch
-            game_data.is_pitching = true;
-            ball_state.position = Vec3::new(0.0, -60.0, 10.0);
-            ball_state.velocity = Vec3::ZERO;
-            ball_state.is_in_play = false;
-            ball_state.hit_type = None;
-        }
+    if let Some(outcome) = game_data.current_pitch_outcome.take()
+        && let Some(GameResult::InProgress(game)) = game_data.game_result.take()
+    {
+        let new_result = game.advance(outcome);
+        info!("New result: {}", new_result);
+        game_data.game_result = Some(new_result);
+
+        // Reset for next pitch
+        game_data.is_pitching = true;
+        ball_state.position = Vec3::new(0.0, -60.0, 10.0);
+        ball_state.velocity = Vec3::ZERO;
+        ball_state.is_in_play = false;
+        ball_state.hit_type = None;
+        ball_state.spray = 0.0;
     }
 }
 
@@ -474,6 +570,13 @@ fn calculate_swing_timing(ball_state: &BallState) -> f32 {
     score
 }
 
+/// Swinging early pulls the ball toward left field and swinging late pushes it toward right, out
+/// to the foul lines.
+fn calculate_spray_angle(ball_state: &BallState) -> f32 {
+    let early = (ball_state.position.y - -150.0) / 50.0;
+    -early.clamp(-1.0, 1.0) * FRAC_PI_4
+}
+
 fn determine_swing_outcome(swing_power: f32, swing_timing: f32) -> SwingOutcome {
     let combined_quality = swing_power * swing_timing;
 
@@ -0,0 +1,351 @@
+use bevy::prelude::*;
+
+use crate::{
+    baseball::*,
+    game::plugins::baseball::{BallState, GameData, HitType, Player},
+};
+
+/// Where the ball is hit from, in field coordinates.
+const HOME_PLATE: Vec2 = Vec2::new(0.0, -150.0);
+
+/// Any ball carried at least this far from home plate leaves the park.
+const FENCE_DISTANCE: f32 = 400.0;
+
+/// Uncaught balls that stay inside this distance go for a single.
+const SINGLE_DISTANCE: f32 = 180.0;
+
+/// Uncaught balls that stay inside this distance go for a double; anything
+/// further but short of the fence is a triple.
+const DOUBLE_DISTANCE: f32 = 300.0;
+
+/// How close a fielder has to be to the ball to make the play.
+const CATCH_RADIUS: f32 = 20.0;
+
+/// How much further an outfielder can run while a fly ball hangs in the air.
+const FLY_BALL_RANGE: f32 = 40.0;
+
+/// How far each kind of batted ball carries before it comes down or, for a
+/// grounder, gets through the infield.
+fn carry(hit_type: HitType) -> f32 {
+    match hit_type {
+        HitType::Grounder => 190.0,
+        HitType::LineDrive => 170.0,
+        HitType::Fly => 260.0,
+        HitType::NoDoubt => FENCE_DISTANCE + 50.0,
+    }
+}
+
+/// Where a ball hit as `hit_type` toward `spray` (radians off the center field
+/// line, positive toward right field) lands, or rolls to for a grounder.
+pub fn fielding_spot(hit_type: HitType, spray: f32) -> Vec3 {
+    let direction = Vec2::new(spray.sin(), spray.cos());
+    (HOME_PLATE + direction * carry(hit_type)).extend(10.0)
+}
+
+/// Turns a dead batted ball into a `PitchOutcome` once `update_ball_physics` has
+/// landed it, so `check_game_events` can feed it to `Game::advance`. Fielders
+/// are judged from where they set up for the pitch.
+pub fn resolve_batted_balls(mut game_data: ResMut<GameData>, ball_state: Res<BallState>, player_query: Query<&Player>) {
+    if ball_state.is_in_play || game_data.current_pitch_outcome.is_some() {
+        return;
+    }
+
+    let Some(hit_type) = ball_state.hit_type else {
+        return;
+    };
+
+    let Some(GameResult::InProgress(game)) = &game_data.game_result else {
+        return;
+    };
+
+    let half_inning = game.current_half_inning();
+    let fielders = player_query.iter().map(|player| (player.position, player.station));
+
+    let outcome = resolve_batted_ball(
+        hit_type,
+        fielding_spot(hit_type, ball_state.spray),
+        fielders,
+        half_inning.baserunners(),
+        half_inning.current_batter(),
+    );
+    info!("Batted ball ({:?}) resolved: {}", hit_type, outcome);
+    game_data.current_pitch_outcome = Some(outcome);
+}
+
+/// Maps where a batted ball ended up and where the defense was standing to a
+/// concrete outcome for the current batter and baserunners. A grounder is
+/// fielded by anyone standing on its path out of the infield; a ball in the air
+/// only by someone who can get to where it comes down.
+pub fn resolve_batted_ball(
+    hit_type: HitType,
+    landing_spot: Vec3,
+    fielders: impl IntoIterator<Item = (PlayerPosition, Vec3)>,
+    baserunners: BaserunnerState,
+    batter: BattingPosition,
+) -> PitchOutcome {
+    let distance = landing_spot.truncate().distance(HOME_PLATE);
+
+    if matches!(hit_type, HitType::NoDoubt) || distance >= FENCE_DISTANCE {
+        return PitchOutcome::HomeRun;
+    }
+
+    let fielded = fielders
+        .into_iter()
+        .filter(|(position, _)| !matches!(position, PlayerPosition::DesignatedHitter))
+        .any(|(_, spot)| {
+            let spot = spot.truncate();
+            match hit_type {
+                HitType::Grounder => distance_from_ground_path(spot, landing_spot.truncate()) <= CATCH_RADIUS,
+                HitType::Fly => spot.distance(landing_spot.truncate()) <= CATCH_RADIUS + FLY_BALL_RANGE,
+                _ => spot.distance(landing_spot.truncate()) <= CATCH_RADIUS,
+            }
+        });
+
+    let play = match (hit_type, fielded) {
+        (HitType::Grounder, true) => PlayOutcome::groundout_with(baserunners, batter),
+        (_, true) => PlayOutcome::flyout_with(baserunners, batter),
+        (HitType::Grounder, false) => PlayOutcome::single(baserunners, batter),
+        (_, false) if distance < SINGLE_DISTANCE => PlayOutcome::single(baserunners, batter),
+        (_, false) if distance < DOUBLE_DISTANCE => PlayOutcome::double(baserunners, batter),
+        (_, false) => PlayOutcome::triple(baserunners, batter),
+    };
+
+    PitchOutcome::InPlay(play)
+}
+
+/// How far `fielder` is from a grounder rolling from home plate to `end`. Nobody
+/// behind the plate can get to it.
+fn distance_from_ground_path(fielder: Vec2, end: Vec2) -> f32 {
+    let path = end - HOME_PLATE;
+    let offset = fielder - HOME_PLATE;
+    if path == Vec2::ZERO {
+        return offset.length();
+    }
+
+    let along = offset.dot(path) / path.length_squared();
+    if along <= 0.0 {
+        return f32::INFINITY;
+    }
+    offset.distance(path * along.min(1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::FRAC_PI_6, time::Duration};
+
+    use super::*;
+    use crate::game::plugins::baseball::{Ball, PLAYER_POSITIONS, update_ball_physics};
+
+    fn landing(distance: f32) -> Vec3 {
+        HOME_PLATE.extend(10.0) + Vec3::Y * distance
+    }
+
+    #[test]
+    fn test_no_doubt_is_home_run() {
+        let outcome = resolve_batted_ball(
+            HitType::NoDoubt,
+            landing(250.0),
+            [],
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        );
+        assert_eq!(outcome, PitchOutcome::HomeRun);
+    }
+
+    #[test]
+    fn test_ball_over_fence_is_home_run() {
+        let outcome = resolve_batted_ball(
+            HitType::LineDrive,
+            landing(FENCE_DISTANCE + 1.0),
+            [],
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        );
+        assert_eq!(outcome, PitchOutcome::HomeRun);
+    }
+
+    #[test]
+    fn test_uncaught_balls_by_distance() {
+        let baserunners = BaserunnerState::new().set_second(Some(BattingPosition::Third));
+        let batter = BattingPosition::Fourth;
+
+        let resolve = |distance| resolve_batted_ball(HitType::LineDrive, landing(distance), [], baserunners, batter);
+
+        assert_eq!(
+            resolve(100.0),
+            PitchOutcome::InPlay(PlayOutcome::single(baserunners, batter))
+        );
+        assert_eq!(
+            resolve(250.0),
+            PitchOutcome::InPlay(PlayOutcome::double(baserunners, batter))
+        );
+        assert_eq!(
+            resolve(350.0),
+            PitchOutcome::InPlay(PlayOutcome::triple(baserunners, batter))
+        );
+    }
+
+    #[test]
+    fn test_caught_fly_ball() {
+        let baserunners = BaserunnerState::new().set_third(Some(BattingPosition::Second));
+        let spot = landing(270.0);
+
+        let outcome = resolve_batted_ball(
+            HitType::Fly,
+            spot,
+            [(PlayerPosition::CenterField, spot + Vec3::X * 5.0)],
+            baserunners,
+            BattingPosition::Third,
+        );
+        assert_eq!(
+            outcome,
+            PitchOutcome::InPlay(PlayOutcome::flyout_with(baserunners, BattingPosition::Third))
+        );
+    }
+
+    #[test]
+    fn test_batter_does_not_field_own_ball() {
+        let spot = landing(20.0);
+
+        let outcome = resolve_batted_ball(
+            HitType::Grounder,
+            spot,
+            [(PlayerPosition::DesignatedHitter, spot)],
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        );
+        assert_eq!(
+            outcome,
+            PitchOutcome::InPlay(PlayOutcome::single(BaserunnerState::empty(), BattingPosition::First))
+        );
+    }
+
+    #[test]
+    fn test_fielded_grounder_forces_runners() {
+        let spot = landing(60.0);
+        let shortstop = [(PlayerPosition::Shortstop, spot)];
+
+        let outcome = resolve_batted_ball(
+            HitType::Grounder,
+            spot,
+            shortstop,
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        );
+        assert_eq!(
+            outcome,
+            PitchOutcome::InPlay(PlayOutcome::groundout_with(
+                BaserunnerState::empty(),
+                BattingPosition::First
+            ))
+        );
+
+        let baserunners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Second));
+        let PitchOutcome::InPlay(play) =
+            resolve_batted_ball(HitType::Grounder, spot, shortstop, baserunners, BattingPosition::Third)
+        else {
+            panic!("Expected ball in play");
+        };
+        assert_eq!(play.first(), BaseOutcome::ForceOut);
+        assert_eq!(play.second(), BaseOutcome::Runner(BattingPosition::First));
+        assert_eq!(play.third(), BaseOutcome::Runner(BattingPosition::Second));
+        assert_eq!(play.runs_scored(), 0);
+        assert_eq!(play.outs(), 1);
+    }
+
+    /// Hits a ball off the plate against the standard defense and runs the ball
+    /// physics until the play is resolved.
+    fn play(hit_type: HitType, spray: f32) -> PitchOutcome {
+        let mut app = App::new();
+        app.init_resource::<GameData>()
+            .init_resource::<BallState>()
+            .init_resource::<Time>()
+            .add_systems(Update, (update_ball_physics, resolve_batted_balls).chain());
+
+        for (x, y, position) in PLAYER_POSITIONS {
+            let station = Vec3::new(x, y, 5.0);
+            app.world_mut().spawn((
+                Transform::from_translation(station),
+                Player {
+                    position,
+                    station,
+                    target_position: station,
+                },
+            ));
+        }
+        let contact = Vec3::new(0.0, -145.0, 10.0);
+        app.world_mut().spawn((Transform::from_translation(contact), Ball));
+
+        app.world_mut().resource_mut::<GameData>().game_result = Some(GameResult::InProgress(Game::new()));
+        let mut ball_state = app.world_mut().resource_mut::<BallState>();
+        ball_state.position = contact;
+        ball_state.is_in_play = true;
+        ball_state.hit(hit_type, spray);
+
+        for _ in 0..600 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+            if let Some(outcome) = app.world_mut().resource_mut::<GameData>().current_pitch_outcome.take() {
+                return outcome;
+            }
+        }
+        panic!("Batted ball never came down");
+    }
+
+    #[test]
+    fn test_batted_balls_against_standard_defense() {
+        let empty = BaserunnerState::empty();
+        let batter = BattingPosition::First;
+
+        // Up the middle the pitcher and center fielder make the plays
+        assert_eq!(
+            play(HitType::Grounder, 0.0),
+            PitchOutcome::InPlay(PlayOutcome::groundout_with(empty, batter))
+        );
+        assert_eq!(
+            play(HitType::Fly, 0.0),
+            PitchOutcome::InPlay(PlayOutcome::flyout_with(empty, batter))
+        );
+
+        // The left fielder runs down a pulled fly
+        assert_eq!(
+            play(HitType::Fly, -FRAC_PI_6),
+            PitchOutcome::InPlay(PlayOutcome::flyout_with(empty, batter))
+        );
+
+        // Between the second baseman and the first baseman
+        assert_eq!(
+            play(HitType::Grounder, FRAC_PI_6),
+            PitchOutcome::InPlay(PlayOutcome::single(empty, batter))
+        );
+
+        // Over the infield and short of the outfield
+        assert_eq!(
+            play(HitType::LineDrive, 0.0),
+            PitchOutcome::InPlay(PlayOutcome::single(empty, batter))
+        );
+        assert_eq!(play(HitType::NoDoubt, 0.0), PitchOutcome::HomeRun);
+    }
+
+    #[test]
+    fn test_catcher_does_not_field_grounders() {
+        let spot = fielding_spot(HitType::Grounder, FRAC_PI_6);
+        let catcher = [(PlayerPosition::Catcher, Vec3::new(0.0, -170.0, 5.0))];
+
+        let outcome = resolve_batted_ball(
+            HitType::Grounder,
+            spot,
+            catcher,
+            BaserunnerState::empty(),
+            BattingPosition::First,
+        );
+        assert_eq!(
+            outcome,
+            PitchOutcome::InPlay(PlayOutcome::single(BaserunnerState::empty(), BattingPosition::First))
+        );
+    }
+}
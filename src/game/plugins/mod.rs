@@ -1 +1,4 @@
 pub mod baseball;
+mod batted_ball;
+#[cfg(feature = "save")]
+pub mod save;
@@ -0,0 +1,315 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    baseball::*,
+    game::plugins::baseball::{Ball, BallState, GameData, HitType, setup_game_state},
+};
+
+/// Where saves go when no path is given on the command line.
+pub const DEFAULT_SAVE_PATH: &str = "baseball_save.json";
+
+/// Saves the game with F5 and restores it with F9.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SavePlugin {
+    path: PathBuf,
+    load_on_startup: bool,
+}
+
+impl SavePlugin {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        SavePlugin {
+            path: path.into(),
+            load_on_startup: false,
+        }
+    }
+
+    /// Restores the save at this plugin's path instead of starting a new game.
+    pub fn load_on_startup(mut self) -> Self {
+        self.load_on_startup = true;
+        self
+    }
+}
+
+impl Default for SavePlugin {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAVE_PATH)
+    }
+}
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SaveFile(self.path.clone()))
+            .add_systems(Update, handle_save_input);
+
+        if self.load_on_startup {
+            app.add_systems(Startup, load_on_startup.after(setup_game_state));
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct SaveFile(pub PathBuf);
+
+/// Everything needed to resume a game exactly where it was left, including a pitch in flight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedGame {
+    game_result: GameResult,
+    is_pitching: bool,
+    ball: SavedBall,
+}
+
+impl SavedGame {
+    pub fn new(game_data: &GameData, ball_state: &BallState) -> Option<Self> {
+        Some(SavedGame {
+            game_result: game_data.game_result.clone()?,
+            is_pitching: game_data.is_pitching,
+            ball: SavedBall::from(ball_state),
+        })
+    }
+
+    pub fn game_result(&self) -> &GameResult {
+        &self.game_result
+    }
+
+    /// Loading a suspended game resumes it.
+    fn restore(self, game_data: &mut GameData, ball_state: &mut BallState) {
+        game_data.game_result = Some(self.game_result.resume());
+        game_data.is_pitching = self.is_pitching;
+        game_data.current_pitch_outcome = None;
+        game_data.swing_power = 0.0;
+        game_data.swing_timing = 0.0;
+        self.ball.restore(ball_state);
+    }
+}
+
+/// `BallState` with plain arrays in place of `Vec3`, so saves do not depend on Bevy's math types.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct SavedBall {
+    position: [f32; 3],
+    velocity: [f32; 3],
+    is_in_play: bool,
+    hit_type: Option<HitType>,
+    spray: f32,
+}
+
+impl From<&BallState> for SavedBall {
+    fn from(ball_state: &BallState) -> Self {
+        SavedBall {
+            position: ball_state.position.to_array(),
+            velocity: ball_state.velocity.to_array(),
+            is_in_play: ball_state.is_in_play,
+            hit_type: ball_state.hit_type,
+            spray: ball_state.spray,
+        }
+    }
+}
+
+impl SavedBall {
+    fn restore(self, ball_state: &mut BallState) {
+        ball_state.position = Vec3::from_array(self.position);
+        ball_state.velocity = Vec3::from_array(self.velocity);
+        ball_state.is_in_play = self.is_in_play;
+        ball_state.hit_type = self.hit_type;
+        ball_state.spray = self.spray;
+    }
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    NoGame,
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Io(error) => write!(f, "Could not access save file: {}", error),
+            SaveError::Json(error) => write!(f, "Save file is not a valid game: {}", error),
+            SaveError::NoGame => write!(f, "There is no game to save"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<std::io::Error> for SaveError {
+    fn from(error: std::io::Error) -> Self {
+        SaveError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(error: serde_json::Error) -> Self {
+        SaveError::Json(error)
+    }
+}
+
+pub fn save_game(path: &Path, saved_game: &SavedGame) -> Result<(), SaveError> {
+    let json = serde_json::to_string_pretty(saved_game)?;
+    fs::write(path, json)?;
+    Ok(())
+}
+
+pub fn load_game(path: &Path) -> Result<SavedGame, SaveError> {
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+fn handle_save_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    save_file: Res<SaveFile>,
+    mut game_data: ResMut<GameData>,
+    mut ball_state: ResMut<BallState>,
+    mut ball_query: Query<&mut Transform, With<Ball>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F5) {
+        let result = SavedGame::new(&game_data, &ball_state)
+            .ok_or(SaveError::NoGame)
+            .and_then(|saved_game| save_game(&save_file.0, &saved_game));
+        match result {
+            Ok(()) => info!("Game saved to {}", save_file.0.display()),
+            Err(error) => error!("Failed to save game: {}", error),
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::F9) {
+        restore(&save_file.0, &mut game_data, &mut ball_state, &mut ball_query);
+    }
+}
+
+fn load_on_startup(
+    save_file: Res<SaveFile>,
+    mut game_data: ResMut<GameData>,
+    mut ball_state: ResMut<BallState>,
+    mut ball_query: Query<&mut Transform, With<Ball>>,
+) {
+    restore(&save_file.0, &mut game_data, &mut ball_state, &mut ball_query);
+}
+
+fn restore(
+    path: &Path,
+    game_data: &mut GameData,
+    ball_state: &mut BallState,
+    ball_query: &mut Query<&mut Transform, With<Ball>>,
+) {
+    match load_game(path) {
+        Ok(saved_game) => {
+            info!("Loaded game from {}: {}", path.display(), saved_game.game_result());
+            saved_game.restore(game_data, ball_state);
+            if let Ok(mut ball_transform) = ball_query.single_mut() {
+                ball_transform.translation = ball_state.position;
+            }
+        }
+        Err(error) => error!("Failed to load game from {}: {}", path.display(), error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("baseball-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_save_and_load_mid_plate_appearance() {
+        let game_result = GameResult::InProgress(Game::new())
+            .advance(PitchOutcome::Ball)
+            .advance(PitchOutcome::Strike)
+            .advance(PitchOutcome::Ball);
+        let game_data = GameData {
+            game_result: Some(game_result.clone()),
+            is_pitching: false,
+            ..default()
+        };
+        let ball_state = BallState {
+            position: Vec3::new(5.0, -100.0, 10.0),
+            velocity: Vec3::new(0.0, -300.0, 0.0),
+            is_in_play: true,
+            hit_type: None,
+            spray: 0.0,
+        };
+
+        let path = temp_path("mid-pa");
+        let saved_game = SavedGame::new(&game_data, &ball_state).expect("Game should be in progress");
+        save_game(&path, &saved_game).expect("Game should save");
+        let loaded = load_game(&path).expect("Game should load");
+        fs::remove_file(&path).ok();
+
+        assert_eq!(loaded, saved_game);
+
+        let mut restored_data = GameData::default();
+        let mut restored_ball = BallState::default();
+        loaded.restore(&mut restored_data, &mut restored_ball);
+
+        assert_eq!(restored_data.game_result, Some(game_result));
+        assert!(!restored_data.is_pitching);
+        assert_eq!(restored_ball.position, ball_state.position);
+        assert!(restored_ball.is_in_play);
+
+        let game = restored_data
+            .game_result
+            .and_then(GameResult::game)
+            .expect("Game should be in progress");
+        let count = game.current_half_inning().current_plate_appearance().count();
+        assert_eq!(count.balls().as_number(), 2);
+        assert_eq!(count.strikes().as_number(), 1);
+    }
+
+    #[test]
+    fn test_suspended_game_resumes_on_load() {
+        let game_result = GameResult::InProgress(Game::new())
+            .advance(PitchOutcome::Ball)
+            .suspend();
+        let game_data = GameData {
+            game_result: Some(game_result),
+            ..default()
+        };
+
+        let path = temp_path("suspended");
+        let saved_game = SavedGame::new(&game_data, &BallState::default()).expect("Suspended game should save");
+        save_game(&path, &saved_game).expect("Game should save");
+        let loaded = load_game(&path).expect("Game should load");
+        fs::remove_file(&path).ok();
+        assert!(loaded.game_result().is_suspended());
+
+        let mut restored_data = GameData::default();
+        loaded.restore(&mut restored_data, &mut BallState::default());
+        let game = restored_data
+            .game_result
+            .and_then(GameResult::game)
+            .expect("Game should be resumed");
+        let count = game.current_half_inning().current_plate_appearance().count();
+        assert_eq!(count.balls().as_number(), 1);
+    }
+
+    #[test]
+    fn test_nothing_to_save_without_a_game() {
+        assert_eq!(SavedGame::new(&GameData::default(), &BallState::default()), None);
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let result = load_game(&temp_path("missing"));
+        assert!(matches!(result, Err(SaveError::Io(_))));
+    }
+
+    #[test]
+    fn test_load_invalid_file() {
+        let path = temp_path("invalid");
+        fs::write(&path, "not a game").expect("Temp file should be writable");
+        let result = load_game(&path);
+        fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(SaveError::Json(_))));
+    }
+}
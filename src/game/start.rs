@@ -1,8 +1,28 @@
 use bevy::prelude::*;
 
 use crate::game::plugins::baseball::BaseballPlugin;
+#[cfg(feature = "save")]
+use crate::game::plugins::save::{DEFAULT_SAVE_PATH, SavePlugin};
 
-/// Runs the game.
+/// Runs the game. With the `save` feature, pass `--load [path]` to resume a saved game.
 pub fn run() {
-    App::new().add_plugins(DefaultPlugins).add_plugins(BaseballPlugin).run();
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins).add_plugins(BaseballPlugin);
+
+    #[cfg(feature = "save")]
+    app.add_plugins(save_plugin(std::env::args().skip(1)));
+
+    app.run();
+}
+
+#[cfg(feature = "save")]
+fn save_plugin(mut args: impl Iterator<Item = String>) -> SavePlugin {
+    while let Some(arg) = args.next() {
+        if arg == "--load" {
+            let path = args.next().unwrap_or_else(|| DEFAULT_SAVE_PATH.to_string());
+            return SavePlugin::new(path).load_on_startup();
+        }
+    }
+
+    SavePlugin::default()
 }